@@ -0,0 +1,123 @@
+//! RPC methods to query the bookings of the chain.
+
+use std::{
+	sync::Arc,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use drenting_runtime::{
+	opaque::Block,
+	pallet_bookings::{runtime_api::BookingsApi as BookingsRuntimeApi, CalendarDate},
+	AccountId, Balance, Hash,
+};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+use super::ical::render_place_calendar;
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code returned when the booking cannot be quoted.
+const QUOTE_ERROR: i32 = 2;
+/// Error code returned when the place does not exist.
+const PLACE_NOT_FOUND_ERROR: i32 = 3;
+
+/// Bookings RPC methods.
+#[rpc(client, server)]
+pub trait BookingsApi<BlockHash> {
+	/// Get the amount a guest has to pay to book a place between two dates, once the discount of
+	/// the coupon code and of the loyalty points of the `redeem_points` account, if any, are
+	/// applied.
+	#[method(name = "bookings_quote")]
+	fn quote_booking(
+		&self,
+		place_id: Hash,
+		start_date: CalendarDate,
+		end_date: CalendarDate,
+		coupon: Option<Bytes>,
+		redeem_points: Option<AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<NumberOrHex>;
+
+	/// Get the confirmed bookings and the blocked periods of a place as an iCalendar (`.ics`)
+	/// document, without the identity of the guests.
+	#[method(name = "bookings_placeCalendar")]
+	fn place_calendar(&self, place_id: Hash, at: Option<BlockHash>) -> RpcResult<String>;
+}
+
+/// Provides the bookings RPC methods.
+pub struct Bookings<C> {
+	client: Arc<C>,
+}
+
+impl<C> Bookings<C> {
+	/// Create a new instance of the bookings RPC.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+/// Builds an RPC error.
+fn rpc_error(code: i32, message: &str, data: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(code, message, Some(format!("{:?}", data)))).into()
+}
+
+impl<C> BookingsApiServer<<Block as BlockT>::Hash> for Bookings<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: BookingsRuntimeApi<Block, Hash, AccountId, Balance>,
+{
+	fn quote_booking(
+		&self,
+		place_id: Hash,
+		start_date: CalendarDate,
+		end_date: CalendarDate,
+		coupon: Option<Bytes>,
+		redeem_points: Option<AccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_booking(
+			at_hash,
+			place_id,
+			start_date,
+			end_date,
+			coupon.map(|code| code.to_vec()),
+			redeem_points,
+		)
+		.map_err(|e| rpc_error(RUNTIME_ERROR, "Unable to quote the booking.", e))?
+		.map(NumberOrHex::from)
+		.map_err(|e| rpc_error(QUOTE_ERROR, "The booking cannot be quoted.", e))
+	}
+
+	fn place_calendar(
+		&self,
+		place_id: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<String> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let calendar = api
+			.place_calendar(at_hash, place_id)
+			.map_err(|e| rpc_error(RUNTIME_ERROR, "Unable to query the calendar.", e))?
+			.ok_or_else(|| {
+				rpc_error(PLACE_NOT_FOUND_ERROR, "The place does not exist.", place_id)
+			})?;
+		let now = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map_or(0, |duration| duration.as_millis() as u64);
+
+		Ok(render_place_calendar(&calendar, now))
+	}
+}
@@ -0,0 +1,177 @@
+//! Rendering of the calendars of the places as iCalendar (RFC 5545) documents.
+
+use drenting_runtime::{
+	pallet_bookings::{CalendarEntryKind, PlaceCalendar},
+	Hash,
+};
+
+const MILLIS_PER_SECOND: u64 = 1_000;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+/// Maximum length of a line, in octets, without the line break.
+const MAX_LINE_LENGTH: usize = 75;
+
+/// Render the calendar of a place as an iCalendar document.
+///
+/// Every booked or blocked period is an event from the checkin to the checkout moment, in UTC.
+/// The events only tell whether the place is booked or blocked, without the guests. Their UIDs
+/// are the stable identifiers of the periods, which anyone can link to the bookings on chain. The
+/// checkin and checkout hours of the place are included in the description of the events.
+pub fn render_place_calendar(calendar: &PlaceCalendar<Hash>, now_millis: u64) -> String {
+	let name = String::from_utf8_lossy(&calendar.name);
+	let hours = format!(
+		"Check-in from {:02}:00, check-out until {:02}:00 (UTC{})",
+		calendar.checkin_hour,
+		calendar.checkout_hour,
+		format_utc_offset(calendar.utc_offset_minutes),
+	);
+	let dtstamp = format_date_time(now_millis);
+
+	let mut lines = vec![
+		"BEGIN:VCALENDAR".to_string(),
+		"VERSION:2.0".to_string(),
+		"PRODID:-//DRenting//Place Calendar//EN".to_string(),
+		"CALSCALE:GREGORIAN".to_string(),
+		"METHOD:PUBLISH".to_string(),
+		format!("X-WR-CALNAME:{}", escape_text(&name)),
+	];
+	for entry in &calendar.entries {
+		let summary = match entry.kind {
+			CalendarEntryKind::Booked => "Booked",
+			CalendarEntryKind::Blocked => "Blocked",
+		};
+		lines.extend([
+			"BEGIN:VEVENT".to_string(),
+			format!("UID:{:x}@drenting", entry.uid),
+			format!("DTSTAMP:{}", dtstamp),
+			format!("DTSTART:{}", format_date_time(entry.start)),
+			format!("DTEND:{}", format_date_time(entry.end)),
+			format!("SUMMARY:{}", summary),
+			format!("DESCRIPTION:{}", escape_text(&hours)),
+			"TRANSP:OPAQUE".to_string(),
+			"END:VEVENT".to_string(),
+		]);
+	}
+	lines.push("END:VCALENDAR".to_string());
+
+	lines.iter().map(|line| fold_line(line) + "\r\n").collect()
+}
+
+/// Format a moment as a UTC DATE-TIME value, such as `20250410T150000Z`.
+fn format_date_time(millis: u64) -> String {
+	let seconds = millis / MILLIS_PER_SECOND;
+	let (year, month, day) = civil_from_days(seconds / SECONDS_PER_DAY);
+	let seconds_of_day = seconds % SECONDS_PER_DAY;
+	format!(
+		"{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+		year,
+		month,
+		day,
+		seconds_of_day / 3600,
+		seconds_of_day % 3600 / 60,
+		seconds_of_day % 60
+	)
+}
+
+/// Get the year, month and day of the date `days` days after 1970-01-01.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+	// Count the years from March, so the leap day is the last day of the year
+	let days = days + 719_468;
+	let era = days / 146_097;
+	let day_of_era = days % 146_097;
+	let year_of_era =
+		(day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month_from_march = (5 * day_of_year + 2) / 153;
+	let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+	let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+	let year = era * 400 + year_of_era + if month <= 2 { 1 } else { 0 };
+	(year, month, day)
+}
+
+/// Format an offset from UTC, such as `+01:00` or `-03:30`.
+fn format_utc_offset(offset_minutes: i16) -> String {
+	let sign = if offset_minutes < 0 { '-' } else { '+' };
+	let offset = offset_minutes.unsigned_abs();
+	format!("{}{:02}:{:02}", sign, offset / 60, offset % 60)
+}
+
+/// Escape the characters with a special meaning in TEXT values.
+fn escape_text(text: &str) -> String {
+	text.replace('\\', "\\\\")
+		.replace(';', "\\;")
+		.replace(',', "\\,")
+		.replace("\r\n", "\\n")
+		.replace('\n', "\\n")
+}
+
+/// Fold a line longer than 75 octets, continuing it in lines that start with a space. Lines are
+/// never split in the middle of a UTF-8 character.
+fn fold_line(line: &str) -> String {
+	let mut folded = String::with_capacity(line.len());
+	let mut line_length = 0;
+	for character in line.chars() {
+		if line_length + character.len_utf8() > MAX_LINE_LENGTH {
+			folded.push_str("\r\n ");
+			line_length = 1;
+		}
+		folded.push(character);
+		line_length += character.len_utf8();
+	}
+	folded
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_civil_from_days_should_work() {
+		assert_eq!(civil_from_days(0), (1970, 1, 1));
+		assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+		assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+		assert_eq!(civil_from_days(47_541), (2100, 3, 1));
+	}
+
+	#[test]
+	fn test_format_date_time_should_work() {
+		assert_eq!(format_date_time(0), "19700101T000000Z");
+		assert_eq!(format_date_time(1_744_297_200_000), "20250410T150000Z");
+		// The milliseconds are truncated
+		assert_eq!(format_date_time(1_709_251_199_999), "20240229T235959Z");
+	}
+
+	#[test]
+	fn test_format_utc_offset_should_work() {
+		assert_eq!(format_utc_offset(0), "+00:00");
+		assert_eq!(format_utc_offset(60), "+01:00");
+		assert_eq!(format_utc_offset(-210), "-03:30");
+	}
+
+	#[test]
+	fn test_escape_text_should_work() {
+		assert_eq!(escape_text("Check-in from 17:00"), "Check-in from 17:00");
+		assert_eq!(escape_text("a;b,c\\d"), "a\\;b\\,c\\\\d");
+		assert_eq!(escape_text("first\r\nsecond\nthird"), "first\\nsecond\\nthird");
+	}
+
+	#[test]
+	fn test_fold_line_should_work() {
+		let short = "a".repeat(MAX_LINE_LENGTH);
+		assert_eq!(fold_line(&short), short);
+
+		let long = "a".repeat(MAX_LINE_LENGTH + 80);
+		let folded = fold_line(&long);
+		let lines: Vec<&str> = folded.split("\r\n").collect();
+		assert_eq!(lines.len(), 3);
+		assert!(lines.iter().all(|line| line.len() <= MAX_LINE_LENGTH));
+		assert!(lines[1..].iter().all(|line| line.starts_with(' ')));
+		assert_eq!(folded.replace("\r\n ", ""), long);
+	}
+
+	#[test]
+	fn test_fold_line_should_not_split_characters() {
+		// The two octets of the last character do not fit in the first line
+		let line = format!("{}é", "a".repeat(MAX_LINE_LENGTH - 1));
+		assert_eq!(fold_line(&line), format!("{}\r\n é", "a".repeat(MAX_LINE_LENGTH - 1)));
+	}
+}
@@ -0,0 +1,130 @@
+//! RPC methods to search the places registered in the chain.
+
+use std::sync::Arc;
+
+use drenting_runtime::{
+	opaque::Block,
+	pallet_bookings::CalendarDate,
+	pallet_places::{
+		runtime_api::PlacesApi as PlacesRuntimeApi, BoundingBox, Coordinates, PlaceFilter,
+	},
+	Hash,
+};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code returned when the search area is too large.
+const SEARCH_ERROR: i32 = 2;
+
+/// Places search RPC methods.
+#[rpc(client, server)]
+pub trait PlacesApi<BlockHash> {
+	/// Get the places located inside a bounding box that match the filter.
+	#[method(name = "places_inBoundingBox")]
+	fn places_in_bounding_box(
+		&self,
+		bounding_box: BoundingBox,
+		filter: Option<PlaceFilter<CalendarDate>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Hash>>;
+
+	/// Get the places located within `radius` meters of `center` that match the filter.
+	#[method(name = "places_withinRadius")]
+	fn places_within_radius(
+		&self,
+		center: Coordinates,
+		radius: u32,
+		filter: Option<PlaceFilter<CalendarDate>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Hash>>;
+
+	/// Check whether the owner of a place is a verified host.
+	#[method(name = "places_isVerifiedHost")]
+	fn is_verified_host(&self, place_id: Hash, at: Option<BlockHash>) -> RpcResult<bool>;
+}
+
+/// Provides the places search RPC methods.
+pub struct Places<C> {
+	client: Arc<C>,
+}
+
+impl<C> Places<C> {
+	/// Create a new instance of the places search RPC.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+/// Converts a runtime API error into an RPC error.
+fn runtime_error(error: impl std::fmt::Display) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query places.",
+		Some(error.to_string()),
+	))
+	.into()
+}
+
+/// Converts a search error into an RPC error.
+fn search_error(error: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		SEARCH_ERROR,
+		"The area cannot be searched.",
+		Some(format!("{:?}", error)),
+	))
+	.into()
+}
+
+impl<C> PlacesApiServer<<Block as BlockT>::Hash> for Places<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: PlacesRuntimeApi<Block, Hash, CalendarDate>,
+{
+	fn places_in_bounding_box(
+		&self,
+		bounding_box: BoundingBox,
+		filter: Option<PlaceFilter<CalendarDate>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Hash>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.places_in_bounding_box(at_hash, bounding_box, filter.unwrap_or_default())
+			.map_err(runtime_error)?
+			.map_err(search_error)
+	}
+
+	fn places_within_radius(
+		&self,
+		center: Coordinates,
+		radius: u32,
+		filter: Option<PlaceFilter<CalendarDate>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Hash>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.places_within_radius(at_hash, center, radius, filter.unwrap_or_default())
+			.map_err(runtime_error)?
+			.map_err(search_error)
+	}
+
+	fn is_verified_host(
+		&self,
+		place_id: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.is_verified_host(at_hash, place_id).map_err(runtime_error)
+	}
+}
@@ -0,0 +1,101 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{
+	interface::AttestationsInterface, Config, Error, Judgement, Judgements, Pallet, Registrars,
+};
+use frame_support::{ensure, sp_runtime::DispatchError};
+use pallet_places::{traits::Verification, VerificationLevel};
+
+impl<T: Config> AttestationsInterface<T> for Pallet<T> {
+	fn _add_registrar(registrar: &T::AccountId) -> Result<(), DispatchError> {
+		<Registrars<T>>::try_mutate(|registrars| {
+			ensure!(!registrars.contains(registrar), Error::<T>::AlreadyRegistrar);
+			registrars.push(registrar.clone());
+			Ok(())
+		})
+	}
+
+	fn _remove_registrar(registrar: &T::AccountId) -> Result<(), DispatchError> {
+		<Registrars<T>>::try_mutate(|registrars| {
+			let index = registrars
+				.iter()
+				.position(|account| account == registrar)
+				.ok_or(Error::<T>::NotRegistrar)?;
+			registrars.swap_remove(index);
+			Ok(())
+		})
+	}
+
+	fn _provide_judgement(
+		registrar: &T::AccountId,
+		account: &T::AccountId,
+		level: VerificationLevel,
+	) -> Result<(), DispatchError> {
+		ensure!(Self::is_registrar(registrar), Error::<T>::NotRegistrar);
+		ensure!(level != VerificationLevel::Unverified, Error::<T>::InvalidJudgement);
+		ensure!(registrar != account, Error::<T>::CannotJudgeSelf);
+
+		let judgement = Judgement { registrar: registrar.clone(), level };
+		let mut judgements = Self::get_judgements(account);
+		ensure!(!judgements.contains(&judgement), Error::<T>::JudgementAlreadyProvided);
+
+		// Make persistance
+		judgements.push(judgement);
+		<Judgements<T>>::insert(account, judgements);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "Account ➡ {:?} has been judged {:?}.", account, level);
+
+		Ok(())
+	}
+
+	fn _revoke_judgement(
+		registrar: &T::AccountId,
+		account: &T::AccountId,
+		level: VerificationLevel,
+	) -> Result<(), DispatchError> {
+		let judgement = Judgement { registrar: registrar.clone(), level };
+		let mut judgements = Self::get_judgements(account);
+		let index = judgements
+			.iter()
+			.position(|provided| provided == &judgement)
+			.ok_or(Error::<T>::JudgementNotFound)?;
+
+		// Make persistance
+		judgements.swap_remove(index);
+		if judgements.is_empty() {
+			<Judgements<T>>::remove(account);
+		} else {
+			<Judgements<T>>::insert(account, judgements);
+		}
+
+		Ok(())
+	}
+}
+
+impl<T: Config> Verification<T::AccountId> for Pallet<T> {
+	fn verification_level(account: &T::AccountId) -> VerificationLevel {
+		let registrars = Self::get_registrars();
+		Self::get_judgements(account)
+			.into_iter()
+			.filter(|judgement| registrars.contains(&judgement.registrar))
+			.map(|judgement| judgement.level)
+			.max()
+			.unwrap_or(VerificationLevel::Unverified)
+	}
+}
+
+/// Auxiliar functions implementation
+impl<T: Config> Pallet<T> {
+	/// Checks whether an account is a registrar.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account identifier.
+	///
+	/// # Returns
+	///
+	/// Returns `true` if the account can attest verifications.
+	pub fn is_registrar(account: &T::AccountId) -> bool {
+		Self::get_registrars().contains(account)
+	}
+}
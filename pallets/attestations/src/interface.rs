@@ -0,0 +1,72 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::Config;
+use frame_support::sp_runtime::DispatchError;
+use pallet_places::VerificationLevel;
+
+/// Interface for Attestations pallet
+pub trait AttestationsInterface<T: Config> {
+	/// Add a registrar.
+	///
+	/// # Arguments
+	///
+	/// * `registrar` - The account identifier of the new registrar.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. It fails if the
+	/// account is already a registrar.
+	fn _add_registrar(registrar: &T::AccountId) -> Result<(), DispatchError>;
+
+	/// Remove a registrar.
+	///
+	/// # Arguments
+	///
+	/// * `registrar` - The account identifier of the registrar.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. It fails if the
+	/// account is not a registrar.
+	fn _remove_registrar(registrar: &T::AccountId) -> Result<(), DispatchError>;
+
+	/// Store a judgement provided by a registrar.
+	///
+	/// This function attests the verification `level` of an `account`. Only registrars can
+	/// provide judgements, never for themselves, and each judgement can only be provided once by
+	/// the same registrar.
+	///
+	/// # Arguments
+	///
+	/// * `registrar` - The account identifier of the registrar.
+	/// * `account` - The account identifier of the judged account.
+	/// * `level` - The verification attested.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains no data. Otherwise, it contains a `DispatchError`
+	/// explaining the reason for failure.
+	fn _provide_judgement(
+		registrar: &T::AccountId,
+		account: &T::AccountId,
+		level: VerificationLevel,
+	) -> Result<(), DispatchError>;
+
+	/// Remove a judgement provided by a registrar.
+	///
+	/// # Arguments
+	///
+	/// * `registrar` - The account identifier of the registrar.
+	/// * `account` - The account identifier of the judged account.
+	/// * `level` - The verification to revoke.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. It fails if the
+	/// registrar did not provide the judgement.
+	fn _revoke_judgement(
+		registrar: &T::AccountId,
+		account: &T::AccountId,
+		level: VerificationLevel,
+	) -> Result<(), DispatchError>;
+}
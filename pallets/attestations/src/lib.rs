@@ -0,0 +1,205 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub mod functions;
+pub mod interface;
+pub mod structures;
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use super::*;
+	use crate::interface::AttestationsInterface;
+	use frame_support::{
+		pallet_prelude::{ValueQuery, *},
+		sp_std::prelude::*,
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_places::VerificationLevel;
+
+	pub use structures::*;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info] // This allows us to use unsafe storages, at some point we might need bounded storages
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin allowed to add and remove registrars.
+		type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+	}
+
+	/// Stores the accounts trusted to attest the verification of hosts and guests.
+	#[pallet::storage]
+	#[pallet::getter(fn get_registrars)]
+	pub type Registrars<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+	/// Stores the judgements provided by the registrars for every account.
+	#[pallet::storage]
+	#[pallet::getter(fn get_judgements)]
+	pub type Judgements<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, Vec<Judgement<T::AccountId>>, ValueQuery>;
+
+	// Pallets use events to inform users when important changes are made.
+	// https://docs.substrate.io/main-docs/build/events-errors/
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new registrar can attest verifications
+		RegistrarAdded { registrar: T::AccountId },
+		/// A registrar has been removed. Its judgements no longer count
+		RegistrarRemoved { registrar: T::AccountId },
+		/// A registrar has attested the verification of an account
+		JudgementProvided {
+			account: T::AccountId,
+			registrar: T::AccountId,
+			level: VerificationLevel,
+		},
+		/// A registrar has revoked a judgement of an account
+		JudgementRevoked {
+			account: T::AccountId,
+			registrar: T::AccountId,
+			level: VerificationLevel,
+		},
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already a registrar
+		AlreadyRegistrar,
+		/// The account is not a registrar
+		NotRegistrar,
+		/// An unverified judgement cannot be provided
+		InvalidJudgement,
+		/// A registrar cannot judge itself
+		CannotJudgeSelf,
+		/// The registrar has already provided this judgement for the account
+		JudgementAlreadyProvided,
+		/// The registrar has not provided this judgement for the account
+		JudgementNotFound,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Add a registrar.
+		///
+		/// This extrinsic allows the `RegistrarOrigin` to trust a new account to attest the
+		/// verification of hosts and guests.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin managing the registrars.
+		/// * `registrar` - The account to add as registrar.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the registrar is added, and the `DispatchResult` contains no
+		/// error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(1)]
+		pub fn add_registrar(origin: OriginFor<T>, registrar: T::AccountId) -> DispatchResult {
+			// Check origin
+			T::RegistrarOrigin::ensure_origin(origin)?;
+			Self::_add_registrar(&registrar)?;
+			// Deposit our "RegistrarAdded" event.
+			Self::deposit_event(Event::RegistrarAdded { registrar });
+			Ok(())
+		}
+
+		/// Remove a registrar.
+		///
+		/// This extrinsic allows the `RegistrarOrigin` to stop trusting a registrar. The
+		/// judgements it provided are kept, but they no longer count towards the verification
+		/// level of the accounts.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin managing the registrars.
+		/// * `registrar` - The registrar to remove.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the registrar is removed, and the `DispatchResult` contains
+		/// no error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(2)]
+		pub fn remove_registrar(origin: OriginFor<T>, registrar: T::AccountId) -> DispatchResult {
+			// Check origin
+			T::RegistrarOrigin::ensure_origin(origin)?;
+			Self::_remove_registrar(&registrar)?;
+			// Deposit our "RegistrarRemoved" event.
+			Self::deposit_event(Event::RegistrarRemoved { registrar });
+			Ok(())
+		}
+
+		/// Attest the verification of an account.
+		///
+		/// This extrinsic allows a registrar to provide a judgement, such as a verified phone or
+		/// identity document, for a host or guest. The verification level of the account is the
+		/// highest of its judgements.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The registrar providing the judgement.
+		/// * `account` - The account verified.
+		/// * `level` - The verification attested.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the judgement is stored, and the `DispatchResult` contains no
+		/// error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(3)]
+		pub fn provide_judgement(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			level: VerificationLevel,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+			Self::_provide_judgement(&sender, &account, level)?;
+			// Deposit our "JudgementProvided" event.
+			Self::deposit_event(Event::JudgementProvided { account, registrar: sender, level });
+			Ok(())
+		}
+
+		/// Revoke a judgement of an account.
+		///
+		/// This extrinsic allows a registrar to revoke a judgement it provided, for example when
+		/// the verified document expires.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The registrar that provided the judgement.
+		/// * `account` - The account judged.
+		/// * `level` - The verification to revoke.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the judgement is removed, and the `DispatchResult` contains
+		/// no error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(4)]
+		pub fn revoke_judgement(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			level: VerificationLevel,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+			Self::_revoke_judgement(&sender, &account, level)?;
+			// Deposit our "JudgementRevoked" event.
+			Self::deposit_event(Event::JudgementRevoked { account, registrar: sender, level });
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,14 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use pallet_places::VerificationLevel;
+use scale_info::TypeInfo;
+
+/// Verification attested by a registrar for an account.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub struct Judgement<AccountId> {
+	/// The registrar that provided the judgement.
+	pub registrar: AccountId,
+	/// The verification attested, such as a verified phone or identity document.
+	pub level: VerificationLevel,
+}
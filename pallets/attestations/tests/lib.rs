@@ -0,0 +1,190 @@
+#[cfg(test)]
+pub mod mock;
+use frame_support::{assert_noop, assert_ok};
+use pallet_attestations::{Error, Judgement};
+use pallet_places::{traits::Verification, VerificationLevel};
+use sp_runtime::DispatchError;
+
+use crate::mock::*;
+
+const REGISTRAR: u64 = 1;
+const OTHER_REGISTRAR: u64 = 2;
+const HOST: u64 = 3;
+
+fn verification_level(account: u64) -> VerificationLevel {
+	<Attestations as Verification<u64>>::verification_level(&account)
+}
+
+fn build_with_registrars() -> sp_io::TestExternalities {
+	let mut ext = build_with_default_config();
+	ext.execute_with(|| {
+		assert_ok!(Attestations::add_registrar(RuntimeOrigin::root(), REGISTRAR));
+		assert_ok!(Attestations::add_registrar(RuntimeOrigin::root(), OTHER_REGISTRAR));
+	});
+	ext
+}
+
+// ========================================================
+// Registrars Unit Tests
+// ========================================================
+#[test]
+fn test_add_registrar_should_work() {
+	build_with_default_config().execute_with(|| {
+		assert_noop!(
+			Attestations::add_registrar(RuntimeOrigin::signed(REGISTRAR), REGISTRAR),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Attestations::add_registrar(RuntimeOrigin::root(), REGISTRAR));
+		assert_eq!(Attestations::get_registrars(), vec![REGISTRAR]);
+
+		assert_noop!(
+			Attestations::add_registrar(RuntimeOrigin::root(), REGISTRAR),
+			Error::<Test>::AlreadyRegistrar
+		);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_attestations::Event::RegistrarAdded { registrar: REGISTRAR }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_remove_registrar_should_work() {
+	build_with_registrars().execute_with(|| {
+		assert_ok!(Attestations::provide_judgement(
+			RuntimeOrigin::signed(REGISTRAR),
+			HOST,
+			VerificationLevel::IdVerified
+		));
+		assert_ok!(Attestations::remove_registrar(RuntimeOrigin::root(), REGISTRAR));
+		assert_eq!(Attestations::get_registrars(), vec![OTHER_REGISTRAR]);
+
+		// The judgements of a removed registrar no longer count
+		assert_eq!(verification_level(HOST), VerificationLevel::Unverified);
+		assert_noop!(
+			Attestations::remove_registrar(RuntimeOrigin::root(), REGISTRAR),
+			Error::<Test>::NotRegistrar
+		);
+	})
+}
+
+// ========================================================
+// Judgements Unit Tests
+// ========================================================
+#[test]
+fn test_provide_judgement_should_work() {
+	build_with_registrars().execute_with(|| {
+		assert_ok!(Attestations::provide_judgement(
+			RuntimeOrigin::signed(REGISTRAR),
+			HOST,
+			VerificationLevel::PhoneVerified
+		));
+		assert_eq!(verification_level(HOST), VerificationLevel::PhoneVerified);
+
+		// The highest judgement sets the verification level
+		assert_ok!(Attestations::provide_judgement(
+			RuntimeOrigin::signed(OTHER_REGISTRAR),
+			HOST,
+			VerificationLevel::IdVerified
+		));
+		assert_ok!(Attestations::provide_judgement(
+			RuntimeOrigin::signed(REGISTRAR),
+			HOST,
+			VerificationLevel::EmailVerified
+		));
+		assert_eq!(verification_level(HOST), VerificationLevel::IdVerified);
+		assert_eq!(Attestations::get_judgements(HOST).len(), 3);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_attestations::Event::JudgementProvided {
+				account: HOST,
+				registrar: REGISTRAR,
+				level: VerificationLevel::EmailVerified,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn test_provide_invalid_judgement_should_fail() {
+	build_with_registrars().execute_with(|| {
+		assert_noop!(
+			Attestations::provide_judgement(
+				RuntimeOrigin::signed(HOST),
+				HOST,
+				VerificationLevel::IdVerified
+			),
+			Error::<Test>::NotRegistrar
+		);
+		assert_noop!(
+			Attestations::provide_judgement(
+				RuntimeOrigin::signed(REGISTRAR),
+				HOST,
+				VerificationLevel::Unverified
+			),
+			Error::<Test>::InvalidJudgement
+		);
+		assert_noop!(
+			Attestations::provide_judgement(
+				RuntimeOrigin::signed(REGISTRAR),
+				REGISTRAR,
+				VerificationLevel::IdVerified
+			),
+			Error::<Test>::CannotJudgeSelf
+		);
+
+		assert_ok!(Attestations::provide_judgement(
+			RuntimeOrigin::signed(REGISTRAR),
+			HOST,
+			VerificationLevel::IdVerified
+		));
+		assert_noop!(
+			Attestations::provide_judgement(
+				RuntimeOrigin::signed(REGISTRAR),
+				HOST,
+				VerificationLevel::IdVerified
+			),
+			Error::<Test>::JudgementAlreadyProvided
+		);
+	})
+}
+
+#[test]
+fn test_revoke_judgement_should_work() {
+	build_with_registrars().execute_with(|| {
+		assert_ok!(Attestations::provide_judgement(
+			RuntimeOrigin::signed(REGISTRAR),
+			HOST,
+			VerificationLevel::PhoneVerified
+		));
+		assert_ok!(Attestations::provide_judgement(
+			RuntimeOrigin::signed(REGISTRAR),
+			HOST,
+			VerificationLevel::IdVerified
+		));
+
+		// Only the registrar that provided a judgement can revoke it
+		assert_noop!(
+			Attestations::revoke_judgement(
+				RuntimeOrigin::signed(OTHER_REGISTRAR),
+				HOST,
+				VerificationLevel::IdVerified
+			),
+			Error::<Test>::JudgementNotFound
+		);
+		assert_ok!(Attestations::revoke_judgement(
+			RuntimeOrigin::signed(REGISTRAR),
+			HOST,
+			VerificationLevel::IdVerified
+		));
+
+		assert_eq!(
+			Attestations::get_judgements(HOST),
+			vec![Judgement { registrar: REGISTRAR, level: VerificationLevel::PhoneVerified }]
+		);
+		assert_eq!(verification_level(HOST), VerificationLevel::PhoneVerified);
+	})
+}
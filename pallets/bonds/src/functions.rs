@@ -0,0 +1,143 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{
+	interface::BondsInterface, BalanceOf, Config, Error, Event, HostBond, HostBonds, Pallet,
+	Reputation,
+};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	sp_runtime::{
+		traits::{Saturating, Zero},
+		DispatchError, Perbill,
+	},
+	traits::{Get, ReservableCurrency},
+};
+use pallet_bookings::traits::HostReputation;
+use pallet_places::traits::ListingBond;
+
+impl<T: Config> BondsInterface<T> for Pallet<T> {
+	fn _slash_bond(
+		host: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let mut bond = Self::get_host_bond(host);
+		ensure!(!bond.bonded.is_zero(), Error::<T>::NoBondToSlash);
+
+		let (_, not_slashed) = T::Currency::slash_reserved(host, amount.min(bond.bonded));
+		let slashed = amount.min(bond.bonded).saturating_sub(not_slashed);
+
+		// Make persistance
+		bond.bonded = bond.bonded.saturating_sub(slashed);
+		<HostBonds<T>>::insert(host, bond);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "The bond of host ➡ {:?} has been slashed by {:?}.", host, slashed);
+
+		Ok(slashed)
+	}
+}
+
+impl<T: Config> ListingBond<T::AccountId> for Pallet<T> {
+	fn lock_listing_bond(host: &T::AccountId) -> DispatchResult {
+		let mut bond = Self::get_host_bond(host);
+		bond.listings = bond.listings.saturating_add(1);
+
+		// Slashed bonds are topped up as well
+		let required = Self::required_bond(host, bond.listings);
+		if required > bond.bonded {
+			let deficit = required.saturating_sub(bond.bonded);
+			T::Currency::reserve(host, deficit)
+				.map_err(|_| Error::<T>::InsufficientBalanceForBond)?;
+			bond.bonded = required;
+			Self::deposit_event(Event::BondLocked { host: host.clone(), amount: deficit });
+		}
+
+		// Make persistance
+		<HostBonds<T>>::insert(host, bond);
+		Ok(())
+	}
+
+	fn release_listing_bond(host: &T::AccountId) {
+		let mut bond = Self::get_host_bond(host);
+		bond.listings = bond.listings.saturating_sub(1);
+		Self::release_excess_bond(host, bond);
+	}
+}
+
+impl<T: Config> HostReputation<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn on_booking_completed(host: &T::AccountId) {
+		<Reputation<T>>::mutate(host, |reputation| *reputation = reputation.saturating_add(1));
+		// A better reputation might lower the bond required
+		Self::release_excess_bond(host, Self::get_host_bond(host));
+	}
+
+	fn on_host_cancellation(host: &T::AccountId, booking_amount: BalanceOf<T>) {
+		// The slash is proportional to the booking canceled, and limited to the bond
+		let amount = T::CancellationSlash::get().mul_floor(booking_amount);
+		if amount.is_zero() {
+			return
+		}
+		if let Ok(slashed) = Self::_slash_bond(host, amount) {
+			Self::deposit_event(Event::BondSlashed { host: host.clone(), amount: slashed });
+		}
+	}
+}
+
+/// Auxiliar functions implementation
+impl<T: Config> Pallet<T> {
+	/// Get the discount on the bond a host has earned through its reputation.
+	///
+	/// Every completed booking adds `ReputationDiscount`, up to `MaxReputationDiscount`.
+	///
+	/// # Arguments
+	///
+	/// * `host` - The account identifier of the host.
+	///
+	/// # Returns
+	///
+	/// Returns the discount applied to the bond of the host.
+	pub fn reputation_discount(host: &T::AccountId) -> Perbill {
+		let parts = T::ReputationDiscount::get()
+			.deconstruct()
+			.saturating_mul(Self::get_reputation(host));
+		Perbill::from_parts(parts).min(T::MaxReputationDiscount::get())
+	}
+
+	/// Get the bond a host needs to lock for a number of listings.
+	///
+	/// # Arguments
+	///
+	/// * `host` - The account identifier of the host.
+	/// * `listings` - The number of active listings of the host.
+	///
+	/// # Returns
+	///
+	/// Returns the `BondPerListing` of every listing, minus the reputation discount of the host.
+	pub fn required_bond(host: &T::AccountId, listings: u32) -> BalanceOf<T> {
+		let bond = T::BondPerListing::get().saturating_mul(listings.into());
+		bond.saturating_sub(Self::reputation_discount(host).mul_floor(bond))
+	}
+
+	/// Release the part of the bond of a host that exceeds the required one and persist it.
+	///
+	/// # Arguments
+	///
+	/// * `host` - The account identifier of the host.
+	/// * `bond` - The updated bond of the host.
+	fn release_excess_bond(host: &T::AccountId, mut bond: HostBond<BalanceOf<T>>) {
+		let required = Self::required_bond(host, bond.listings);
+		if bond.bonded > required {
+			let excess = bond.bonded.saturating_sub(required);
+			T::Currency::unreserve(host, excess);
+			bond.bonded = required;
+			Self::deposit_event(Event::BondReleased { host: host.clone(), amount: excess });
+		}
+
+		// Make persistance
+		if bond == HostBond::default() {
+			<HostBonds<T>>::remove(host);
+		} else {
+			<HostBonds<T>>::insert(host, bond);
+		}
+	}
+}
@@ -0,0 +1,26 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{BalanceOf, Config};
+use frame_support::sp_runtime::DispatchError;
+
+/// Interface for Bonds pallet
+pub trait BondsInterface<T: Config> {
+	/// Slash the bond of a host.
+	///
+	/// This function slashes up to `amount` from the bond reserved by the `host`. The slashed
+	/// funds are burnt. Slashing a host without bond fails.
+	///
+	/// # Arguments
+	///
+	/// * `host` - The account identifier of the host.
+	/// * `amount` - The maximum amount to slash.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the amount actually slashed. Otherwise, it contains a
+	/// `DispatchError` explaining the reason for failure.
+	fn _slash_bond(
+		host: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError>;
+}
@@ -0,0 +1,128 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub mod functions;
+pub mod interface;
+pub mod structures;
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use super::*;
+	use crate::interface::BondsInterface;
+	use frame_support::{
+		pallet_prelude::{ValueQuery, *},
+		sp_runtime::Perbill,
+		traits::{Currency, ReservableCurrency},
+	};
+	use frame_system::pallet_prelude::*;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub use structures::*;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info] // This allows us to use unsafe storages, at some point we might need bounded storages
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The Currency handler for the Bonds pallet.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The bond required for every active listing of a host without reputation.
+		#[pallet::constant]
+		type BondPerListing: Get<BalanceOf<Self>>;
+
+		/// The discount on the bond earned by a host for every completed booking.
+		#[pallet::constant]
+		type ReputationDiscount: Get<Perbill>;
+
+		/// The maximum discount on the bond a host can earn through its reputation.
+		#[pallet::constant]
+		type MaxReputationDiscount: Get<Perbill>;
+
+		/// The share of the amount of a confirmed booking slashed from the bond of its host when
+		/// the host cancels it.
+		#[pallet::constant]
+		type CancellationSlash: Get<Perbill>;
+
+		/// The origin allowed to slash bonds as the result of a dispute ruling.
+		type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+	}
+
+	/// Stores the bond of every host.
+	#[pallet::storage]
+	#[pallet::getter(fn get_host_bond)]
+	pub type HostBonds<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, HostBond<BalanceOf<T>>, ValueQuery>;
+
+	/// Stores the reputation score of every host, the number of bookings it has completed.
+	#[pallet::storage]
+	#[pallet::getter(fn get_reputation)]
+	pub type Reputation<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	// Pallets use events to inform users when important changes are made.
+	// https://docs.substrate.io/main-docs/build/events-errors/
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Part of the balance of a host has been locked as bond
+		BondLocked { host: T::AccountId, amount: BalanceOf<T> },
+		/// Part of the bond of a host is no longer required and has been released
+		BondReleased { host: T::AccountId, amount: BalanceOf<T> },
+		/// Part of the bond of a host has been slashed
+		BondSlashed { host: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The host cannot afford the bond required for one more listing
+		InsufficientBalanceForBond,
+		/// The host has no bond to slash
+		NoBondToSlash,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Slash the bond of a host as the result of a dispute ruling.
+		///
+		/// This extrinsic allows the `SlashOrigin` to slash part of the bond locked by a host. The
+		/// slashed amount is limited to the current bond. Once slashed, the host has to top up the
+		/// bond before creating new listings.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin ruling the dispute.
+		/// * `host` - The host whose bond is slashed.
+		/// * `amount` - The amount to slash.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the bond is slashed, and the `DispatchResult` contains no
+		/// error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(1)]
+		pub fn slash_bond(
+			origin: OriginFor<T>,
+			host: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			// Check origin
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			let slashed = Self::_slash_bond(&host, amount)?;
+
+			// Deposit our "Slashed" event.
+			Self::deposit_event(Event::BondSlashed { host, amount: slashed });
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,13 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Bond locked by a host to publish its listings.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug, Default)]
+pub struct HostBond<Balance> {
+	/// The number of active listings covered by the bond.
+	pub listings: u32,
+	/// The amount currently reserved from the host.
+	pub bonded: Balance,
+}
@@ -0,0 +1,173 @@
+#[cfg(test)]
+pub mod mock;
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use pallet_bonds::{Error, HostBond};
+use pallet_bookings::traits::HostReputation;
+use pallet_places::traits::ListingBond;
+use sp_runtime::DispatchError;
+
+use crate::mock::*;
+
+const HOST: u64 = 1;
+const POOR_HOST: u64 = 2;
+
+fn lock_listing_bonds(host: u64, listings: u32) {
+	for _ in 0..listings {
+		assert_ok!(<Bonds as ListingBond<u64>>::lock_listing_bond(&host));
+	}
+}
+
+fn complete_bookings(host: u64, bookings: u32) {
+	for _ in 0..bookings {
+		<Bonds as HostReputation<u64, u64>>::on_booking_completed(&host);
+	}
+}
+
+// ========================================================
+// Listing Bonds Unit Tests
+// ========================================================
+#[test]
+fn test_lock_listing_bond_should_work() {
+	build_with_funded_accounts().execute_with(|| {
+		lock_listing_bonds(HOST, 2);
+
+		assert_eq!(Bonds::get_host_bond(HOST), HostBond { listings: 2, bonded: 40 });
+		assert_eq!(Balances::reserved_balance(&HOST), 40);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bonds::Event::BondLocked { host: HOST, amount: 20 }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_lock_listing_bond_without_funds_should_fail() {
+	build_with_funded_accounts().execute_with(|| {
+		lock_listing_bonds(POOR_HOST, 1);
+
+		assert_noop!(
+			<Bonds as ListingBond<u64>>::lock_listing_bond(&POOR_HOST),
+			Error::<Test>::InsufficientBalanceForBond
+		);
+		assert_eq!(Bonds::get_host_bond(POOR_HOST), HostBond { listings: 1, bonded: 20 });
+	})
+}
+
+#[test]
+fn test_release_listing_bond_should_work() {
+	build_with_funded_accounts().execute_with(|| {
+		lock_listing_bonds(HOST, 2);
+
+		<Bonds as ListingBond<u64>>::release_listing_bond(&HOST);
+		assert_eq!(Bonds::get_host_bond(HOST), HostBond { listings: 1, bonded: 20 });
+		assert_eq!(Balances::reserved_balance(&HOST), 20);
+
+		System::assert_last_event(
+			pallet_bonds::Event::BondReleased { host: HOST, amount: 20 }.into(),
+		);
+
+		// Once the last listing is removed, the whole bond is released
+		<Bonds as ListingBond<u64>>::release_listing_bond(&HOST);
+		assert_eq!(Bonds::get_host_bond(HOST), HostBond::default());
+		assert_eq!(Balances::reserved_balance(&HOST), 0);
+	})
+}
+
+// ========================================================
+// Reputation Unit Tests
+// ========================================================
+#[test]
+fn test_reputation_lowers_required_bond() {
+	build_with_funded_accounts().execute_with(|| {
+		lock_listing_bonds(HOST, 2);
+
+		// Every completed booking lowers the bond by 10%
+		complete_bookings(HOST, 2);
+		assert_eq!(Bonds::get_reputation(HOST), 2);
+		assert_eq!(Bonds::required_bond(&HOST, 2), 32);
+		assert_eq!(Bonds::get_host_bond(HOST), HostBond { listings: 2, bonded: 32 });
+		assert_eq!(Balances::reserved_balance(&HOST), 32);
+
+		// The discount is capped at 50%
+		complete_bookings(HOST, 8);
+		assert_eq!(Bonds::get_reputation(HOST), 10);
+		assert_eq!(Bonds::get_host_bond(HOST), HostBond { listings: 2, bonded: 20 });
+
+		// New listings benefit from the discount too
+		lock_listing_bonds(HOST, 1);
+		assert_eq!(Bonds::get_host_bond(HOST), HostBond { listings: 3, bonded: 30 });
+	})
+}
+
+// ========================================================
+// Slashing Unit Tests
+// ========================================================
+#[test]
+fn test_host_cancellation_slashes_bond() {
+	build_with_funded_accounts().execute_with(|| {
+		lock_listing_bonds(HOST, 2);
+
+		<Bonds as HostReputation<u64, u64>>::on_host_cancellation(&HOST, 40);
+
+		// 25% of the amount of the booking is slashed
+		assert_eq!(Bonds::get_host_bond(HOST), HostBond { listings: 2, bonded: 30 });
+		assert_eq!(Balances::total_balance(&HOST), BASE_TOKEN_AMOUNT - 10);
+		System::assert_last_event(
+			pallet_bonds::Event::BondSlashed { host: HOST, amount: 10 }.into(),
+		);
+
+		// The slashed bond is topped up with the next listing
+		lock_listing_bonds(HOST, 1);
+		assert_eq!(Bonds::get_host_bond(HOST), HostBond { listings: 3, bonded: 60 });
+		assert_eq!(Balances::reserved_balance(&HOST), 60);
+	})
+}
+
+#[test]
+fn test_host_cancellation_slash_is_limited_to_bond() {
+	build_with_funded_accounts().execute_with(|| {
+		lock_listing_bonds(HOST, 1);
+
+		// 25% of the 400 of the booking exceeds the bond of 20
+		<Bonds as HostReputation<u64, u64>>::on_host_cancellation(&HOST, 400);
+
+		assert_eq!(Bonds::get_host_bond(HOST), HostBond { listings: 1, bonded: 0 });
+		assert_eq!(Balances::total_balance(&HOST), BASE_TOKEN_AMOUNT - 20);
+		System::assert_last_event(
+			pallet_bonds::Event::BondSlashed { host: HOST, amount: 20 }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_slash_bond_should_work() {
+	build_with_funded_accounts().execute_with(|| {
+		lock_listing_bonds(HOST, 1);
+
+		// The slashed amount is limited to the bond
+		assert_ok!(Bonds::slash_bond(RuntimeOrigin::root(), HOST, 50));
+		assert_eq!(Bonds::get_host_bond(HOST), HostBond { listings: 1, bonded: 0 });
+		assert_eq!(Balances::total_balance(&HOST), BASE_TOKEN_AMOUNT - 20);
+
+		System::assert_last_event(
+			pallet_bonds::Event::BondSlashed { host: HOST, amount: 20 }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_slash_bond_with_wrong_origin_should_fail() {
+	build_with_funded_accounts().execute_with(|| {
+		lock_listing_bonds(HOST, 1);
+
+		assert_noop!(
+			Bonds::slash_bond(RuntimeOrigin::signed(POOR_HOST), HOST, 10),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Bonds::slash_bond(RuntimeOrigin::root(), POOR_HOST, 10),
+			Error::<Test>::NoBondToSlash
+		);
+	})
+}
@@ -0,0 +1,115 @@
+#![cfg(test)]
+use frame_support::{parameter_types, traits::ConstU32};
+use frame_system::EnsureRoot;
+use pallet_bonds;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, Perbill,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Bonds: pallet_bonds,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type AccountId = u64;
+	type RuntimeCall = RuntimeCall;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	/// The type for recording an account's balance.
+	type Balance = u64;
+	/// The ubiquitous event type.
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+parameter_types! {
+	pub const BondPerListing: u64 = 20;
+	pub const ReputationDiscount: Perbill = Perbill::from_percent(10);
+	pub const MaxReputationDiscount: Perbill = Perbill::from_percent(50);
+	pub const CancellationSlash: Perbill = Perbill::from_percent(25);
+}
+
+impl pallet_bonds::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BondPerListing = BondPerListing;
+	type ReputationDiscount = ReputationDiscount;
+	type MaxReputationDiscount = MaxReputationDiscount;
+	type CancellationSlash = CancellationSlash;
+	type SlashOrigin = EnsureRoot<u64>;
+}
+
+pub const BASE_TOKEN_AMOUNT: u64 = 100;
+
+pub fn build_with_default_config(balances: Vec<(u64, u64)>) -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	GenesisConfig { balances: BalancesConfig { balances }, ..Default::default() }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	// To emit events, we need to be past block 0
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+pub fn build_with_funded_accounts() -> sp_io::TestExternalities {
+	build_with_default_config(vec![(1, BASE_TOKEN_AMOUNT), (2, 30)])
+}
@@ -1,17 +1,31 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use crate::{
 	interface::BookingsInterface,
-	structures::{BookingData, BookingHashingData},
-	BalanceOf, BookingState, BookingsData, BookingsIds, Config, Error, Pallet,
-	PendingBookingWithdraws, PlaceBookings,
+	structures::{
+		BookingData, BookingHashingData, BookingMessage, CalendarDate, CalendarEntry,
+		CalendarEntryKind, CouponData, CouponTerms, Discount, ItineraryData, ItineraryLeg,
+		ItineraryState, MessageContent, PlaceCalendar, WaitlistEntry,
+	},
+	traits::{BlockedDates, HostReputation, LoyaltyProgram, ReferralProgram},
+	ArchivedBookings, BalanceOf, BookingAddresses, BookingCoupons, BookingItineraries,
+	BookingMessages, BookingState, BookingsData, BookingsIds, Bytes, Config, CouponRedemptions,
+	Coupons, Error, Event, HostCancellations, Itineraries, LastMessages, MessageLogs,
+	MessagesCount, Pallet, PendingBalances, PendingBookingWithdraws, PendingItineraries,
+	PendingItinerariesCursor, PlaceBookings, PruningCursor, RedeemedPoints, WaitlistClaims,
+	WaitlistClaimsCursor, Waitlists,
 };
 use frame_support::{
 	ensure,
-	sp_runtime::{traits::Hash, DispatchError, SaturatedConversion},
+	pallet_prelude::{MaxEncodedLen, Weight},
+	sp_runtime::{
+		traits::{Hash, Saturating, Zero},
+		DispatchError, Perbill, SaturatedConversion,
+	},
 	sp_std::{cmp::Ordering, vec::Vec},
-	traits::{tokens::ExistenceRequirement, Currency, ReservableCurrency},
+	storage::with_storage_layer,
+	traits::{tokens::ExistenceRequirement, Currency, Get, ReservableCurrency},
 };
-use pallet_places::Error as PlacesError;
+use pallet_places::{traits::ActiveBookings, Error as PlacesError, Permissions};
 
 impl<T: Config> BookingsInterface<T> for Pallet<T> {
 	type Error = Error<T>;
@@ -19,16 +33,33 @@ impl<T: Config> BookingsInterface<T> for Pallet<T> {
 	fn _create_booking(
 		sender: T::AccountId,
 		place_id: T::Hash,
-		start_date: T::Moment,
-		end_date: T::Moment,
+		start_date: CalendarDate,
+		end_date: CalendarDate,
 		amount: BalanceOf<T>,
+		coupon: Option<Bytes>,
+		redeem_points: bool,
 	) -> Result<T::Hash, DispatchError> {
+		ensure!(start_date.is_valid() && end_date.is_valid(), Error::<T>::InvalidCalendarDate);
+		// Only the hash of the coupon code is stored on-chain
+		let coupon = coupon.map(|code| T::Hashing::hash(&code));
 		ensure!(end_date > start_date, Error::<T>::InvalidDates);
 		if let Some(place) = pallet_places::Pallet::<T>::get_place_by_id(place_id) {
 			ensure!(&place.owner != &sender, Error::<T>::CannotBookOwnedPlace);
+			ensure!(
+				pallet_places::Pallet::<T>::meets_guest_verification(&place_id, &sender),
+				Error::<T>::GuestNotVerified
+			);
 
-			let formatted_start_date = Self::modify_timestamp(start_date, place.checkin_hour)?;
-			let formatted_end_date = Self::modify_timestamp(end_date, place.checkout_hour)?;
+			let formatted_start_date = Self::calendar_date_to_moment(
+				&start_date,
+				place.checkin_hour,
+				place.utc_offset_minutes,
+			)?;
+			let formatted_end_date = Self::calendar_date_to_moment(
+				&end_date,
+				place.checkout_hour,
+				place.utc_offset_minutes,
+			)?;
 
 			let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
 			ensure!(formatted_start_date > current_moment, Error::<T>::InvalidStartDate);
@@ -36,21 +67,77 @@ impl<T: Config> BookingsInterface<T> for Pallet<T> {
 			if !Self::check_availability(place_id, formatted_start_date, formatted_end_date) {
 				return Err(Error::<T>::BookingDatesNotAvailable.into())
 			}
+			// Dates freed from an active booking are held for the notified guest of the waitlist
+			ensure!(
+				!Self::is_held_by_waitlist(
+					&place_id,
+					&Self::get_place_waitlist(place_id),
+					&sender,
+					formatted_start_date,
+					formatted_end_date
+				),
+				Error::<T>::BookingDatesNotAvailable
+			);
 
-			let expected_amount = Self::calculate_total_amount(
-				formatted_start_date,
-				formatted_end_date,
-				place.price_per_night,
-			)?;
-
-			match amount.saturated_into::<u64>().cmp(&expected_amount) {
-				Ordering::Less =>
-					return Err(DispatchError::Other("Amount provided is less that required")),
-				Ordering::Greater =>
-					return Err(DispatchError::Other("Amount provided exceeds the requested one")),
-				Ordering::Equal => {},
+			let mut expected_amount =
+				Self::calculate_total_amount(&start_date, &end_date, place.price_per_night)?;
+			// The discount of platform coupons is funded by the `PlatformAccount` on payout
+			let mut platform_discount: u64 = 0;
+			if let Some(code) = &coupon {
+				let discounted_amount = Self::apply_coupon(
+					code,
+					&place_id,
+					&place.owner,
+					&start_date,
+					&end_date,
+					expected_amount,
+					Some(&sender),
+				)?;
+				if Self::get_coupon(code).map_or(false, |coupon| coupon.issuer.is_none()) {
+					platform_discount = expected_amount.saturating_sub(discounted_amount);
+				}
+				expected_amount = discounted_amount;
+			}
+			let points_discount = if redeem_points {
+				T::Loyalty::redeemable_discount(&sender, expected_amount.saturated_into())
+			} else {
+				Zero::zero()
 			};
-			ensure!(T::Currency::can_reserve(&sender, amount), Error::<T>::NotEnoughFreeBalance);
+			let expected_amount =
+				expected_amount.saturating_sub(points_discount.saturated_into::<u64>());
+
+			// The expected amount can be queried beforehand through `quote_booking`
+			if amount.saturated_into::<u64>() != expected_amount {
+				log::debug!(target: "did", "The amount provided {:?} does not match the expected amount {:?}.", amount, expected_amount);
+				return Err(Error::<T>::AmountMismatch.into())
+			}
+
+			// With a payment schedule only the deposit is reserved now, unless the balance is
+			// already due
+			let (paid, balance_due_date) =
+				match pallet_places::Pallet::<T>::get_payment_schedule(place_id) {
+					Some(schedule) => {
+						let due_date = Self::calculate_balance_due_date(
+							formatted_start_date,
+							schedule.balance_due_days,
+						)?;
+						if due_date > current_moment {
+							(schedule.deposit.mul_floor(amount), Some(due_date))
+						} else {
+							(amount, None)
+						}
+					},
+					None => (amount, None),
+				};
+
+			// Booking the dates the guest is waiting for takes them out of the waitlist
+			if let Some(entry) =
+				Self::take_covered_waitlist_entry(&place_id, &sender, &start_date, &end_date)
+			{
+				T::Currency::unreserve(&sender, entry.reserved);
+			}
+			ensure!(T::Currency::can_reserve(&sender, paid), Error::<T>::NotEnoughFreeBalance);
+			T::Loyalty::redeem_points(&sender, points_discount)?;
 
 			let booking_data: BookingData<T> = BookingData::new(
 				place_id,
@@ -59,6 +146,8 @@ impl<T: Config> BookingsInterface<T> for Pallet<T> {
 				formatted_start_date,
 				formatted_end_date,
 				amount,
+				paid,
+				balance_due_date,
 			);
 
 			let hashing_data = BookingHashingData::from(booking_data.clone());
@@ -72,10 +161,32 @@ impl<T: Config> BookingsInterface<T> for Pallet<T> {
 			<BookingsIds<T>>::append(booking_id);
 			<PlaceBookings<T>>::mutate(place_id, |booking_list| booking_list.push(booking_id));
 			// Lock users funds and store a reference
-			T::Currency::reserve(&sender, amount)?;
+			T::Currency::reserve(&sender, paid)?;
+			if let Some(due_date) = balance_due_date {
+				<PendingBalances<T>>::insert(booking_id, due_date);
+			}
 			<PendingBookingWithdraws<T>>::mutate(&place.owner, |booking_withdraws| {
 				booking_withdraws.push((booking_id, amount));
 			});
+			if !points_discount.is_zero() {
+				<RedeemedPoints<T>>::insert(booking_id, points_discount);
+			}
+			if let Some(code) = coupon {
+				<Coupons<T>>::mutate(code, |coupon| {
+					if let Some(coupon) = coupon {
+						coupon.redemptions = coupon.redemptions.saturating_add(1)
+					}
+				});
+				<CouponRedemptions<T>>::mutate(code, &sender, |redemptions| {
+					*redemptions = redemptions.saturating_add(1)
+				});
+				<BookingCoupons<T>>::insert(booking_id, (code, platform_discount.saturated_into()));
+				Self::deposit_event(Event::CouponRedeemed {
+					code,
+					id: booking_id,
+					guest: sender.clone(),
+				});
+			}
 
 			// Logging to the console on debug level
 			log::debug!(target: "did", "A new Booking with ID ➡ {:?} has been placed.", booking_id);
@@ -89,8 +200,8 @@ impl<T: Config> BookingsInterface<T> for Pallet<T> {
 		sender: <T>::AccountId,
 		booking_id: &<T>::Hash,
 		place_id: &T::Hash,
-		start_date: <T>::Moment,
-		end_date: <T>::Moment,
+		start_date: CalendarDate,
+		end_date: CalendarDate,
 	) -> Result<<T>::Hash, DispatchError> {
 		todo!()
 	}
@@ -107,7 +218,10 @@ impl<T: Config> BookingsInterface<T> for Pallet<T> {
 		booking_id: &<T>::Hash,
 	) -> Result<<T>::Hash, DispatchError> {
 		if let Some(mut booking) = Self::get_booking_by_id(booking_id) {
-			ensure!(sender == booking.host, Error::<T>::NotPlaceOwner);
+			ensure!(
+				Self::has_place_permission(&booking, &sender, Permissions::MANAGE_BOOKINGS),
+				Error::<T>::NotPlaceOwner
+			);
 			ensure!(booking.state == BookingState::Created, Error::<T>::WrongState);
 			let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
 			ensure!(current_moment < booking.start_date, Error::<T>::CannotConfirmOutdatedBooking);
@@ -118,18 +232,16 @@ impl<T: Config> BookingsInterface<T> for Pallet<T> {
 				booking.start_date,
 				booking.end_date,
 			) {
-				Self::_do_cancel_booking(
-					booking.place_id,
-					booking_id_to_cancel,
-					booking.host.clone(),
-					booking.guest.clone(),
-					booking.amount,
-				)?;
+				Self::_do_cancel_booking(booking.place_id, booking_id_to_cancel)?;
 			}
 
 			// Make persistence
 			booking.state = BookingState::Confirmed;
 			<BookingsData<T>>::insert(booking_id, booking);
+
+			if let Some(itinerary_id) = Self::get_booking_itinerary(booking_id) {
+				Self::check_itinerary_confirmation(&itinerary_id);
+			}
 			return Ok(*booking_id)
 		}
 		Err(Error::<T>::BookingNotFound.into())
@@ -140,15 +252,54 @@ impl<T: Config> BookingsInterface<T> for Pallet<T> {
 		booking_id: &<T>::Hash,
 	) -> Result<<T>::Hash, DispatchError> {
 		if let Some(booking) = Self::get_booking_by_id(booking_id) {
-			ensure!(sender == booking.host, Error::<T>::NotPlaceOwner);
+			ensure!(
+				Self::has_place_permission(&booking, &sender, Permissions::MANAGE_BOOKINGS),
+				Error::<T>::NotPlaceOwner
+			);
 			ensure!(booking.state == BookingState::Created, Error::<T>::WrongState);
-			Self::_do_cancel_booking(
-				booking.place_id,
-				*booking_id,
-				booking.host.clone(),
-				booking.guest.clone(),
-				booking.amount,
-			)?;
+			Self::_do_cancel_booking(booking.place_id, *booking_id)?;
+			Self::serve_waitlist(booking.place_id);
+			return Ok(*booking_id)
+		}
+		Err(Error::<T>::BookingNotFound.into())
+	}
+
+	fn _host_cancel_booking(
+		sender: <T>::AccountId,
+		booking_id: &<T>::Hash,
+	) -> Result<<T>::Hash, DispatchError> {
+		if let Some(booking) = Self::get_booking_by_id(booking_id) {
+			ensure!(
+				Self::has_place_permission(&booking, &sender, Permissions::MANAGE_BOOKINGS),
+				Error::<T>::NotPlaceOwner
+			);
+			ensure!(booking.state == BookingState::Confirmed, Error::<T>::WrongState);
+
+			// The guest is fully refunded, the penalty is paid on top of it
+			Self::_do_cancel_booking(booking.place_id, *booking_id)?;
+
+			let compensation = T::HostCancellationPenalty::get().mul_floor(booking.amount);
+			if !compensation.is_zero() {
+				T::Currency::transfer(
+					&booking.host,
+					&booking.guest,
+					compensation,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				Self::deposit_event(Event::GuestCompensated {
+					id: *booking_id,
+					guest: booking.guest.clone(),
+					amount: compensation,
+				});
+			}
+
+			// Make persistence
+			<HostCancellations<T>>::mutate(&booking.host, |cancellations| {
+				*cancellations = cancellations.saturating_add(1)
+			});
+			T::HostReputation::on_host_cancellation(&booking.host, booking.amount);
+			Self::serve_waitlist(booking.place_id);
+
 			return Ok(*booking_id)
 		}
 		Err(Error::<T>::BookingNotFound.into())
@@ -161,10 +312,55 @@ impl<T: Config> BookingsInterface<T> for Pallet<T> {
 		if let Some(mut booking) = Self::get_booking_by_id(booking_id) {
 			ensure!(sender == booking.guest, Error::<T>::NotPlaceGuest);
 			ensure!(booking.state == BookingState::Confirmed, Error::<T>::WrongState);
-			// For production, check start time is correct
+			ensure!(booking.balance_due_date.is_none(), Error::<T>::BalanceNotPaid);
+			// The start date is already set to the checkin hour in the local time of the place
 			let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
 			ensure!(current_moment >= booking.start_date, Error::<T>::CheckinNotAvailableYet);
 			// Make persistence
+			booking.state = BookingState::CheckedIn;
+			<BookingsData<T>>::insert(booking_id, booking);
+
+			return Ok(*booking_id)
+		}
+		Err(Error::<T>::BookingNotFound.into())
+	}
+
+	fn _checkout(
+		sender: <T>::AccountId,
+		booking_id: &<T>::Hash,
+	) -> Result<<T>::Hash, DispatchError> {
+		if let Some(mut booking) = Self::get_booking_by_id(booking_id) {
+			ensure!(booking.state == BookingState::CheckedIn, Error::<T>::WrongState);
+			// Past the checkout hour anyone can record the automatic checkout
+			ensure!(
+				sender == booking.guest || Self::is_stay_over(&booking),
+				Error::<T>::NotPlaceGuest
+			);
+			// Make persistence
+			booking.state = BookingState::CheckedOut;
+			<BookingsData<T>>::insert(booking_id, booking);
+
+			return Ok(*booking_id)
+		}
+		Err(Error::<T>::BookingNotFound.into())
+	}
+
+	fn _acknowledge_checkout(
+		sender: <T>::AccountId,
+		booking_id: &<T>::Hash,
+	) -> Result<<T>::Hash, DispatchError> {
+		if let Some(mut booking) = Self::get_booking_by_id(booking_id) {
+			ensure!(
+				Self::has_place_permission(&booking, &sender, Permissions::MANAGE_BOOKINGS),
+				Error::<T>::NotPlaceOwner
+			);
+			match booking.state {
+				BookingState::CheckedOut => (),
+				BookingState::CheckedIn =>
+					ensure!(Self::is_stay_over(&booking), Error::<T>::CheckoutNotAvailableYet),
+				_ => return Err(Error::<T>::WrongState.into()),
+			}
+			// Make persistence
 			booking.state = BookingState::OwnerCanWithdraw;
 			<BookingsData<T>>::insert(booking_id, booking);
 
@@ -173,6 +369,56 @@ impl<T: Config> BookingsInterface<T> for Pallet<T> {
 		Err(Error::<T>::BookingNotFound.into())
 	}
 
+	fn _mark_no_show(
+		sender: <T>::AccountId,
+		booking_id: &<T>::Hash,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		if let Some(mut booking) = Self::get_booking_by_id(booking_id) {
+			ensure!(
+				Self::has_place_permission(&booking, &sender, Permissions::MANAGE_BOOKINGS),
+				Error::<T>::NotPlaceOwner
+			);
+			ensure!(booking.state == BookingState::Confirmed, Error::<T>::WrongState);
+
+			let policy = pallet_places::Pallet::<T>::get_no_show_policy(booking.place_id)
+				.unwrap_or_default();
+			let no_show_date =
+				Self::calculate_no_show_date(booking.start_date, policy.grace_period_hours)?;
+			let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
+			ensure!(current_moment >= no_show_date, Error::<T>::NoShowNotAvailableYet);
+
+			let host_amount = policy.host_share.mul_floor(booking.paid);
+			let guest_amount = booking.paid.saturating_sub(host_amount);
+			Self::settle_redemptions(booking_id, &booking, policy.host_share)?;
+
+			// Make persistence
+			<PlaceBookings<T>>::mutate(booking.place_id, |booking_list| {
+				booking_list.retain(|bid| bid != booking_id)
+			});
+			<BookingAddresses<T>>::remove(booking_id);
+
+			// Each party withdraws its part of the amount paid
+			Self::take_pending_withdraw(&booking.host, booking_id);
+			if !host_amount.is_zero() {
+				<PendingBookingWithdraws<T>>::mutate(&booking.host, |booking_withdraws| {
+					booking_withdraws.push((*booking_id, host_amount))
+				});
+			}
+			if !guest_amount.is_zero() {
+				<PendingBookingWithdraws<T>>::mutate(&booking.guest, |booking_withdraws| {
+					booking_withdraws.push((*booking_id, guest_amount))
+				});
+			}
+
+			booking.state =
+				if booking.paid.is_zero() { BookingState::Completed } else { BookingState::NoShow };
+			<BookingsData<T>>::insert(booking_id, booking);
+
+			return Ok((host_amount, guest_amount))
+		}
+		Err(Error::<T>::BookingNotFound.into())
+	}
+
 	fn _withdraw_booking(
 		sender: <T>::AccountId,
 		booking_id: &<T>::Hash,
@@ -183,11 +429,250 @@ impl<T: Config> BookingsInterface<T> for Pallet<T> {
 					Self::_guest_withdraw_booking(sender, booking_id), // Unreserve funds for guest
 				BookingState::Withdrawable => todo!(), // Check Refund Policy
 				BookingState::OwnerCanWithdraw => Self::_host_withdraw_booking(sender, booking_id), /* Transfer reserved funds from guest to host */
+				BookingState::NoShow => Self::_no_show_withdraw_booking(sender, booking_id), /* Each party withdraws its part */
 				_ => return Err(Error::<T>::WrongState.into()),
 			}
 		}
 		Err(Error::<T>::BookingNotFound.into())
 	}
+
+	fn _reveal_address(
+		sender: T::AccountId,
+		booking_id: &T::Hash,
+		encrypted_address: Bytes,
+	) -> Result<T::Hash, DispatchError> {
+		if let Some(booking) = Self::get_booking_by_id(booking_id) {
+			ensure!(
+				Self::has_place_permission(&booking, &sender, Permissions::MANAGE_BOOKINGS),
+				Error::<T>::NotPlaceOwner
+			);
+			ensure!(
+				matches!(booking.state, BookingState::Confirmed | BookingState::CheckedIn),
+				Error::<T>::WrongState
+			);
+			ensure!(
+				encrypted_address.len() <= T::MaxEncryptedAddressLength::get() as usize,
+				Error::<T>::EncryptedAddressTooLong
+			);
+
+			// Make persistence
+			<BookingAddresses<T>>::insert(booking_id, encrypted_address);
+
+			return Ok(*booking_id)
+		}
+		Err(Error::<T>::BookingNotFound.into())
+	}
+
+	fn _post_message(
+		sender: T::AccountId,
+		booking_id: &T::Hash,
+		content: MessageContent<T::Hash>,
+	) -> Result<u32, DispatchError> {
+		let booking = Self::get_booking_by_id(booking_id).ok_or(Error::<T>::BookingNotFound)?;
+		ensure!(
+			sender == booking.guest || sender == booking.host,
+			Error::<T>::NotBookingParticipant
+		);
+		if let MessageContent::Encrypted(encrypted) = &content {
+			ensure!(
+				encrypted.len() <= T::MaxMessageLength::get() as usize,
+				Error::<T>::MessageTooLong
+			);
+		}
+
+		let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
+		// Finished bookings only accept messages during their dispute window
+		if matches!(
+			booking.state,
+			BookingState::Rejected | BookingState::Completed | BookingState::NoShow
+		) {
+			ensure!(
+				current_moment < Self::calculate_dispute_deadline(booking.end_date)?,
+				Error::<T>::MessagingClosed
+			);
+		}
+
+		let index = Self::get_messages_count(booking_id);
+		ensure!(index < T::MaxMessagesPerBooking::get(), Error::<T>::TooManyMessages);
+		if let Some(last_message_at) = Self::get_last_message_at(booking_id, &sender) {
+			let elapsed = Self::convert_moment_to_u64_in_milliseconds(
+				current_moment.saturating_sub(last_message_at),
+			)?;
+			ensure!(elapsed >= T::MessageInterval::get(), Error::<T>::MessageRateLimited);
+		}
+
+		// Make persistence
+		<LastMessages<T>>::insert(booking_id, &sender, current_moment);
+		let message = BookingMessage::<T>::new(sender, content, current_moment);
+		// Every message is chained to the previous ones, so the log is committed on archival
+		let message_log =
+			T::Hashing::hash_of(&(Self::get_message_log(booking_id).unwrap_or_default(), &message));
+		<BookingMessages<T>>::insert(booking_id, index, message);
+		<MessagesCount<T>>::insert(booking_id, index + 1);
+		<MessageLogs<T>>::insert(booking_id, message_log);
+
+		Ok(index)
+	}
+
+	fn _join_waitlist(
+		sender: T::AccountId,
+		place_id: T::Hash,
+		start_date: CalendarDate,
+		end_date: CalendarDate,
+		reserve_funds: bool,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(start_date.is_valid() && end_date.is_valid(), Error::<T>::InvalidCalendarDate);
+		ensure!(end_date > start_date, Error::<T>::InvalidDates);
+		let place = pallet_places::Pallet::<T>::get_place_by_id(place_id)
+			.ok_or(PlacesError::<T>::PlaceNotFound)?;
+		ensure!(place.owner != sender, Error::<T>::CannotBookOwnedPlace);
+		ensure!(
+			pallet_places::Pallet::<T>::meets_guest_verification(&place_id, &sender),
+			Error::<T>::GuestNotVerified
+		);
+
+		let entry = WaitlistEntry::<T>::new(sender.clone(), start_date, end_date, Zero::zero());
+		let (formatted_start_date, formatted_end_date) =
+			Self::get_waitlist_entry_moments(&place_id, &entry)?;
+		let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
+		ensure!(formatted_start_date > current_moment, Error::<T>::InvalidStartDate);
+		ensure!(
+			!Self::check_availability(place_id, formatted_start_date, formatted_end_date),
+			Error::<T>::BookingDatesAvailable
+		);
+
+		let mut waitlist = Self::get_place_waitlist(place_id);
+		ensure!(!waitlist.iter().any(|entry| entry.guest == sender), Error::<T>::AlreadyInWaitlist);
+		ensure!((waitlist.len() as u32) < T::MaxWaitlistLength::get(), Error::<T>::WaitlistFull);
+
+		let reserved = if reserve_funds {
+			Self::quote_booking(&place_id, &start_date, &end_date, None, None)?
+		} else {
+			Zero::zero()
+		};
+		T::Currency::reserve(&sender, reserved).map_err(|_| Error::<T>::NotEnoughFreeBalance)?;
+
+		// Make persistence
+		waitlist.push(WaitlistEntry { reserved, ..entry });
+		<Waitlists<T>>::insert(place_id, waitlist);
+
+		Ok(reserved)
+	}
+
+	fn _leave_waitlist(sender: T::AccountId, place_id: T::Hash) -> Result<T::Hash, DispatchError> {
+		let entry =
+			Self::take_waitlist_entry(&place_id, &sender).ok_or(Error::<T>::NotInWaitlist)?;
+		T::Currency::unreserve(&sender, entry.reserved);
+
+		// The dates held for the guest are served to the next ones
+		if entry.claim_until.is_some() {
+			Self::serve_waitlist(place_id);
+		}
+
+		Ok(place_id)
+	}
+
+	fn _create_itinerary(
+		sender: T::AccountId,
+		legs: Vec<ItineraryLeg<T>>,
+	) -> Result<T::Hash, DispatchError> {
+		ensure!(!legs.is_empty(), Error::<T>::EmptyItinerary);
+		ensure!(legs.len() as u32 <= T::MaxItineraryLegs::get(), Error::<T>::TooManyItineraryLegs);
+		ensure!(
+			legs.windows(2).all(|pair| pair[1].start_date >= pair[0].end_date),
+			Error::<T>::OverlappingItineraryLegs
+		);
+
+		// Any leg failing its validation discards the whole itinerary
+		let mut booking_ids: Vec<T::Hash> = Vec::new();
+		for leg in legs {
+			booking_ids.push(Self::_create_booking(
+				sender.clone(),
+				leg.place_id,
+				leg.start_date,
+				leg.end_date,
+				leg.amount,
+				None,
+				false,
+			)?);
+		}
+
+		// The legs cannot be confirmed once the first one has started
+		let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
+		let first_start_date = Self::get_booking_by_id(booking_ids[0])
+			.ok_or(Error::<T>::BookingNotFound)?
+			.start_date;
+		let deadline = Self::calculate_itinerary_deadline(current_moment)?.min(first_start_date);
+
+		let itinerary_id = T::Hashing::hash_of(&(&sender, &booking_ids));
+		ensure!(!<Itineraries<T>>::contains_key(itinerary_id), Error::<T>::BookingAlreadyExists);
+
+		// Make persistence
+		for booking_id in booking_ids.iter() {
+			<BookingItineraries<T>>::insert(booking_id, itinerary_id);
+		}
+		<Itineraries<T>>::insert(itinerary_id, ItineraryData::new(sender, booking_ids, deadline));
+		<PendingItineraries<T>>::insert(itinerary_id, deadline);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "A new Itinerary with ID ➡ {:?} has been placed.", itinerary_id);
+
+		Ok(itinerary_id)
+	}
+
+	fn _create_coupon(
+		issuer: Option<T::AccountId>,
+		code: T::Hash,
+		terms: CouponTerms<T>,
+	) -> Result<T::Hash, DispatchError> {
+		ensure!(!<Coupons<T>>::contains_key(code), Error::<T>::CouponAlreadyExists);
+		ensure!(terms.valid_until > terms.valid_from, Error::<T>::InvalidCouponTerms);
+		let no_discount = match &terms.discount {
+			Discount::Percentage(share) => *share == Perbill::zero(),
+			Discount::Fixed(amount) => amount.is_zero(),
+		};
+		ensure!(!no_discount, Error::<T>::InvalidCouponTerms);
+
+		// Hosts can only run promotions in their own places
+		if let Some(issuer) = &issuer {
+			for place_id in terms.places.iter() {
+				let place = pallet_places::Pallet::<T>::get_place_by_id(place_id)
+					.ok_or(PlacesError::<T>::PlaceNotFound)?;
+				ensure!(&place.owner == issuer, Error::<T>::NotPlaceOwner);
+			}
+		}
+
+		// Make persistence
+		<Coupons<T>>::insert(code, CouponData::new(issuer, terms));
+
+		Ok(code)
+	}
+
+	fn _remove_coupon(
+		sender: Option<T::AccountId>,
+		code: T::Hash,
+	) -> Result<T::Hash, DispatchError> {
+		let coupon = Self::get_coupon(code).ok_or(Error::<T>::CouponNotFound)?;
+		ensure!(coupon.issuer == sender, Error::<T>::NotCouponIssuer);
+
+		// Make persistence
+		<Coupons<T>>::remove(code);
+
+		Ok(code)
+	}
+}
+
+impl<T: Config> ActiveBookings<T::Hash> for Pallet<T> {
+	fn has_active_bookings(place_id: &T::Hash) -> bool {
+		Self::get_place_bookings(place_id).iter().any(|booking_id| {
+			Self::get_booking_by_id(booking_id).map_or(false, |booking| {
+				matches!(
+					booking.state,
+					BookingState::Created | BookingState::Confirmed | BookingState::CheckedIn
+				)
+			})
+		})
+	}
 }
 
 /// Auxiliar functions implementation
@@ -207,8 +692,8 @@ impl<T: Config> Pallet<T> {
 	/// # Returns
 	///
 	/// Returns `true` if the place is available for booking, meaning it does not overlap with any
-	/// existing bookings. Returns `false` if the place is not available for booking, indicating an
-	/// overlap with an existing booking.
+	/// existing bookings nor with the dates blocked by the host. Returns `false` if the place is
+	/// not available for booking, indicating an overlap with an existing booking or blocked dates.
 	fn check_availability(place_id: T::Hash, start_date: T::Moment, end_date: T::Moment) -> bool {
 		let place_bookings = Self::get_place_bookings(place_id);
 		for booking_id in place_bookings {
@@ -225,109 +710,394 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
-		true
+		!Self::overlaps_blocked_dates(&place_id, start_date, end_date)
 	}
 
-	/// Get overlapping bookings for a specified place and booking period.
+	/// Check whether a stay overlaps any period blocked by the host of a place.
 	///
-	/// This function retrieves a list of booking identifiers (`Hash`) that overlap with the
-	/// provided booking period. It iterates through the existing bookings associated with the given
-	/// `place_id` and compares their start and end dates with the provided `booking_start_date` and
-	/// `booking_end_date`. If there is an overlap in the booking periods, the booking identifier is
-	/// added to the list of `bookings_to_cancel`. The `booking_id_to_confirm` is excluded from the
-	/// overlapping check, allowing the booking confirmation without conflict with itself.
+	/// The blocked dates are converted to the checkin and checkout hours of the place, so a stay
+	/// can start the day a blocked period ends.
 	///
 	/// # Arguments
 	///
-	/// * `place_id` - The unique identifier of the place for which overlapping bookings are to be
-	///   found.
-	/// * `booking_id_to_confirm` - The unique identifier of the booking to be confirmed, excluded
-	///   from the overlapping check.
-	/// * `booking_start_date` - The start date of the booking to be confirmed.
-	/// * `booking_end_date` - The end date of the booking to be confirmed.
+	/// * `place_id` - The identifier of the place.
+	/// * `start_date` - The checkin moment of the stay.
+	/// * `end_date` - The checkout moment of the stay.
 	///
 	/// # Returns
 	///
-	/// Returns a vector (`Vec`) of booking identifiers (`Hash`) representing the list of
-	/// overlapping bookings. If no overlapping bookings are found, the vector will be empty.
-	fn get_overlapping_bookings(
-		place_id: T::Hash,
-		booking_id_to_confirm: T::Hash,
-		booking_start_date: T::Moment,
-		booking_end_date: T::Moment,
-	) -> Vec<T::Hash> {
-		let mut bookings_to_cancel: Vec<T::Hash> = Vec::new();
-
-		let place_bookings = Self::get_place_bookings(place_id);
-		for booking_id in place_bookings {
-			if booking_id == booking_id_to_confirm {
-				continue
-			}
-			if let Some(booking) = Self::get_booking_by_id(booking_id) {
-				match (
-					booking_start_date.cmp(&booking.start_date),
-					booking_end_date.cmp(&booking.end_date),
-				) {
-					(Ordering::Less, Ordering::Greater) |
-					(Ordering::Equal, Ordering::Equal) |
-					(Ordering::Greater, Ordering::Less) => {
-						bookings_to_cancel.push(booking_id);
-					},
-					_ => {},
-				}
-			}
+	/// Returns `true` if the stay overlaps a blocked period, `false` otherwise.
+	fn overlaps_blocked_dates(
+		place_id: &T::Hash,
+		start_date: T::Moment,
+		end_date: T::Moment,
+	) -> bool {
+		let blocked_periods = T::BlockedDates::blocked_periods(place_id);
+		if blocked_periods.is_empty() {
+			return false
 		}
+		let Some(place) = pallet_places::Pallet::<T>::get_place_by_id(place_id) else {
+			return false
+		};
 
-		bookings_to_cancel
+		blocked_periods.iter().any(|(blocked_start, blocked_end)| {
+			match (
+				Self::calendar_date_to_moment(
+					blocked_start,
+					place.checkin_hour,
+					place.utc_offset_minutes,
+				),
+				Self::calendar_date_to_moment(
+					blocked_end,
+					place.checkout_hour,
+					place.utc_offset_minutes,
+				),
+			) {
+				(Ok(blocked_start), Ok(blocked_end)) =>
+					start_date < blocked_end && blocked_start < end_date,
+				_ => false,
+			}
+		})
 	}
 
-	/// Perform the cancellation of a booking.
+	/// Get the amount a guest has to pay to book a place between two dates.
 	///
-	/// This function cancels a specific booking identified by `booking_id`. It updates the
-	/// booking's status and releases any held funds. The cancellation is initiated by the `host` or
-	/// the `guest`, and the `amount` is returned accordingly.
+	/// The amount provided when creating a booking must match this quote exactly, so wallets can
+	/// use it to fill the amount in beforehand. The per-account limit of the coupon is only
+	/// checked when the booking is created.
 	///
 	/// # Arguments
 	///
-	/// * `place_id` - The unique identifier of the place associated with the booking to be
-	///   canceled.
-	/// * `booking_id` - The unique identifier of the booking to be canceled.
-	/// * `host` - The account identifier of the host initiating the booking cancellation.
-	/// * `guest` - The account identifier of the guest initiating the booking cancellation.
-	/// * `amount` - The payment amount associated with the booking to be canceled.
+	/// * `place_id` - The identifier of the place.
+	/// * `start_date` - The calendar date of the checkin, in the local time of the place.
+	/// * `end_date` - The calendar date of the checkout, in the local time of the place.
+	/// * `coupon` - The code of the coupon to redeem, if any.
+	/// * `redeem_points` - The account redeeming its loyalty points as a discount, if any.
 	///
 	/// # Returns
 	///
-	/// Returns a `Result` indicating the success or failure of the booking cancellation operation.
-	/// If the operation is successful, the booking is canceled, and the `Result` contains no error.
-	/// If the booking cancellation fails, the `Result` contains a `DispatchError` explaining the
-	/// reason for failure.
-	fn _do_cancel_booking(
-		place_id: T::Hash,
-		booking_id: T::Hash,
-		host: T::AccountId,
-		guest: T::AccountId,
-		amount: BalanceOf<T>,
-	) -> Result<(), DispatchError> {
-		// Make persistance
-		if let Some(mut booking_data) = Self::get_booking_by_id(booking_id) {
-			booking_data.state = BookingState::Rejected;
-			<BookingsData<T>>::insert(booking_id, booking_data);
-		}
-
-		<PlaceBookings<T>>::try_mutate(place_id, |booking_list| {
-			if let Some(ind) = booking_list.iter().position(|&bid| bid == booking_id) {
-				booking_list.swap_remove(ind);
-				return Ok(())
-			}
-			Err(())
-		})
-		.map_err(|_| <Error<T>>::BookingNotFound)?;
+	/// Returns a `Result` containing the total amount of the stay on success. If the dates are not
+	/// valid, the place does not exist or the coupon cannot be redeemed, the `Result` contains the
+	/// specific error.
+	pub fn quote_booking(
+		place_id: &T::Hash,
+		start_date: &CalendarDate,
+		end_date: &CalendarDate,
+		coupon: Option<&Bytes>,
+		redeem_points: Option<&T::AccountId>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(start_date.is_valid() && end_date.is_valid(), Error::<T>::InvalidCalendarDate);
+		ensure!(end_date > start_date, Error::<T>::InvalidDates);
+		let place = pallet_places::Pallet::<T>::get_place_by_id(place_id)
+			.ok_or(PlacesError::<T>::PlaceNotFound)?;
 
-		T::Currency::unreserve(&guest, amount);
+		let mut total_amount =
+			Self::calculate_total_amount(start_date, end_date, place.price_per_night)?;
+		if let Some(code) = coupon {
+			total_amount = Self::apply_coupon(
+				&T::Hashing::hash(code),
+				place_id,
+				&place.owner,
+				start_date,
+				end_date,
+				total_amount,
+				None,
+			)?;
+		}
+		if let Some(account) = redeem_points {
+			let points_discount =
+				T::Loyalty::redeemable_discount(account, total_amount.saturated_into());
+			total_amount = total_amount.saturating_sub(points_discount.saturated_into::<u64>());
+		}
+		Ok(total_amount.saturated_into())
+	}
 
-		<PendingBookingWithdraws<T>>::mutate(&host, |booking_withdraws| {
-			for (index, tuple) in booking_withdraws.iter().enumerate() {
+	/// Apply the discount of a coupon to the amount of a stay.
+	///
+	/// The coupon must be redeemable in the place, at the current moment and for the number of
+	/// nights of the stay, and it cannot have reached its redemption limits. Fixed discounts are
+	/// capped to the amount of the stay.
+	///
+	/// # Arguments
+	///
+	/// * `code` - The hash of the coupon code.
+	/// * `place_id` - The identifier of the place.
+	/// * `place_owner` - The owner of the place.
+	/// * `start_date` - The calendar date of the checkin.
+	/// * `end_date` - The calendar date of the checkout.
+	/// * `amount` - The total amount of the stay.
+	/// * `guest` - The account redeeming the coupon, if known, to check its own limit.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` containing the discounted amount on success. If the coupon cannot be
+	/// redeemed, the `Result` contains the specific error.
+	pub fn apply_coupon(
+		code: &T::Hash,
+		place_id: &T::Hash,
+		place_owner: &T::AccountId,
+		start_date: &CalendarDate,
+		end_date: &CalendarDate,
+		amount: u64,
+		guest: Option<&T::AccountId>,
+	) -> Result<u64, DispatchError> {
+		let coupon = Self::get_coupon(code).ok_or(Error::<T>::CouponNotFound)?;
+		let terms = coupon.terms;
+
+		// Host coupons are scoped to the places of the host
+		ensure!(
+			coupon.issuer.as_ref().map_or(true, |issuer| issuer == place_owner),
+			Error::<T>::CouponNotApplicable
+		);
+		ensure!(
+			terms.places.is_empty() || terms.places.contains(place_id),
+			Error::<T>::CouponNotApplicable
+		);
+		let nights = Self::calculate_nights(start_date, end_date)?;
+		ensure!(nights >= terms.min_nights as u64, Error::<T>::CouponNotApplicable);
+
+		let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
+		ensure!(
+			terms.valid_from <= current_moment && current_moment < terms.valid_until,
+			Error::<T>::CouponNotActive
+		);
+
+		if let Some(max_redemptions) = terms.max_redemptions {
+			ensure!(coupon.redemptions < max_redemptions, Error::<T>::CouponExhausted);
+		}
+		if let (Some(guest), Some(max_redemptions)) = (guest, terms.max_redemptions_per_account) {
+			ensure!(
+				Self::get_coupon_redemptions(code, guest) < max_redemptions,
+				Error::<T>::CouponExhausted
+			);
+		}
+
+		let discount = match terms.discount {
+			Discount::Percentage(share) => share.mul_floor(amount),
+			Discount::Fixed(value) => value.saturated_into::<u64>().min(amount),
+		};
+		Ok(amount.saturating_sub(discount))
+	}
+
+	/// Checks whether a place can be booked between two dates.
+	///
+	/// The dates are converted to the checkin and checkout hours of the place before checking its
+	/// availability, the same way they are converted when a booking is created. This is the check
+	/// used by the places search to filter by availability.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the place.
+	/// * `start_date` - The start date of the stay.
+	/// * `end_date` - The end date of the stay.
+	///
+	/// # Returns
+	///
+	/// Returns `true` if the place exists and is available, `false` otherwise.
+	pub fn is_place_available(
+		place_id: &T::Hash,
+		start_date: &CalendarDate,
+		end_date: &CalendarDate,
+	) -> bool {
+		let Some(place) = pallet_places::Pallet::<T>::get_place_by_id(place_id) else {
+			return false
+		};
+		if end_date <= start_date {
+			return false
+		}
+		match (
+			Self::calendar_date_to_moment(start_date, place.checkin_hour, place.utc_offset_minutes),
+			Self::calendar_date_to_moment(end_date, place.checkout_hour, place.utc_offset_minutes),
+		) {
+			(Ok(start_date), Ok(end_date)) =>
+				Self::check_availability(*place_id, start_date, end_date),
+			_ => false,
+		}
+	}
+
+	/// Get the messages posted to the channel of a booking.
+	///
+	/// # Arguments
+	///
+	/// * `booking_id` - The identifier of the booking.
+	///
+	/// # Returns
+	///
+	/// Returns the messages in the order they were posted.
+	pub fn booking_messages(booking_id: &T::Hash) -> Vec<BookingMessage<T>> {
+		(0..Self::get_messages_count(booking_id))
+			.filter_map(|index| Self::get_booking_message(booking_id, index))
+			.collect()
+	}
+
+	/// Get the booked and blocked periods of a place, to export them as a calendar.
+	///
+	/// Only the bookings confirmed by the host and not yet paid out are included. The periods do
+	/// not include the guests, and their identifiers are derived from the place and the booking
+	/// or the blocked dates, so they are stable across exports. As they only depend on public
+	/// data, anyone can link the booked periods to their bookings.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the place.
+	///
+	/// # Returns
+	///
+	/// Returns the calendar of the place, or `None` if the place does not exist.
+	pub fn place_calendar(place_id: &T::Hash) -> Option<PlaceCalendar<T::Hash>> {
+		let place = pallet_places::Pallet::<T>::get_place_by_id(place_id)?;
+
+		let booked = Self::get_place_bookings(place_id).into_iter().filter_map(|booking_id| {
+			let booking = Self::get_booking_by_id(booking_id)?;
+			match booking.state {
+				BookingState::Confirmed |
+				BookingState::CheckedIn |
+				BookingState::CheckedOut |
+				BookingState::OwnerCanWithdraw => Some(CalendarEntry {
+					uid: T::Hashing::hash_of(&(place_id, booking_id)),
+					kind: CalendarEntryKind::Booked,
+					start: Self::convert_moment_to_u64_in_milliseconds(booking.start_date).ok()?,
+					end: Self::convert_moment_to_u64_in_milliseconds(booking.end_date).ok()?,
+				}),
+				_ => None,
+			}
+		});
+		let blocked = T::BlockedDates::blocked_periods(place_id).into_iter().filter_map(
+			|(start_date, end_date)| {
+				let start = Self::calendar_date_to_moment(
+					&start_date,
+					place.checkin_hour,
+					place.utc_offset_minutes,
+				)
+				.and_then(Self::convert_moment_to_u64_in_milliseconds)
+				.ok()?;
+				let end = Self::calendar_date_to_moment(
+					&end_date,
+					place.checkout_hour,
+					place.utc_offset_minutes,
+				)
+				.and_then(Self::convert_moment_to_u64_in_milliseconds)
+				.ok()?;
+				Some(CalendarEntry {
+					uid: T::Hashing::hash_of(&(place_id, start_date, end_date)),
+					kind: CalendarEntryKind::Blocked,
+					start,
+					end,
+				})
+			},
+		);
+
+		let mut entries: Vec<CalendarEntry<T::Hash>> = booked.chain(blocked).collect();
+		entries.sort_by_key(|entry| (entry.start, entry.end));
+
+		Some(PlaceCalendar {
+			name: place.name,
+			checkin_hour: place.checkin_hour,
+			checkout_hour: place.checkout_hour,
+			utc_offset_minutes: place.utc_offset_minutes,
+			entries,
+		})
+	}
+
+	/// Get overlapping bookings for a specified place and booking period.
+	///
+	/// This function retrieves a list of booking identifiers (`Hash`) that overlap with the
+	/// provided booking period. It iterates through the existing bookings associated with the given
+	/// `place_id` and compares their start and end dates with the provided `booking_start_date` and
+	/// `booking_end_date`. If there is an overlap in the booking periods, the booking identifier is
+	/// added to the list of `bookings_to_cancel`. The `booking_id_to_confirm` is excluded from the
+	/// overlapping check, allowing the booking confirmation without conflict with itself.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The unique identifier of the place for which overlapping bookings are to be
+	///   found.
+	/// * `booking_id_to_confirm` - The unique identifier of the booking to be confirmed, excluded
+	///   from the overlapping check.
+	/// * `booking_start_date` - The start date of the booking to be confirmed.
+	/// * `booking_end_date` - The end date of the booking to be confirmed.
+	///
+	/// # Returns
+	///
+	/// Returns a vector (`Vec`) of booking identifiers (`Hash`) representing the list of
+	/// overlapping bookings. If no overlapping bookings are found, the vector will be empty.
+	fn get_overlapping_bookings(
+		place_id: T::Hash,
+		booking_id_to_confirm: T::Hash,
+		booking_start_date: T::Moment,
+		booking_end_date: T::Moment,
+	) -> Vec<T::Hash> {
+		let mut bookings_to_cancel: Vec<T::Hash> = Vec::new();
+
+		let place_bookings = Self::get_place_bookings(place_id);
+		for booking_id in place_bookings {
+			if booking_id == booking_id_to_confirm {
+				continue
+			}
+			if let Some(booking) = Self::get_booking_by_id(booking_id) {
+				match (
+					booking_start_date.cmp(&booking.start_date),
+					booking_end_date.cmp(&booking.end_date),
+				) {
+					(Ordering::Less, Ordering::Greater) |
+					(Ordering::Equal, Ordering::Equal) |
+					(Ordering::Greater, Ordering::Less) => {
+						bookings_to_cancel.push(booking_id);
+					},
+					_ => {},
+				}
+			}
+		}
+
+		bookings_to_cancel
+	}
+
+	/// Perform the cancellation of a booking.
+	///
+	/// This function cancels a specific booking identified by `booking_id`. It updates the
+	/// booking's status and releases any held funds. The part of the amount already paid by the
+	/// guest is returned accordingly.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The unique identifier of the place associated with the booking to be
+	///   canceled.
+	/// * `booking_id` - The unique identifier of the booking to be canceled.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the booking cancellation operation.
+	/// If the operation is successful, the booking is canceled, and the `Result` contains no error.
+	/// If the booking cancellation fails, the `Result` contains a `DispatchError` explaining the
+	/// reason for failure.
+	fn _do_cancel_booking(place_id: T::Hash, booking_id: T::Hash) -> Result<(), DispatchError> {
+		let mut booking_data =
+			Self::get_booking_by_id(booking_id).ok_or(Error::<T>::BookingNotFound)?;
+		let host = booking_data.host.clone();
+		let guest = booking_data.guest.clone();
+		let paid = booking_data.paid;
+
+		// Make persistance
+		booking_data.state = BookingState::Rejected;
+		<BookingsData<T>>::insert(booking_id, booking_data);
+
+		<PlaceBookings<T>>::try_mutate(place_id, |booking_list| {
+			if let Some(ind) = booking_list.iter().position(|&bid| bid == booking_id) {
+				booking_list.swap_remove(ind);
+				return Ok(())
+			}
+			Err(())
+		})
+		.map_err(|_| <Error<T>>::BookingNotFound)?;
+
+		T::Currency::unreserve(&guest, paid);
+		<BookingAddresses<T>>::remove(booking_id);
+		<PendingBalances<T>>::remove(booking_id);
+
+		Self::restore_redemptions(&booking_id, &guest);
+
+		<PendingBookingWithdraws<T>>::mutate(&host, |booking_withdraws| {
+			for (index, tuple) in booking_withdraws.iter().enumerate() {
 				// Check if the first element of the tuple matches the target value
 				if tuple.0 == booking_id {
 					// Perform the swap_remove operation
@@ -338,14 +1108,22 @@ impl<T: Config> Pallet<T> {
 		});
 
 		<PendingBookingWithdraws<T>>::mutate(&guest, |booking_withdraws| {
-			booking_withdraws.push((booking_id, amount))
+			booking_withdraws.push((booking_id, paid))
 		});
 
+		// Canceling a leg of an itinerary not confirmed yet cancels the whole itinerary
+		if let Some(itinerary_id) = Self::get_booking_itinerary(booking_id) {
+			Self::cancel_itinerary(&itinerary_id)?;
+		}
+
 		Ok(())
 	}
 
 	/// Perform the withdrawal as the OWNER of the place.
 	///
+	/// Any account with the `WITHDRAW_PAYOUT` permission in the place can trigger it, and the
+	/// funds are split between the owner and the managers following the place payout shares.
+	///
 	/// # Arguments
 	///
 	/// * `sender` - The caller of the function.
@@ -361,16 +1139,21 @@ impl<T: Config> Pallet<T> {
 		booking_id: &T::Hash,
 	) -> Result<T::Hash, DispatchError> {
 		if let Some(mut booking_data) = Self::get_booking_by_id(booking_id) {
-			ensure!(sender == booking_data.host, Error::<T>::NotPlaceOwner);
+			ensure!(
+				Self::has_place_permission(&booking_data, &sender, Permissions::WITHDRAW_PAYOUT),
+				Error::<T>::NotPlaceOwner
+			);
 
 			// Try to withdraw first
-			T::Currency::unreserve(&booking_data.guest, booking_data.amount);
-			T::Currency::transfer(
+			T::Currency::unreserve(&booking_data.guest, booking_data.paid);
+			Self::distribute_payout(
+				booking_id,
+				&booking_data,
 				&booking_data.guest,
-				&booking_data.host,
 				booking_data.amount,
-				ExistenceRequirement::KeepAlive,
 			)?;
+			Self::settle_redemptions(booking_id, &booking_data, Perbill::one())?;
+			Self::reward_referrers(booking_id, &booking_data);
 
 			// Now persist new state
 			<PendingBookingWithdraws<T>>::mutate(&booking_data.host, |booking_withdraws| {
@@ -393,8 +1176,14 @@ impl<T: Config> Pallet<T> {
 			})
 			.map_err(|_| <Error<T>>::BookingNotFound)?;
 
+			let host = booking_data.host.clone();
+			let guest = booking_data.guest.clone();
+			let amount = booking_data.amount;
 			booking_data.state = BookingState::Completed;
 			<BookingsData<T>>::insert(booking_id, booking_data);
+			<BookingAddresses<T>>::remove(booking_id);
+			T::HostReputation::on_booking_completed(&host);
+			T::Loyalty::on_booking_completed(&guest, amount);
 
 			return Ok(*booking_id)
 		}
@@ -421,20 +1210,15 @@ impl<T: Config> Pallet<T> {
 		if let Some(mut booking_data) = Self::get_booking_by_id(booking_id) {
 			ensure!(sender == booking_data.guest, Error::<T>::NotPlaceGuest);
 
-			// Simply unreserve the funds
-			T::Currency::unreserve(&booking_data.guest, booking_data.amount);
+			// The funds of rejected bookings were already released on their cancellation, so
+			// unreserving them again would release the funds reserved by other features, such as
+			// waitlists, bonds or inspections
+			if booking_data.state != BookingState::Rejected {
+				T::Currency::unreserve(&booking_data.guest, booking_data.paid);
+			}
 
 			// Now persist new state
-			<PendingBookingWithdraws<T>>::mutate(&booking_data.guest, |booking_withdraws| {
-				for (index, tuple) in booking_withdraws.iter().enumerate() {
-					// Check if the first element of the tuple matches the target value
-					if tuple.0 == *booking_id {
-						// Perform the swap_remove operation
-						booking_withdraws.swap_remove(index);
-						break
-					}
-				}
-			});
+			Self::take_pending_withdraw(&booking_data.guest, booking_id);
 
 			booking_data.state = BookingState::Completed;
 			<BookingsData<T>>::insert(booking_id, booking_data);
@@ -444,4 +1228,938 @@ impl<T: Config> Pallet<T> {
 
 		Err(Error::<T>::BookingNotFound.into())
 	}
+
+	/// Collect the remaining balance of the bookings that are due.
+	///
+	/// This function is called on the initialization of every block. It checks at most
+	/// `MaxScheduledChecks` pending balances, resuming where the previous block stopped. For every
+	/// booking whose balance is due, the remaining amount is reserved from the guest. If the guest
+	/// cannot afford it, the booking is canceled following the same rules as any other
+	/// cancellation, so the deposit is returned to the guest. Bookings that are no longer active
+	/// are dropped from the pending list.
+	///
+	/// # Returns
+	///
+	/// Returns the weight consumed by the collection.
+	pub fn collect_pending_balances() -> Weight {
+		let limit = T::MaxScheduledChecks::get() as usize;
+		let cursor = Self::get_pending_balances_cursor();
+		let pending_balances: Vec<(T::Hash, T::Moment)> = match cursor.clone() {
+			Some(raw_key) => <PendingBalances<T>>::iter_from(raw_key).take(limit).collect(),
+			None => <PendingBalances<T>>::iter().take(limit).collect(),
+		};
+		let mut reads: u64 = 1 + pending_balances.len() as u64;
+		let mut writes: u64 = 0;
+
+		// The next block resumes after the last balance checked, or from the start once the end
+		// of the queue is reached
+		let next_cursor = match pending_balances.last() {
+			Some((booking_id, _)) if pending_balances.len() == limit =>
+				Some(<PendingBalances<T>>::hashed_key_for(booking_id)),
+			_ => None,
+		};
+		if next_cursor != cursor {
+			<PendingBalancesCursor<T>>::set(next_cursor);
+			writes += 1;
+		}
+		if pending_balances.is_empty() {
+			return T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
+		let mut freed_places: Vec<T::Hash> = Vec::new();
+		reads += 1;
+
+		for (booking_id, due_date) in pending_balances {
+			if current_moment < due_date {
+				continue
+			}
+
+			// Make persistance
+			<PendingBalances<T>>::remove(booking_id);
+			reads += 1;
+			writes += 1;
+			let Some(mut booking_data) = Self::get_booking_by_id(booking_id) else { continue };
+			if booking_data.balance_due_date.is_none() ||
+				!matches!(booking_data.state, BookingState::Created | BookingState::Confirmed)
+			{
+				continue
+			}
+
+			let guest = booking_data.guest.clone();
+			let balance = booking_data.amount.saturating_sub(booking_data.paid);
+			if T::Currency::reserve(&guest, balance).is_ok() {
+				// Make persistance
+				booking_data.paid = booking_data.amount;
+				booking_data.balance_due_date = None;
+				<BookingsData<T>>::insert(booking_id, booking_data);
+				writes += 2;
+
+				Self::deposit_event(Event::BalanceCollected {
+					id: booking_id,
+					guest,
+					amount: balance,
+				});
+			} else {
+				// Hooks are not transactional, so the cancellation is applied atomically
+				let canceled = with_storage_layer(|| {
+					Self::_do_cancel_booking(booking_data.place_id, booking_id)
+				});
+				if canceled.is_ok() {
+					freed_places.push(booking_data.place_id);
+				}
+				reads += 2;
+				writes += 5;
+
+				Self::deposit_event(Event::BalancePaymentMissed { id: booking_id, guest });
+			}
+		}
+
+		// The bookings promoted from a waitlist are added to the pending balances
+		for place_id in freed_places {
+			Self::serve_waitlist(place_id);
+			reads += 4;
+			writes += 6;
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// Perform the withdrawal of a no-show booking.
+	///
+	/// The guest withdraws the part of the amount refunded by the no-show policy, while any
+	/// account with the `WITHDRAW_PAYOUT` permission in the place withdraws the part kept by the
+	/// host, which is split between the beneficiaries of the place. Once both parts are
+	/// withdrawn, the booking is completed.
+	///
+	/// # Arguments
+	///
+	/// * `sender` - The caller of the function.
+	/// * `booking_id` - The unique identifier of the booking to be withdrawed.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the booking withdrawal function. If
+	/// the part of the sender was withdrawn, it simply returns the same booking_id, otherwise, it
+	/// will throw an specific error.
+	fn _no_show_withdraw_booking(
+		sender: <T>::AccountId,
+		booking_id: &T::Hash,
+	) -> Result<T::Hash, DispatchError> {
+		if let Some(mut booking_data) = Self::get_booking_by_id(booking_id) {
+			let is_guest = sender == booking_data.guest;
+			ensure!(
+				is_guest ||
+					Self::has_place_permission(
+						&booking_data,
+						&sender,
+						Permissions::WITHDRAW_PAYOUT
+					),
+				Error::<T>::NotPlaceOwner
+			);
+
+			let recipient = if is_guest { &booking_data.guest } else { &booking_data.host };
+			let amount = Self::take_pending_withdraw(recipient, booking_id)
+				.ok_or(Error::<T>::NothingToWithdraw)?;
+
+			T::Currency::unreserve(&booking_data.guest, amount);
+			if !is_guest {
+				Self::distribute_payout(booking_id, &booking_data, &booking_data.guest, amount)?;
+			}
+
+			// Make persistence
+			let has_pending_withdraw = |account: &T::AccountId| {
+				Self::get_pending_booking_withdraws_by_account(account)
+					.iter()
+					.any(|(bid, _)| bid == booking_id)
+			};
+			if !has_pending_withdraw(&booking_data.host) &&
+				!has_pending_withdraw(&booking_data.guest)
+			{
+				booking_data.state = BookingState::Completed;
+				<BookingsData<T>>::insert(booking_id, booking_data);
+			}
+
+			return Ok(*booking_id)
+		}
+
+		Err(Error::<T>::BookingNotFound.into())
+	}
+
+	/// Give back the coupon and the loyalty points redeemed by a booking that has been canceled.
+	///
+	/// # Arguments
+	///
+	/// * `booking_id` - The unique identifier of the canceled booking.
+	/// * `guest` - The guest of the booking.
+	fn restore_redemptions(booking_id: &T::Hash, guest: &T::AccountId) {
+		// The loyalty points redeemed by the booking are given back
+		if let Some(points_discount) = <RedeemedPoints<T>>::take(booking_id) {
+			T::Loyalty::restore_points(guest, points_discount);
+		}
+
+		// The coupon redeemed by the booking can be redeemed again
+		if let Some((code, _)) = <BookingCoupons<T>>::take(booking_id) {
+			<Coupons<T>>::mutate(code, |coupon| {
+				if let Some(coupon) = coupon {
+					coupon.redemptions = coupon.redemptions.saturating_sub(1)
+				}
+			});
+			<CouponRedemptions<T>>::mutate(code, guest, |redemptions| {
+				*redemptions = redemptions.saturating_sub(1)
+			});
+		}
+	}
+
+	/// Settle the coupon and the loyalty points redeemed by a booking whose stay was honored,
+	/// either completed or missed by the guest.
+	///
+	/// The redemptions are kept. The discounts of a platform coupon and of the loyalty points are
+	/// paid out by the platform, not by the host, in the same share the host keeps of the amount
+	/// paid.
+	///
+	/// # Arguments
+	///
+	/// * `booking_id` - The unique identifier of the booking.
+	/// * `booking_data` - The booking to be settled.
+	/// * `host_share` - The share of the amount paid kept by the host.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the payout of the discount.
+	fn settle_redemptions(
+		booking_id: &T::Hash,
+		booking_data: &BookingData<T>,
+		host_share: Perbill,
+	) -> Result<(), DispatchError> {
+		let points_discount = <RedeemedPoints<T>>::take(booking_id).unwrap_or_default();
+		let platform_discount =
+			<BookingCoupons<T>>::take(booking_id).map_or(Zero::zero(), |(_, discount)| discount);
+		Self::distribute_payout(
+			booking_id,
+			booking_data,
+			&T::PlatformAccount::get(),
+			host_share.mul_floor(platform_discount.saturating_add(points_discount)),
+		)
+	}
+
+	/// Remove the pending withdraw of a booking from the list of an account.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account whose pending withdraws are updated.
+	/// * `booking_id` - The unique identifier of the booking.
+	///
+	/// # Returns
+	///
+	/// Returns the amount of the removed withdraw, or `None` if the account had nothing to
+	/// withdraw from the booking.
+	fn take_pending_withdraw(account: &T::AccountId, booking_id: &T::Hash) -> Option<BalanceOf<T>> {
+		<PendingBookingWithdraws<T>>::mutate(account, |booking_withdraws| {
+			let index = booking_withdraws.iter().position(|(bid, _)| bid == booking_id)?;
+			Some(booking_withdraws.swap_remove(index).1)
+		})
+	}
+
+	/// Serve the waitlist of a place after some of its dates have been freed.
+	///
+	/// The waitlist is traversed in order of arrival until a guest whose dates became available
+	/// is found. If the guest reserved the funds when joining, the booking request is created
+	/// with them, otherwise the guest is notified and the dates are held for the claim period.
+	/// Guests whose stay has already started, or whose booking request cannot be created, are
+	/// removed from the waitlist and their funds are released.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The unique identifier of the place whose dates have been freed.
+	pub fn serve_waitlist(place_id: T::Hash) {
+		let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
+		let mut waitlist = Self::get_place_waitlist(place_id);
+		let mut index = 0;
+
+		while index < waitlist.len() {
+			let entry = waitlist[index].clone();
+			let dates = Self::get_waitlist_entry_moments(&place_id, &entry);
+			let (start_date, end_date) = match dates {
+				Ok((start_date, _)) if start_date <= current_moment => {
+					T::Currency::unreserve(&entry.guest, entry.reserved);
+					waitlist.remove(index);
+					continue
+				},
+				Ok(dates) => dates,
+				Err(_) => {
+					index += 1;
+					continue
+				},
+			};
+
+			if entry.claim_until.is_some() ||
+				!Self::check_availability(place_id, start_date, end_date) ||
+				Self::is_held_by_waitlist(
+					&place_id,
+					&waitlist,
+					&entry.guest,
+					start_date,
+					end_date,
+				) {
+				index += 1;
+				continue
+			}
+
+			if entry.reserved.is_zero() {
+				let Ok(claim_until) = Self::calculate_waitlist_claim_date(current_moment) else {
+					break
+				};
+				waitlist[index].claim_until = Some(claim_until);
+				<WaitlistClaims<T>>::insert(place_id, true);
+
+				Self::deposit_event(Event::WaitlistSpotAvailable {
+					place_id,
+					guest: entry.guest,
+					claim_until,
+				});
+				break
+			}
+
+			// The booking request reserves the funds again following the payment schedule
+			waitlist.remove(index);
+			<Waitlists<T>>::insert(place_id, &waitlist);
+			T::Currency::unreserve(&entry.guest, entry.reserved);
+			let promoted = with_storage_layer(|| {
+				let amount =
+					Self::quote_booking(&place_id, &entry.start_date, &entry.end_date, None, None)?;
+				Self::_create_booking(
+					entry.guest.clone(),
+					place_id,
+					entry.start_date,
+					entry.end_date,
+					amount,
+					None,
+					false,
+				)
+			});
+
+			match promoted {
+				Ok(booking_id) => {
+					Self::deposit_event(Event::WaitlistPromoted {
+						place_id,
+						guest: entry.guest,
+						booking_id,
+					});
+					break
+				},
+				Err(_) => Self::deposit_event(Event::WaitlistLeft { place_id, guest: entry.guest }),
+			}
+		}
+
+		// Make persistence
+		<Waitlists<T>>::insert(place_id, waitlist);
+	}
+
+	/// Remove the guests of the waitlists that did not book the dates held for them in time.
+	///
+	/// This function is called on the initialization of every block. The dates held by an
+	/// expired claim are served to the next guests of the waitlist.
+	///
+	/// # Returns
+	///
+	/// Returns the weight consumed by the expiration.
+	pub fn expire_waitlist_claims() -> Weight {
+		let limit = T::MaxScheduledChecks::get() as usize;
+		let cursor = Self::get_waitlist_claims_cursor();
+		let places: Vec<T::Hash> = match cursor.clone() {
+			Some(raw_key) => <WaitlistClaims<T>>::iter_keys_from(raw_key).take(limit).collect(),
+			None => <WaitlistClaims<T>>::iter_keys().take(limit).collect(),
+		};
+		let mut reads: u64 = 1 + places.len() as u64;
+		let mut writes: u64 = 0;
+
+		// The next block resumes after the last place checked, or from the start once the end of
+		// the places is reached
+		let next_cursor = match places.last() {
+			Some(place_id) if places.len() == limit =>
+				Some(<WaitlistClaims<T>>::hashed_key_for(place_id)),
+			_ => None,
+		};
+		if next_cursor != cursor {
+			<WaitlistClaimsCursor<T>>::set(next_cursor);
+			writes += 1;
+		}
+		if places.is_empty() {
+			return T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
+		reads += 1;
+
+		for place_id in places {
+			reads += 1;
+			let mut waitlist = Self::get_place_waitlist(place_id);
+			let waitlist_length = waitlist.len();
+			waitlist.retain(|entry| match entry.claim_until {
+				Some(claim_until) if claim_until <= current_moment => {
+					Self::deposit_event(Event::WaitlistClaimExpired {
+						place_id,
+						guest: entry.guest.clone(),
+					});
+					false
+				},
+				_ => true,
+			});
+
+			if waitlist.len() < waitlist_length {
+				// Make persistance
+				<Waitlists<T>>::insert(place_id, waitlist);
+				Self::serve_waitlist(place_id);
+				reads += 4;
+				writes += 6;
+			}
+
+			reads += 1;
+			if !Self::get_place_waitlist(place_id)
+				.iter()
+				.any(|entry| entry.claim_until.is_some())
+			{
+				// Make persistance
+				<WaitlistClaims<T>>::remove(place_id);
+				writes += 1;
+			}
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// Cancel the itineraries whose legs have not been confirmed in time.
+	///
+	/// This function is called on the initialization of every block. Every leg of an expired
+	/// itinerary is canceled following the same rules as any other cancellation, so the funds
+	/// reserved are released.
+	///
+	/// # Returns
+	///
+	/// Returns the weight consumed by the expiration.
+	pub fn expire_itineraries() -> Weight {
+		let limit = T::MaxScheduledChecks::get() as usize;
+		let cursor = Self::get_pending_itineraries_cursor();
+		let pending_itineraries: Vec<(T::Hash, T::Moment)> = match cursor.clone() {
+			Some(raw_key) => <PendingItineraries<T>>::iter_from(raw_key).take(limit).collect(),
+			None => <PendingItineraries<T>>::iter().take(limit).collect(),
+		};
+		let mut reads: u64 = 1 + pending_itineraries.len() as u64;
+		let mut writes: u64 = 0;
+
+		// The next block resumes after the last itinerary checked, or from the start once the end
+		// of the queue is reached
+		let next_cursor = match pending_itineraries.last() {
+			Some((itinerary_id, _)) if pending_itineraries.len() == limit =>
+				Some(<PendingItineraries<T>>::hashed_key_for(itinerary_id)),
+			_ => None,
+		};
+		if next_cursor != cursor {
+			<PendingItinerariesCursor<T>>::set(next_cursor);
+			writes += 1;
+		}
+		if pending_itineraries.is_empty() {
+			return T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
+		reads += 1;
+
+		for (itinerary_id, deadline) in pending_itineraries {
+			if current_moment < deadline {
+				continue
+			}
+
+			reads += 1;
+			let Some(itinerary) = Self::get_itinerary_by_id(itinerary_id) else {
+				// Make persistance
+				<PendingItineraries<T>>::remove(itinerary_id);
+				writes += 1;
+				continue
+			};
+
+			// Hooks are not transactional, so the cancellation is applied atomically
+			let _ = with_storage_layer(|| Self::cancel_itinerary(&itinerary_id));
+			let legs = itinerary.bookings.len() as u64;
+			reads += 3 * legs;
+			writes += 2 + 5 * legs;
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// Cancel every leg of an itinerary that has not been confirmed yet.
+	///
+	/// The legs still active are canceled and their funds released. Confirmed or canceled
+	/// itineraries are left untouched.
+	///
+	/// # Arguments
+	///
+	/// * `itinerary_id` - The unique identifier of the itinerary to be canceled.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the cancellation.
+	fn cancel_itinerary(itinerary_id: &T::Hash) -> Result<(), DispatchError> {
+		let mut itinerary =
+			Self::get_itinerary_by_id(itinerary_id).ok_or(Error::<T>::ItineraryNotFound)?;
+		if itinerary.state != ItineraryState::Pending {
+			return Ok(())
+		}
+
+		// Make persistence
+		// The state is updated first, so the cancellation of the legs does not cancel it again
+		itinerary.state = ItineraryState::Canceled;
+		<Itineraries<T>>::insert(itinerary_id, &itinerary);
+		<PendingItineraries<T>>::remove(itinerary_id);
+
+		for booking_id in itinerary.bookings {
+			let Some(booking) = Self::get_booking_by_id(booking_id) else { continue };
+			if matches!(booking.state, BookingState::Created | BookingState::Confirmed) {
+				Self::_do_cancel_booking(booking.place_id, booking_id)?;
+			}
+		}
+
+		Self::deposit_event(Event::ItineraryCanceled { id: *itinerary_id });
+		Ok(())
+	}
+
+	/// Archive the finished bookings whose retention period has passed.
+	///
+	/// This function is called when a block has some weight left. Bookings are checked in the
+	/// order of `BookingsData`, from where the previous call stopped, up to `MaxPruningChecks`
+	/// bookings or as many as fit in `remaining_weight`. Rejected and completed bookings whose
+	/// checkout was more than `BookingRetentionPeriod` ago are archived. Removing them from
+	/// `BookingsIds` rewrites the whole list, so nothing is checked unless its size fits in
+	/// `remaining_weight` as well.
+	///
+	/// # Arguments
+	///
+	/// * `remaining_weight` - The weight available for the pruning.
+	///
+	/// # Returns
+	///
+	/// Returns the weight consumed by the pruning.
+	pub fn prune_finished_bookings(remaining_weight: Weight) -> Weight {
+		// Reading the cursor, the moment and the length of `BookingsIds`, and writing the cursor
+		let base_weight = T::DbWeight::get().reads_writes(3, 1);
+		// Checking a booking, and archiving it along with its channel and itinerary in the worst
+		// case
+		let check_weight = T::DbWeight::get().reads_writes(
+			8 + T::MaxItineraryLegs::get() as u64,
+			13 + T::MaxMessagesPerBooking::get() as u64,
+		);
+		if !base_weight.saturating_add(check_weight).all_lte(remaining_weight) {
+			return Weight::zero()
+		}
+
+		// Reading and writing the whole `BookingsIds`, once any booking is archived
+		let ids_size = <BookingsIds<T>>::decode_len()
+			.unwrap_or_default()
+			.saturating_mul(T::Hash::max_encoded_len()) as u64;
+		let ids_weight = T::DbWeight::get().reads_writes(1, 1).saturating_add(Weight::from_parts(
+			crate::BOOKINGS_IDS_WEIGHT_PER_BYTE.saturating_mul(ids_size),
+			ids_size,
+		));
+		let mut limit = 0;
+		let mut budget = base_weight.saturating_add(ids_weight);
+		while limit < T::MaxPruningChecks::get() as usize &&
+			budget.saturating_add(check_weight).all_lte(remaining_weight)
+		{
+			budget.saturating_accrue(check_weight);
+			limit += 1;
+		}
+		if limit == 0 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let cursor = Self::get_pruning_cursor();
+		let bookings: Vec<(T::Hash, BookingData<T>)> = match cursor.clone() {
+			Some(raw_key) => <BookingsData<T>>::iter_from(raw_key).take(limit).collect(),
+			None => <BookingsData<T>>::iter().take(limit).collect(),
+		};
+		let mut reads: u64 = 2 + bookings.len() as u64;
+		let mut writes: u64 = 0;
+
+		// The next block resumes after the last booking checked, or from the start once the end
+		// of the map is reached
+		let next_cursor = match bookings.last() {
+			Some((booking_id, _)) if bookings.len() == limit =>
+				Some(<BookingsData<T>>::hashed_key_for(booking_id)),
+			_ => None,
+		};
+		if next_cursor != cursor {
+			<PruningCursor<T>>::set(next_cursor);
+			writes += 1;
+		}
+		if bookings.is_empty() {
+			return T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
+		reads += 1;
+		let mut archived_ids = Vec::new();
+		let mut consumed_weight = Weight::zero();
+
+		for (booking_id, booking) in bookings {
+			let finished =
+				matches!(booking.state, BookingState::Rejected | BookingState::Completed) &&
+					Self::calculate_archival_date(booking.end_date)
+						.map_or(false, |archival_date| archival_date <= current_moment);
+			if finished {
+				consumed_weight.saturating_accrue(Self::archive_booking(&booking_id));
+				archived_ids.push(booking_id);
+			}
+		}
+
+		// Make persistence
+		if !archived_ids.is_empty() {
+			<BookingsIds<T>>::mutate(|bookings_ids| {
+				bookings_ids.retain(|booking_id| !archived_ids.contains(booking_id))
+			});
+			consumed_weight.saturating_accrue(ids_weight);
+		}
+
+		consumed_weight.saturating_add(T::DbWeight::get().reads_writes(reads, writes))
+	}
+
+	/// Remove a finished booking from the storage, keeping only a commitment to its record and
+	/// to the log of its messages.
+	///
+	/// The full record is emitted in the `BookingArchived` event, so indexers can keep the
+	/// history. The messages of the booking are removed as well, since they were already emitted
+	/// in the `MessagePosted` events and their hash chain is part of the commitment, so they can
+	/// still be proven in disputes. The booking is not removed from `BookingsIds`, which is left
+	/// to the caller.
+	///
+	/// # Arguments
+	///
+	/// * `booking_id` - The unique identifier of the booking to be archived.
+	///
+	/// # Returns
+	///
+	/// Returns the weight consumed by the archival.
+	fn archive_booking(booking_id: &T::Hash) -> Weight {
+		let Some(booking) = <BookingsData<T>>::take(booking_id) else {
+			return T::DbWeight::get().reads(1)
+		};
+		let messages_count = <MessagesCount<T>>::take(booking_id);
+		let message_log = <MessageLogs<T>>::take(booking_id).unwrap_or_default();
+		let commitment = T::Hashing::hash_of(&(&booking, message_log));
+		let mut reads: u64 = 6;
+		let mut writes: u64 = 12 + messages_count as u64;
+
+		// Make persistence
+		<ArchivedBookings<T>>::insert(booking_id, commitment);
+		let _ = <BookingMessages<T>>::clear_prefix(booking_id, messages_count, None);
+		let _ = <LastMessages<T>>::clear_prefix(booking_id, 2, None);
+		<BookingAddresses<T>>::remove(booking_id);
+		<BookingCoupons<T>>::remove(booking_id);
+		<RedeemedPoints<T>>::remove(booking_id);
+		Self::take_pending_withdraw(&booking.host, booking_id);
+		Self::take_pending_withdraw(&booking.guest, booking_id);
+
+		// The itinerary is removed along with its last leg
+		if let Some(itinerary_id) = <BookingItineraries<T>>::take(booking_id) {
+			reads += 1;
+			if let Some(itinerary) = Self::get_itinerary_by_id(itinerary_id) {
+				reads += itinerary.bookings.len() as u64;
+				let itinerary_finished = itinerary.state != ItineraryState::Pending &&
+					itinerary.bookings.iter().all(|leg| !<BookingsData<T>>::contains_key(leg));
+				if itinerary_finished {
+					<Itineraries<T>>::remove(itinerary_id);
+					writes += 1;
+				}
+			}
+		}
+
+		Self::deposit_event(Event::BookingArchived {
+			id: *booking_id,
+			booking,
+			message_log,
+			commitment,
+		});
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// Mark an itinerary as confirmed once every leg has been confirmed.
+	///
+	/// # Arguments
+	///
+	/// * `itinerary_id` - The unique identifier of the itinerary to be checked.
+	fn check_itinerary_confirmation(itinerary_id: &T::Hash) {
+		let Some(mut itinerary) = Self::get_itinerary_by_id(itinerary_id) else { return };
+		if itinerary.state != ItineraryState::Pending {
+			return
+		}
+		let fully_confirmed = itinerary.bookings.iter().all(|booking_id| {
+			Self::get_booking_by_id(booking_id)
+				.map_or(false, |booking| booking.state == BookingState::Confirmed)
+		});
+		if !fully_confirmed {
+			return
+		}
+
+		// Make persistence
+		itinerary.state = ItineraryState::Confirmed;
+		<Itineraries<T>>::insert(itinerary_id, itinerary);
+		<PendingItineraries<T>>::remove(itinerary_id);
+
+		Self::deposit_event(Event::ItineraryConfirmed { id: *itinerary_id });
+	}
+
+	/// Remove an account from the waitlist of a place.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The unique identifier of the place.
+	/// * `account` - The account to remove.
+	///
+	/// # Returns
+	///
+	/// Returns the removed entry, or `None` if the account was not waiting for the place.
+	fn take_waitlist_entry(place_id: &T::Hash, account: &T::AccountId) -> Option<WaitlistEntry<T>> {
+		<Waitlists<T>>::mutate(place_id, |waitlist| {
+			let index = waitlist.iter().position(|entry| &entry.guest == account)?;
+			Some(waitlist.remove(index))
+		})
+	}
+
+	/// Remove an account from the waitlist of a place, only if a booking covers the dates it is
+	/// waiting for.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The unique identifier of the place.
+	/// * `account` - The account to remove.
+	/// * `start_date` - The checkin date of the booking.
+	/// * `end_date` - The checkout date of the booking.
+	///
+	/// # Returns
+	///
+	/// Returns the removed entry, or `None` if the account was not waiting for those dates.
+	fn take_covered_waitlist_entry(
+		place_id: &T::Hash,
+		account: &T::AccountId,
+		start_date: &CalendarDate,
+		end_date: &CalendarDate,
+	) -> Option<WaitlistEntry<T>> {
+		<Waitlists<T>>::mutate(place_id, |waitlist| {
+			let index = waitlist.iter().position(|entry| {
+				&entry.guest == account &&
+					start_date <= &entry.start_date &&
+					&entry.end_date <= end_date
+			})?;
+			Some(waitlist.remove(index))
+		})
+	}
+
+	/// Get the checkin and checkout moments of the dates a guest of the waitlist is waiting for.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The unique identifier of the place.
+	/// * `entry` - The entry of the waitlist.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` containing the checkin and checkout moments on success. If the place
+	/// does not exist or the dates cannot be converted, it returns the specific error.
+	fn get_waitlist_entry_moments(
+		place_id: &T::Hash,
+		entry: &WaitlistEntry<T>,
+	) -> Result<(T::Moment, T::Moment), DispatchError> {
+		let place = pallet_places::Pallet::<T>::get_place_by_id(place_id)
+			.ok_or(PlacesError::<T>::PlaceNotFound)?;
+		let start_date = Self::calendar_date_to_moment(
+			&entry.start_date,
+			place.checkin_hour,
+			place.utc_offset_minutes,
+		)?;
+		let end_date = Self::calendar_date_to_moment(
+			&entry.end_date,
+			place.checkout_hour,
+			place.utc_offset_minutes,
+		)?;
+
+		Ok((start_date, end_date))
+	}
+
+	/// Checks whether some dates are held for another guest of a waitlist.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The unique identifier of the place.
+	/// * `waitlist` - The waitlist of the place.
+	/// * `account` - The account willing to book the dates.
+	/// * `start_date` - The checkin moment of the stay.
+	/// * `end_date` - The checkout moment of the stay.
+	///
+	/// # Returns
+	///
+	/// Returns `true` if the dates overlap with the dates of an open claim of another guest,
+	/// `false` otherwise.
+	fn is_held_by_waitlist(
+		place_id: &T::Hash,
+		waitlist: &[WaitlistEntry<T>],
+		account: &T::AccountId,
+		start_date: T::Moment,
+		end_date: T::Moment,
+	) -> bool {
+		let current_moment = <pallet_places::pallet_timestamp::Pallet<T>>::now();
+		waitlist.iter().any(|entry| {
+			let Some(claim_until) = entry.claim_until else { return false };
+			if &entry.guest == account || claim_until <= current_moment {
+				return false
+			}
+			match Self::get_waitlist_entry_moments(place_id, entry) {
+				Ok((held_start_date, held_end_date)) =>
+					start_date < held_end_date && held_start_date < end_date,
+				Err(_) => false,
+			}
+		})
+	}
+
+	/// Checks whether an account holds a permission over the place of a booking.
+	///
+	/// The host of the booking holds every permission, even if the place has been removed. The
+	/// rest of the accounts rely on the roles assigned in the place.
+	///
+	/// # Arguments
+	///
+	/// * `booking_data` - The booking whose place is checked.
+	/// * `account` - The account to check.
+	/// * `permission` - The permission required.
+	///
+	/// # Returns
+	///
+	/// Returns `true` if the account holds the permission, `false` otherwise.
+	fn has_place_permission(
+		booking_data: &BookingData<T>,
+		account: &T::AccountId,
+		permission: Permissions,
+	) -> bool {
+		account == &booking_data.host ||
+			pallet_places::Pallet::<T>::has_permission(
+				&booking_data.place_id,
+				account,
+				permission,
+			)
+	}
+
+	/// Checks whether the stay of a booking is over.
+	///
+	/// The end date of a booking is already set to the checkout hour in the local time of the
+	/// place, so the stay is over once the chain moment reaches it.
+	///
+	/// # Arguments
+	///
+	/// * `booking_data` - The booking to check.
+	///
+	/// # Returns
+	///
+	/// Returns `true` if the checkout hour of the booking has passed, `false` otherwise.
+	fn is_stay_over(booking_data: &BookingData<T>) -> bool {
+		<pallet_places::pallet_timestamp::Pallet<T>>::now() >= booking_data.end_date
+	}
+
+	/// Transfer an amount of a booking to the beneficiaries of the place.
+	///
+	/// The amount is split following the payout split of the place or, if there is none, the
+	/// payout shares of its roles. Every beneficiary receives the floor of its share, and the
+	/// rounding dust is kept by the first beneficiary. The transfers are applied atomically, if any
+	/// of them fails none is persisted. An event is deposited for every recipient.
+	///
+	/// # Arguments
+	///
+	/// * `booking_id` - The unique identifier of the booking to be paid out.
+	/// * `booking_data` - The booking to be paid out.
+	/// * `source` - The account paying out, usually the guest of the booking.
+	/// * `amount` - The amount to pay out. It must be already unreserved.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the transfers.
+	fn distribute_payout(
+		booking_id: &T::Hash,
+		booking_data: &BookingData<T>,
+		source: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<(), DispatchError> {
+		let shares = pallet_places::Pallet::<T>::get_payout_shares(
+			&booking_data.place_id,
+			&booking_data.host,
+		);
+
+		let mut payouts: Vec<(T::AccountId, BalanceOf<T>)> = shares
+			.into_iter()
+			.map(|(account, share)| (account, share.mul_floor(amount)))
+			.collect();
+
+		// Any rounding dust goes to the first beneficiary
+		let distributed = payouts
+			.iter()
+			.skip(1)
+			.fold(BalanceOf::<T>::zero(), |acc, (_, amount)| acc.saturating_add(*amount));
+		if let Some((_, first_amount)) = payouts.first_mut() {
+			*first_amount = amount.saturating_sub(distributed);
+		}
+
+		with_storage_layer(|| {
+			for (recipient, amount) in payouts {
+				if amount.is_zero() {
+					continue
+				}
+				T::Currency::transfer(source, &recipient, amount, ExistenceRequirement::KeepAlive)?;
+				Self::deposit_event(Event::PayoutDistributed {
+					id: *booking_id,
+					recipient,
+					amount,
+				});
+			}
+			Ok(())
+		})
+	}
+
+	/// Pay the referrers of the guest and the host of a completed booking.
+	///
+	/// The referral program is notified of every completed booking, so it counts them, and the
+	/// rewards are paid by the `PlatformAccount`. A reward that cannot be paid, such as one below
+	/// the existential deposit of an empty referrer account, is skipped, so the referral program
+	/// never blocks the payout of the host.
+	///
+	/// # Arguments
+	///
+	/// * `booking_id` - The unique identifier of the booking.
+	/// * `booking_data` - The data of the booking.
+	fn reward_referrers(booking_id: &T::Hash, booking_data: &BookingData<T>) {
+		for referred in [&booking_data.guest, &booking_data.host] {
+			let Some((referrer, reward)) =
+				T::Referrals::on_booking_completed(referred, booking_data.amount)
+			else {
+				continue
+			};
+			if reward.is_zero() {
+				continue
+			}
+			if T::Currency::transfer(
+				&T::PlatformAccount::get(),
+				&referrer,
+				reward,
+				ExistenceRequirement::KeepAlive,
+			)
+			.is_err()
+			{
+				log::debug!(target: "did", "The referral reward of {:?} could not be paid to {:?}.", referred, referrer);
+				continue
+			}
+			Self::deposit_event(Event::ReferralRewardPaid {
+				id: *booking_id,
+				referrer,
+				referred: referred.clone(),
+				amount: reward,
+			});
+		}
+	}
 }
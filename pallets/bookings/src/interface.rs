@@ -1,4 +1,4 @@
-use crate::{BalanceOf, Config};
+use crate::{BalanceOf, Bytes, CalendarDate, Config, CouponTerms, ItineraryLeg, MessageContent};
 use frame_support::sp_runtime::DispatchError;
 /// Interface for Bookings pallet
 pub trait BookingsInterface<T: Config> {
@@ -15,8 +15,11 @@ pub trait BookingsInterface<T: Config> {
 	///
 	/// * `sender` - The account identifier of the booking sender.
 	/// * `place_id` - The identifier of the place to book.
-	/// * `start_date` - The start date of the booking.
-	/// * `end_date` - The end date of the booking.
+	/// * `start_date` - The calendar date of the checkin, in the local time of the place.
+	/// * `end_date` - The calendar date of the checkout, in the local time of the place.
+	/// * `amount` - The payment amount for the booking, once discounted.
+	/// * `coupon` - The code of the coupon to redeem, if any. Only its hash is stored.
+	/// * `redeem_points` - Whether the loyalty points of the sender are redeemed as a discount.
 	///
 	/// # Returns
 	///
@@ -26,9 +29,11 @@ pub trait BookingsInterface<T: Config> {
 	fn _create_booking(
 		sender: T::AccountId,
 		place_id: T::Hash,
-		start_date: T::Moment,
-		end_date: T::Moment,
+		start_date: CalendarDate,
+		end_date: CalendarDate,
 		amount: BalanceOf<T>,
+		coupon: Option<Bytes>,
+		redeem_points: bool,
 	) -> Result<T::Hash, DispatchError>;
 
 	/// Update a booking's information.
@@ -56,8 +61,8 @@ pub trait BookingsInterface<T: Config> {
 		sender: T::AccountId,
 		booking_id: &T::Hash,
 		place_id: &T::Hash,
-		start_date: T::Moment,
-		end_date: T::Moment,
+		start_date: CalendarDate,
+		end_date: CalendarDate,
 	) -> Result<T::Hash, DispatchError>;
 
 	/// Cancel a booking.
@@ -84,7 +89,8 @@ pub trait BookingsInterface<T: Config> {
 
 	/// Confirm a Booking Request.
 	///
-	/// This function allows a host to confirm a booking request for a specific place.
+	/// This function allows a host, or an account with the `MANAGE_BOOKINGS` permission in the
+	/// place, to confirm a booking request for a specific place.
 	/// The `booking_id` parameter identifies the booking request to be confirmed.
 	/// Upon successful confirmation, the booking status changes to "Confirmed," and the booked
 	/// dates are reserved for the guest.
@@ -107,7 +113,8 @@ pub trait BookingsInterface<T: Config> {
 
 	/// Reject a Booking Request.
 	///
-	/// This function allows a host to reject a booking request for a specific place.
+	/// This function allows a host, or an account with the `MANAGE_BOOKINGS` permission in the
+	/// place, to reject a booking request for a specific place.
 	/// The `booking_id` parameter identifies the booking request to be rejected.
 	/// After rejection, the booking status changes to "Rejected," and the place becomes available
 	/// for other potential guests.
@@ -128,12 +135,35 @@ pub trait BookingsInterface<T: Config> {
 		booking_id: &T::Hash,
 	) -> Result<T::Hash, DispatchError>;
 
+	/// Cancel a Confirmed Booking as the host.
+	///
+	/// This function allows the host, or an account with the `MANAGE_BOOKINGS` permission in the
+	/// place, to cancel a confirmed booking. The amount paid by the guest is fully refunded and the
+	/// host transfers a compensation to the guest, given by the `HostCancellationPenalty` share of
+	/// the amount of the booking. The host cancellations counter is increased.
+	///
+	/// # Arguments
+	///
+	/// * `sender` - The account identifier of the sender requesting the cancellation.
+	/// * `booking_id` - The identifier of the booking to be canceled.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the cancellation operation.
+	/// If the operation is successful, the `Result` contains the unique identifier (`Hash`) for the
+	/// canceled booking. Otherwise, it contains a `DispatchError` explaining the reason for
+	/// failure.
+	fn _host_cancel_booking(
+		sender: T::AccountId,
+		booking_id: &T::Hash,
+	) -> Result<T::Hash, DispatchError>;
+
 	/// Perform Check-In for a Booking.
 	///
 	/// This function allows a guest to perform the check-in process for a confirmed booking.
 	/// The `booking_id` parameter identifies the booking for which the check-in is being performed.
 	/// After successful check-in, the guest gains access to the place for the specified booking
-	/// period and the booking state is set to `HostCanWithdraw`.
+	/// period and the booking state is set to `CheckedIn`.
 	///
 	/// # Arguments
 	///
@@ -148,6 +178,73 @@ pub trait BookingsInterface<T: Config> {
 	/// failure.
 	fn _checkin(sender: T::AccountId, booking_id: &T::Hash) -> Result<T::Hash, DispatchError>;
 
+	/// Perform Check-Out for a Booking.
+	///
+	/// This function allows a guest to perform the check-out process for a checked-in booking.
+	/// Once the checkout hour of the last day of the booking has passed, the check-out is
+	/// automatic and anyone can record it. After successful check-out, the booking state is set
+	/// to `CheckedOut` and the host is expected to acknowledge it.
+	///
+	/// # Arguments
+	///
+	/// * `sender` - The account identifier of the sender requesting the check-out.
+	/// * `booking_id` - The identifier of the booking for which the check-out is being performed.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the check-out operation.
+	/// If the operation is successful, the `Result` contains the unique identifier (`Hash`) for the
+	/// checked-out booking. Otherwise, it contains a `DispatchError` explaining the reason for
+	/// failure.
+	fn _checkout(sender: T::AccountId, booking_id: &T::Hash) -> Result<T::Hash, DispatchError>;
+
+	/// Acknowledge the Check-Out of a Booking.
+	///
+	/// This function allows the host, or an account with the `MANAGE_BOOKINGS` permission in the
+	/// place, to acknowledge that the guest has left the place. A booking that is still checked
+	/// in once the checkout hour of the last day has passed is considered checked out. After
+	/// acknowledgement, the booking state is set to `OwnerCanWithdraw` and the payout can be
+	/// released.
+	///
+	/// # Arguments
+	///
+	/// * `sender` - The account identifier of the sender acknowledging the check-out.
+	/// * `booking_id` - The identifier of the booking.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation.
+	/// If the operation is successful, the `Result` contains the unique identifier (`Hash`) for the
+	/// booking. Otherwise, it contains a `DispatchError` explaining the reason for failure.
+	fn _acknowledge_checkout(
+		sender: T::AccountId,
+		booking_id: &T::Hash,
+	) -> Result<T::Hash, DispatchError>;
+
+	/// Mark a Booking as a no-show.
+	///
+	/// This function allows the host, or an account with the `MANAGE_BOOKINGS` permission in the
+	/// place, to mark a confirmed booking whose guest never arrived as a no-show. It is only
+	/// available once the grace period of the no-show policy of the place has passed since the
+	/// checkin hour. The amount paid by the guest is split between the host and the guest
+	/// following the policy, and the booking state is set to `NoShow` until both parts are
+	/// withdrawn.
+	///
+	/// # Arguments
+	///
+	/// * `sender` - The account identifier of the sender marking the no-show.
+	/// * `booking_id` - The identifier of the booking.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation.
+	/// If the operation is successful, the `Result` contains the amounts withdrawable by the host
+	/// and the guest. Otherwise, it contains a `DispatchError` explaining the reason for failure.
+	fn _mark_no_show(
+		sender: T::AccountId,
+		booking_id: &T::Hash,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError>;
+
 	/// Withdraw a Booking.
 	///
 	/// This function allows a guest or host to withdraw a booking (canceled or completed),
@@ -171,4 +268,158 @@ pub trait BookingsInterface<T: Config> {
 		sender: T::AccountId,
 		booking_id: &T::Hash,
 	) -> Result<T::Hash, DispatchError>;
+
+	/// Reveal the address of the place of a Booking.
+	///
+	/// This function allows the host, or an account with the `MANAGE_BOOKINGS` permission in the
+	/// place, to store the exact address of the place encrypted to the public key of the guest.
+	/// It is only available for confirmed bookings and during the stay.
+	///
+	/// # Arguments
+	///
+	/// * `sender` - The account identifier of the sender revealing the address.
+	/// * `booking_id` - The identifier of the booking.
+	/// * `encrypted_address` - The address of the place, encrypted to the guest public key.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation.
+	/// If the operation is successful, the `Result` contains the unique identifier (`Hash`) for the
+	/// booking. Otherwise, it contains a `DispatchError` explaining the reason for failure.
+	fn _reveal_address(
+		sender: T::AccountId,
+		booking_id: &T::Hash,
+		encrypted_address: Bytes,
+	) -> Result<T::Hash, DispatchError>;
+
+	/// Post a message to the channel of a Booking.
+	///
+	/// This function allows the guest or the host of a booking to append a message to its
+	/// channel, as long as the content fits in `MaxMessageLength`, the channel is not full, the
+	/// sender has not posted another message in the last `MessageInterval` and, if the booking is
+	/// finished, its `DisputeWindow` has not passed.
+	///
+	/// # Arguments
+	///
+	/// * `sender` - The account identifier of the guest or the host posting the message.
+	/// * `booking_id` - The identifier of the booking.
+	/// * `content` - The hash of the content, or the encrypted content.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation.
+	/// If the operation is successful, the `Result` contains the index of the message in the
+	/// channel. Otherwise, it contains a `DispatchError` explaining the reason for failure.
+	fn _post_message(
+		sender: T::AccountId,
+		booking_id: &T::Hash,
+		content: MessageContent<T::Hash>,
+	) -> Result<u32, DispatchError>;
+
+	/// Join the waitlist of a place.
+	///
+	/// This function adds the sender at the end of the waitlist of a place for some dates that
+	/// overlap with an active booking. If `reserve_funds` is set, the amount of the stay is
+	/// reserved from the sender, so the booking request can be created automatically once the
+	/// dates are freed.
+	///
+	/// # Arguments
+	///
+	/// * `sender` - The account identifier of the guest joining the waitlist.
+	/// * `place_id` - The identifier of the place.
+	/// * `start_date` - The calendar date of the checkin, in the local time of the place.
+	/// * `end_date` - The calendar date of the checkout, in the local time of the place.
+	/// * `reserve_funds` - Whether the amount of the stay is reserved.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation.
+	/// If the operation is successful, the `Result` contains the amount reserved from the guest.
+	/// Otherwise, it contains a `DispatchError` explaining the reason for failure.
+	fn _join_waitlist(
+		sender: T::AccountId,
+		place_id: T::Hash,
+		start_date: CalendarDate,
+		end_date: CalendarDate,
+		reserve_funds: bool,
+	) -> Result<BalanceOf<T>, DispatchError>;
+
+	/// Leave the waitlist of a place.
+	///
+	/// This function removes the sender from the waitlist of a place and releases the funds
+	/// reserved when joining, if any.
+	///
+	/// # Arguments
+	///
+	/// * `sender` - The account identifier of the guest leaving the waitlist.
+	/// * `place_id` - The identifier of the place.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation.
+	/// If the operation is successful, the `Result` contains the identifier of the place.
+	/// Otherwise, it contains a `DispatchError` explaining the reason for failure.
+	fn _leave_waitlist(sender: T::AccountId, place_id: T::Hash) -> Result<T::Hash, DispatchError>;
+
+	/// Store an itinerary with a booking for each leg.
+	///
+	/// This function validates and creates a booking for every leg of the itinerary, in order,
+	/// and links them as a group. The legs must not overlap each other. The itinerary has to be
+	/// fully confirmed before its deadline, otherwise every leg is canceled.
+	///
+	/// # Arguments
+	///
+	/// * `sender` - The account identifier of the guest.
+	/// * `legs` - The legs of the itinerary, in chronological order.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the itinerary creation operation.
+	/// If the operation is successful, the `Result` contains the unique identifier (`Hash`) for
+	/// the created itinerary. Otherwise, it contains a `DispatchError` explaining the reason for
+	/// failure.
+	fn _create_itinerary(
+		sender: T::AccountId,
+		legs: Vec<ItineraryLeg<T>>,
+	) -> Result<T::Hash, DispatchError>;
+
+	/// Store a coupon.
+	///
+	/// This function creates a coupon identified by the hash of its code. Host coupons can only
+	/// list places owned by the host. The validity window cannot be empty and the discount cannot
+	/// be zero.
+	///
+	/// # Arguments
+	///
+	/// * `issuer` - The host creating the coupon, or `None` for platform coupons.
+	/// * `code` - The hash of the coupon code.
+	/// * `terms` - The discount and the conditions under which the coupon can be redeemed.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation.
+	/// If the operation is successful, the `Result` contains the hash of the coupon code.
+	/// Otherwise, it contains a `DispatchError` explaining the reason for failure.
+	fn _create_coupon(
+		issuer: Option<T::AccountId>,
+		code: T::Hash,
+		terms: CouponTerms<T>,
+	) -> Result<T::Hash, DispatchError>;
+
+	/// Remove a coupon.
+	///
+	/// # Arguments
+	///
+	/// * `sender` - The issuer of the coupon, or `None` for the platform.
+	/// * `code` - The hash of the coupon code.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation.
+	/// If the operation is successful, the `Result` contains the hash of the coupon code.
+	/// Otherwise, it contains a `DispatchError` explaining the reason for failure.
+	fn _remove_coupon(
+		sender: Option<T::AccountId>,
+		code: T::Hash,
+	) -> Result<T::Hash, DispatchError>;
 }
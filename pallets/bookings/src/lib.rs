@@ -4,15 +4,22 @@ pub use pallet::*;
 
 pub mod functions;
 pub mod interface;
+pub mod migrations;
+pub mod runtime_api;
 pub mod structures;
+pub mod traits;
 pub mod utils;
 
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
 	use super::*;
-	use crate::interface::BookingsInterface;
+	use crate::{
+		interface::BookingsInterface,
+		traits::{BlockedDates, HostReputation, LoyaltyProgram, ReferralProgram},
+	};
 	use frame_support::{
 		pallet_prelude::{ValueQuery, *},
+		sp_runtime::Perbill,
 		sp_std::prelude::*,
 		traits::{Currency, ReservableCurrency},
 	};
@@ -24,7 +31,17 @@ pub mod pallet {
 
 	pub use structures::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// The weight of hashing, storing and emitting every byte of the content of a message.
+	pub const MESSAGE_WEIGHT_PER_BYTE: u64 = 1_000;
+
+	/// The weight of decoding and encoding every byte of the list of all the bookings ids.
+	pub const BOOKINGS_IDS_WEIGHT_PER_BYTE: u64 = 1_000;
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info] // This allows us to use unsafe storages, at some point we might need bounded storages
 	pub struct Pallet<T>(_);
 
@@ -36,6 +53,86 @@ pub mod pallet {
 
 		/// The Currency handler for the Bookings pallet.
 		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The maximum length of the encrypted address revealed to the guest of a booking.
+		#[pallet::constant]
+		type MaxEncryptedAddressLength: Get<u32>;
+
+		/// The share of the amount of a confirmed booking that the host pays to the guest as a
+		/// compensation when canceling it.
+		#[pallet::constant]
+		type HostCancellationPenalty: Get<Perbill>;
+
+		/// The reputation of the hosts, informed of the completed and canceled bookings.
+		type HostReputation: HostReputation<Self::AccountId, BalanceOf<Self>>;
+
+		/// The loyalty program, rewarding the completed bookings with points that guests can
+		/// redeem as a discount.
+		type Loyalty: LoyaltyProgram<Self::AccountId, BalanceOf<Self>>;
+
+		/// The referral program, rewarding the referrers of guests and hosts.
+		type Referrals: ReferralProgram<Self::AccountId, BalanceOf<Self>>;
+
+		/// The dates blocked by the hosts outside of the bookings, which cannot be booked.
+		type BlockedDates: BlockedDates<Self::Hash>;
+
+		/// The account of the platform, funding the discounts of platform coupons and loyalty
+		/// points and the referral rewards. It must be kept above the existential deposit.
+		#[pallet::constant]
+		type PlatformAccount: Get<Self::AccountId>;
+
+		/// The maximum number of guests waiting for the dates of a place.
+		#[pallet::constant]
+		type MaxWaitlistLength: Get<u32>;
+
+		/// The time, in milliseconds, a guest of the waitlist has to book the freed dates once
+		/// notified.
+		#[pallet::constant]
+		type WaitlistClaimPeriod: Get<u64>;
+
+		/// The maximum number of legs of an itinerary.
+		#[pallet::constant]
+		type MaxItineraryLegs: Get<u32>;
+
+		/// The time, in milliseconds, the hosts have to confirm every leg of an itinerary.
+		#[pallet::constant]
+		type ItineraryConfirmationPeriod: Get<u64>;
+
+		/// The origin allowed to manage the platform-wide coupons.
+		type CouponOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum length of the encrypted content of a message.
+		#[pallet::constant]
+		type MaxMessageLength: Get<u32>;
+
+		/// The maximum number of messages in the channel of a booking.
+		#[pallet::constant]
+		type MaxMessagesPerBooking: Get<u32>;
+
+		/// The minimum time, in milliseconds, between two messages of the same sender in the
+		/// channel of a booking.
+		#[pallet::constant]
+		type MessageInterval: Get<u64>;
+
+		/// The time, in milliseconds, the guest and the host of a rejected, completed or no-show
+		/// booking can still post messages after its checkout. It should not exceed the
+		/// `BookingRetentionPeriod`.
+		#[pallet::constant]
+		type DisputeWindow: Get<u64>;
+
+		/// The time, in milliseconds, a finished booking is kept after its checkout before it is
+		/// archived.
+		#[pallet::constant]
+		type BookingRetentionPeriod: Get<u64>;
+
+		/// The maximum number of bookings checked for archival in a block.
+		#[pallet::constant]
+		type MaxPruningChecks: Get<u32>;
+
+		/// The maximum number of entries of every queue processed on the initialization of a
+		/// block, such as the pending balances.
+		#[pallet::constant]
+		type MaxScheduledChecks: Get<u32>;
 	}
 
 	/// Stores all the bookings in the system
@@ -60,6 +157,132 @@ pub mod pallet {
 	pub type PendingBookingWithdraws<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, Vec<(T::Hash, BalanceOf<T>)>, ValueQuery>;
 
+	/// Stores the exact address of the place of a booking, encrypted to the public key of the
+	/// guest. It is only available while the booking is active.
+	#[pallet::storage]
+	#[pallet::getter(fn get_booking_address)]
+	pub type BookingAddresses<T: Config> = StorageMap<_, Twox64Concat, T::Hash, Bytes>;
+
+	/// Stores the number of confirmed bookings canceled by each host.
+	#[pallet::storage]
+	#[pallet::getter(fn get_host_cancellations)]
+	pub type HostCancellations<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Stores a mapping between the bookings whose remaining balance has not been collected yet
+	/// and the moment at which it is due.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_balance)]
+	pub type PendingBalances<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::Moment>;
+
+	/// Stores the raw key of the last pending balance checked, from which the next block
+	/// resumes. The queue is checked from the start when it is not set.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_balances_cursor)]
+	pub type PendingBalancesCursor<T: Config> = StorageValue<_, Bytes>;
+
+	/// Stores a mapping between a place id and the guests waiting for its dates, in order of
+	/// arrival.
+	#[pallet::storage]
+	#[pallet::getter(fn get_place_waitlist)]
+	pub type Waitlists<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, Vec<WaitlistEntry<T>>, ValueQuery>;
+
+	/// Stores the places whose waitlist holds dates for a notified guest.
+	#[pallet::storage]
+	#[pallet::getter(fn has_waitlist_claims)]
+	pub type WaitlistClaims<T: Config> = StorageMap<_, Twox64Concat, T::Hash, bool, ValueQuery>;
+
+	/// Stores the raw key of the last place with waitlist claims checked, from which the next
+	/// block resumes. The places are checked from the start when it is not set.
+	#[pallet::storage]
+	#[pallet::getter(fn get_waitlist_claims_cursor)]
+	pub type WaitlistClaimsCursor<T: Config> = StorageValue<_, Bytes>;
+
+	/// Stores a mapping between an itinerary id and the actual itinerary
+	#[pallet::storage]
+	#[pallet::getter(fn get_itinerary_by_id)]
+	pub type Itineraries<T: Config> = StorageMap<_, Twox64Concat, T::Hash, ItineraryData<T>>;
+
+	/// Stores a mapping between a booking id and the itinerary it belongs to.
+	#[pallet::storage]
+	#[pallet::getter(fn get_booking_itinerary)]
+	pub type BookingItineraries<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::Hash>;
+
+	/// Stores a mapping between the itineraries that are waiting for the confirmation of some
+	/// legs and the moment at which they are canceled.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_itinerary)]
+	pub type PendingItineraries<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::Moment>;
+
+	/// Stores the raw key of the last pending itinerary checked, from which the next block
+	/// resumes. The queue is checked from the start when it is not set.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_itineraries_cursor)]
+	pub type PendingItinerariesCursor<T: Config> = StorageValue<_, Bytes>;
+
+	/// Stores a mapping between the hash of a coupon code and the actual coupon
+	#[pallet::storage]
+	#[pallet::getter(fn get_coupon)]
+	pub type Coupons<T: Config> = StorageMap<_, Twox64Concat, T::Hash, CouponData<T>>;
+
+	/// Stores the number of bookings of each account that have redeemed a coupon.
+	#[pallet::storage]
+	#[pallet::getter(fn get_coupon_redemptions)]
+	pub type CouponRedemptions<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Stores a mapping between a booking id and the hash of the coupon code it redeemed, along
+	/// with the part of the discount funded by the `PlatformAccount`.
+	#[pallet::storage]
+	#[pallet::getter(fn get_booking_coupon)]
+	pub type BookingCoupons<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, (T::Hash, BalanceOf<T>)>;
+
+	/// Stores a mapping between a booking id and the discount its guest got by redeeming loyalty
+	/// points, so they are given back if the booking is canceled.
+	#[pallet::storage]
+	#[pallet::getter(fn get_redeemed_points)]
+	pub type RedeemedPoints<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BalanceOf<T>>;
+
+	/// Stores the messages exchanged by the guest and the host of every booking, by their index
+	/// in the order they were posted. They are kept as evidence for disputes until the booking
+	/// is archived, which commits to their log.
+	#[pallet::storage]
+	#[pallet::getter(fn get_booking_message)]
+	pub type BookingMessages<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, u32, BookingMessage<T>>;
+
+	/// Stores a mapping between a booking id and the number of messages posted to its channel.
+	#[pallet::storage]
+	#[pallet::getter(fn get_messages_count)]
+	pub type MessagesCount<T: Config> = StorageMap<_, Twox64Concat, T::Hash, u32, ValueQuery>;
+
+	/// Stores the moment of the last message posted by every participant of a booking, to rate
+	/// limit them.
+	#[pallet::storage]
+	#[pallet::getter(fn get_last_message_at)]
+	pub type LastMessages<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, T::AccountId, T::Moment>;
+
+	/// Stores a mapping between a booking id and the hash chain of the messages posted to its
+	/// channel, so they can still be proven once the booking is archived.
+	#[pallet::storage]
+	#[pallet::getter(fn get_message_log)]
+	pub type MessageLogs<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::Hash>;
+
+	/// Stores a mapping between the id of an archived booking and the hash of its record and of
+	/// its message log, so they can still be proven in reviews and disputes once pruned.
+	#[pallet::storage]
+	#[pallet::getter(fn get_archived_booking)]
+	pub type ArchivedBookings<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::Hash>;
+
+	/// Stores the raw key of the last booking checked for archival, from which the next block
+	/// resumes.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pruning_cursor)]
+	pub type PruningCursor<T: Config> = StorageValue<_, Bytes>;
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
@@ -71,6 +294,69 @@ pub mod pallet {
 		BookingUpdated { id: T::Hash, sender: T::AccountId, state: BookingState },
 		/// A Booking has been canceled
 		BookingCanceled { id: T::Hash, sender: T::AccountId },
+		/// Part of the payout of a Booking has been transferred to one of its recipients
+		PayoutDistributed { id: T::Hash, recipient: T::AccountId, amount: BalanceOf<T> },
+		/// The platform has paid the referrer of the guest or the host of a completed Booking
+		ReferralRewardPaid {
+			id: T::Hash,
+			referrer: T::AccountId,
+			referred: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// The encrypted address of the place has been revealed to the guest of a Booking
+		AddressRevealed { id: T::Hash, sender: T::AccountId },
+		/// The guest of a Booking never arrived. The amount paid is split following the no-show
+		/// policy of the place
+		NoShowMarked {
+			id: T::Hash,
+			sender: T::AccountId,
+			host_amount: BalanceOf<T>,
+			guest_amount: BalanceOf<T>,
+		},
+		/// The host has compensated the guest of a Booking canceled by the host
+		GuestCompensated { id: T::Hash, guest: T::AccountId, amount: BalanceOf<T> },
+		/// The remaining balance of a Booking has been reserved from the guest
+		BalanceCollected { id: T::Hash, guest: T::AccountId, amount: BalanceOf<T> },
+		/// The guest could not pay the remaining balance of a Booking, so it has been canceled
+		BalancePaymentMissed { id: T::Hash, guest: T::AccountId },
+		/// A guest has joined the waitlist of a place
+		WaitlistJoined { place_id: T::Hash, guest: T::AccountId, reserved: BalanceOf<T> },
+		/// A guest has left the waitlist of a place
+		WaitlistLeft { place_id: T::Hash, guest: T::AccountId },
+		/// The dates a guest was waiting for have been booked with the funds reserved
+		WaitlistPromoted { place_id: T::Hash, guest: T::AccountId, booking_id: T::Hash },
+		/// The dates a guest was waiting for are available and held until `claim_until`
+		WaitlistSpotAvailable { place_id: T::Hash, guest: T::AccountId, claim_until: T::Moment },
+		/// A notified guest did not book the dates in time and has been removed from the waitlist
+		WaitlistClaimExpired { place_id: T::Hash, guest: T::AccountId },
+		/// A new Itinerary has been created, with a booking for each leg
+		ItineraryCreated { id: T::Hash, sender: T::AccountId },
+		/// Every leg of an Itinerary has been confirmed
+		ItineraryConfirmed { id: T::Hash },
+		/// A leg of an Itinerary has been canceled or not confirmed in time, so every leg has been
+		/// canceled
+		ItineraryCanceled { id: T::Hash },
+		/// A new Coupon has been created by a host, or by the platform if there is no issuer
+		CouponCreated { code: T::Hash, issuer: Option<T::AccountId> },
+		/// A Coupon has been removed
+		CouponRemoved { code: T::Hash },
+		/// A Coupon has been redeemed in a Booking
+		CouponRedeemed { code: T::Hash, id: T::Hash, guest: T::AccountId },
+		/// A message has been posted to the channel of a Booking
+		MessagePosted {
+			id: T::Hash,
+			sender: T::AccountId,
+			index: u32,
+			content: MessageContent<T::Hash>,
+		},
+		/// A finished Booking has been pruned from the storage. Only the hash of its record and
+		/// of its message log is kept
+		BookingArchived {
+			id: T::Hash,
+			booking: BookingData<T>,
+			message_log: T::Hash,
+			commitment: T::Hash,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -84,6 +370,38 @@ pub mod pallet {
 		BookingAlreadyExists,
 		/// Booking dates are not available
 		BookingDatesNotAvailable,
+		/// Booking dates are available, the place can be booked directly
+		BookingDatesAvailable,
+		/// The account is already waiting for the place
+		AlreadyInWaitlist,
+		/// The account is not waiting for the place
+		NotInWaitlist,
+		/// The waitlist of the place is full
+		WaitlistFull,
+		/// Itinerary id not found
+		ItineraryNotFound,
+		/// An itinerary needs at least one leg
+		EmptyItinerary,
+		/// The itinerary exceeds the maximum number of legs
+		TooManyItineraryLegs,
+		/// Every leg of an itinerary must start after the end of the previous one
+		OverlappingItineraryLegs,
+		/// Coupon code not found
+		CouponNotFound,
+		/// Coupon code already exists
+		CouponAlreadyExists,
+		/// The terms of the coupon are not valid
+		InvalidCouponTerms,
+		/// Not the issuer of the coupon
+		NotCouponIssuer,
+		/// The coupon cannot be redeemed for this place or stay
+		CouponNotApplicable,
+		/// The coupon cannot be redeemed at this moment
+		CouponNotActive,
+		/// The coupon has reached its redemption limit
+		CouponExhausted,
+		/// The guest does not hold the verification level required by the place
+		GuestNotVerified,
 		/// Owner cannot book its own place
 		CannotBookOwnedPlace,
 		/// Account does not have enough free balance to book
@@ -92,7 +410,18 @@ pub mod pallet {
 		InvalidDates,
 		/// start_date cannot be less or equal to current chain moment
 		InvalidStartDate,
-		/// Not Place Owner
+		/// The date does not exist or is earlier than the Unix epoch
+		InvalidCalendarDate,
+		/// The hour of the day is out of range
+		HourOutOfRange,
+		/// The date cannot be represented as a Moment
+		DateOutOfRange,
+		/// The amount provided does not match the price of the stay. Pallet errors cannot carry
+		/// balances, the expected amount is returned by the `quote_booking` runtime API
+		AmountMismatch,
+		/// The price of the stay overflows
+		TotalAmountOverflow,
+		/// Not Place Owner nor holds the required permission in the place
 		NotPlaceOwner,
 		/// Not Place Guest
 		NotPlaceGuest,
@@ -102,6 +431,47 @@ pub mod pallet {
 		CannotConfirmOutdatedBooking,
 		/// Cannot checkin yet
 		CheckinNotAvailableYet,
+		/// The remaining balance of the booking has not been collected yet
+		BalanceNotPaid,
+		/// The grace period of the no-show policy has not passed yet
+		NoShowNotAvailableYet,
+		/// The account has nothing to withdraw from the booking
+		NothingToWithdraw,
+		/// Cannot acknowledge the checkout yet, the guest is still in the place
+		CheckoutNotAvailableYet,
+		/// The encrypted address exceeds the maximum length
+		EncryptedAddressTooLong,
+		/// Neither the guest nor the host of the booking
+		NotBookingParticipant,
+		/// The encrypted content of the message exceeds the maximum length
+		MessageTooLong,
+		/// The channel of the booking has reached the maximum number of messages
+		TooManyMessages,
+		/// The sender posted a message too recently
+		MessageRateLimited,
+		/// The booking is finished and its dispute window has passed
+		MessagingClosed,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+		}
+
+		/// Collect the remaining balance of the bookings that are due, release the waitlist
+		/// claims that have expired and cancel the itineraries not confirmed in time.
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			Self::collect_pending_balances()
+				.saturating_add(Self::expire_waitlist_claims())
+				.saturating_add(Self::expire_itineraries())
+		}
+
+		/// Archive the finished bookings whose retention period has passed, within the weight
+		/// left in the block.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune_finished_bookings(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -111,15 +481,20 @@ pub mod pallet {
 		/// This extrinsic allows any signed account (`origin`) to create a booking for a specific
 		/// `place_id` with the given `start_date`, `end_date`, and `amount`. The booking request is
 		/// processed, and if successful, a unique identifier (`Hash`) for the created booking
-		/// is returned. The `amount` parameter represents the payment to be made for the booking.
+		/// is returned. The `amount` parameter represents the payment to be made for the booking,
+		/// once the discount of the `coupon`, if any, is applied. If `redeem_points` is set, the
+		/// loyalty points of the sender are redeemed as a discount as well, up to the cap of the
+		/// loyalty program.
 		///
 		/// # Arguments
 		///
 		/// * `origin` - The account identifier of the sender initiating the booking creation.
 		/// * `place_id` - The unique identifier of the place to book.
-		/// * `start_date` - The start date of the booking.
-		/// * `end_date` - The end date of the booking.
+		/// * `start_date` - The calendar date of the checkin, in the local time of the place.
+		/// * `end_date` - The calendar date of the checkout, in the local time of the place.
 		/// * `amount` - The payment amount for the booking.
+		/// * `coupon` - The code of the coupon to redeem, if any. Only its hash is stored.
+		/// * `redeem_points` - Whether the loyalty points of the sender are redeemed.
 		///
 		/// # Returns
 		///
@@ -131,15 +506,24 @@ pub mod pallet {
 		pub fn create_booking(
 			origin: OriginFor<T>,
 			place_id: T::Hash,
-			start_date: T::Moment,
-			end_date: T::Moment,
+			start_date: CalendarDate,
+			end_date: CalendarDate,
 			amount: BalanceOf<T>,
+			coupon: Option<Bytes>,
+			redeem_points: bool,
 		) -> DispatchResult {
 			// Check sender
 			let sender = ensure_signed(origin)?;
 
-			let booking_id =
-				Self::_create_booking(sender.clone(), place_id, start_date, end_date, amount)?;
+			let booking_id = Self::_create_booking(
+				sender.clone(),
+				place_id,
+				start_date,
+				end_date,
+				amount,
+				coupon,
+				redeem_points,
+			)?;
 
 			// Deposit our "Placed" event.
 			Self::deposit_event(Event::BookingPlaced { id: booking_id, sender });
@@ -173,8 +557,8 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			booking_id: T::Hash,
 			place_id: T::Hash,
-			start_date: T::Moment,
-			end_date: T::Moment,
+			start_date: CalendarDate,
+			end_date: CalendarDate,
 			amount: BalanceOf<T>,
 		) -> DispatchResult {
 			// Check sender
@@ -210,6 +594,8 @@ pub mod pallet {
 		/// This extrinsic allows any signed account (`origin`) to confirm a booking request for a
 		/// specific `booking_id`. Upon successful confirmation, the booking status changes to
 		/// "Confirmed," and the booked dates are reserved for the guest.
+		/// Once confirmed, the host is expected to reveal the exact address of the place to the
+		/// guest through `reveal_address`.
 		///
 		/// # Arguments
 		///
@@ -271,11 +657,43 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Cancel a Confirmed Booking as the host.
+		///
+		/// This extrinsic allows the host of a booking, or an account with the `MANAGE_BOOKINGS`
+		/// permission in the place, to cancel a booking that has already been confirmed. The
+		/// guest is fully refunded and the host pays a compensation to the guest, a share of the
+		/// amount of the booking set by `HostCancellationPenalty`. The cancellation is recorded
+		/// in the reliability counter of the host.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account identifier of the sender initiating the cancellation.
+		/// * `booking_id` - The identifier of the booking to cancel.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the cancellation. If
+		/// the operation is successful, the booking is canceled, and the `DispatchResult`
+		/// contains no error. If the cancellation fails, the `DispatchResult` contains an error
+		/// describing the reason for failure.
+		#[pallet::call_index(11)]
+		pub fn host_cancel_booking(origin: OriginFor<T>, booking_id: T::Hash) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_host_cancel_booking(sender.clone(), &booking_id)?;
+
+			// Deposit our "Canceled" event.
+			Self::deposit_event(Event::BookingCanceled { id: booking_id, sender });
+			Ok(())
+		}
+
 		/// Perform Check-In for a Confirmed Booking.
 		///
 		/// This extrinsic allows any signed account (`origin`) to perform the check-in process for
 		/// a confirmed booking. The check-in process ensures that the guest gains access to the
-		/// place for the specified booking period.
+		/// place for the specified booking period. The payout of the booking is not released
+		/// until the guest checks out.
 		///
 		/// # Arguments
 		///
@@ -295,11 +713,11 @@ pub mod pallet {
 
 			Self::_checkin(sender.clone(), &booking_id)?;
 
-			// Deposit our "OwnerCanWithdraw" event.
+			// Deposit our "CheckedIn" event.
 			Self::deposit_event(Event::BookingUpdated {
 				id: booking_id,
 				sender,
-				state: BookingState::OwnerCanWithdraw,
+				state: BookingState::CheckedIn,
 			});
 			Ok(())
 		}
@@ -330,13 +748,416 @@ pub mod pallet {
 
 			Self::_withdraw_booking(sender.clone(), &booking_id)?;
 
+			// The funds of a no-show are withdrawn separately by the host and the guest
+			let state = Self::get_booking_by_id(booking_id)
+				.map(|booking| booking.state)
+				.unwrap_or(BookingState::Completed);
+
 			// Deposit our "Completed" event.
+			Self::deposit_event(Event::BookingUpdated { id: booking_id, sender, state });
+			Ok(())
+		}
+
+		/// Perform Check-Out for a Checked-In Booking.
+		///
+		/// This extrinsic allows the guest of a booking to leave the place before the end of the
+		/// stay. Once the checkout hour of the last day has passed, the check-out is automatic and
+		/// any signed account can record it.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account identifier of the sender initiating the check-out process.
+		/// * `booking_id` - The identifier of the booking to update.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the check-out process.
+		/// If the check-out is successful, the booking is checked out, and the `DispatchResult`
+		/// contains no error. If the check-out process fails, the `DispatchResult` contains an
+		/// error describing the reason for failure.
+		#[pallet::call_index(9)]
+		pub fn checkout(origin: OriginFor<T>, booking_id: T::Hash) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_checkout(sender.clone(), &booking_id)?;
+
+			// Deposit our "CheckedOut" event.
+			Self::deposit_event(Event::BookingUpdated {
+				id: booking_id,
+				sender,
+				state: BookingState::CheckedOut,
+			});
+			Ok(())
+		}
+
+		/// Acknowledge the Check-Out of a Booking and release its payout.
+		///
+		/// This extrinsic allows the host of a booking, or an account with the `MANAGE_BOOKINGS`
+		/// permission in the place, to acknowledge that the guest has left the place. If the guest
+		/// did not check out, the acknowledgement is only available once the checkout hour of the
+		/// last day has passed. After the acknowledgement, the payout can be withdrawn.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account identifier of the sender acknowledging the check-out.
+		/// * `booking_id` - The identifier of the booking to update.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the owner can withdraw the booking, and the `DispatchResult`
+		/// contains no error. If the operation fails, the `DispatchResult` contains an error
+		/// describing the reason for failure.
+		#[pallet::call_index(10)]
+		pub fn acknowledge_checkout(origin: OriginFor<T>, booking_id: T::Hash) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_acknowledge_checkout(sender.clone(), &booking_id)?;
+
+			// Deposit our "OwnerCanWithdraw" event.
 			Self::deposit_event(Event::BookingUpdated {
 				id: booking_id,
 				sender,
-				state: BookingState::Completed,
+				state: BookingState::OwnerCanWithdraw,
+			});
+			Ok(())
+		}
+
+		/// Mark a Confirmed Booking whose guest never arrived as a no-show.
+		///
+		/// This extrinsic allows the host of a booking, or an account with the `MANAGE_BOOKINGS`
+		/// permission in the place, to mark a booking as a no-show once the grace period of the
+		/// no-show policy of the place has passed since the checkin hour. The amount paid by the
+		/// guest is split following the policy, and both parts can then be withdrawn through
+		/// `withdraw_booking`.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account identifier of the sender marking the no-show.
+		/// * `booking_id` - The identifier of the booking to update.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the booking is marked as a no-show, and the `DispatchResult`
+		/// contains no error. If the operation fails, the `DispatchResult` contains an error
+		/// describing the reason for failure.
+		#[pallet::call_index(12)]
+		pub fn mark_no_show(origin: OriginFor<T>, booking_id: T::Hash) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			let (host_amount, guest_amount) = Self::_mark_no_show(sender.clone(), &booking_id)?;
+
+			// Deposit our "NoShowMarked" event.
+			Self::deposit_event(Event::NoShowMarked {
+				id: booking_id,
+				sender,
+				host_amount,
+				guest_amount,
 			});
 			Ok(())
 		}
+
+		/// Join the waitlist of a place for some dates that are already booked.
+		///
+		/// This extrinsic allows any signed account (`origin`) to wait for the dates of a place
+		/// that cannot be booked because they overlap with a confirmed booking. When a conflicting
+		/// booking is rejected or canceled, the first guest of the waitlist whose dates became
+		/// available is served. If the guest reserved the funds when joining, the booking request
+		/// is created automatically. Otherwise, the guest is notified and the dates are held for
+		/// `WaitlistClaimPeriod` so the guest can book them through `create_booking`.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account identifier of the guest joining the waitlist.
+		/// * `place_id` - The unique identifier of the place.
+		/// * `start_date` - The calendar date of the checkin, in the local time of the place.
+		/// * `end_date` - The calendar date of the checkout, in the local time of the place.
+		/// * `reserve_funds` - Whether the amount of the stay is reserved to book it automatically.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the guest is added at the end of the waitlist, and the
+		/// `DispatchResult` contains no error. If the operation fails, the `DispatchResult`
+		/// contains an error describing the reason for failure.
+		#[pallet::call_index(13)]
+		pub fn join_waitlist(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+			start_date: CalendarDate,
+			end_date: CalendarDate,
+			reserve_funds: bool,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			let reserved = Self::_join_waitlist(
+				sender.clone(),
+				place_id,
+				start_date,
+				end_date,
+				reserve_funds,
+			)?;
+
+			// Deposit our "WaitlistJoined" event.
+			Self::deposit_event(Event::WaitlistJoined { place_id, guest: sender, reserved });
+			Ok(())
+		}
+
+		/// Leave the waitlist of a place.
+		///
+		/// This extrinsic allows a guest waiting for the dates of a place to leave its waitlist.
+		/// Any funds reserved when joining are released.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account identifier of the guest leaving the waitlist.
+		/// * `place_id` - The unique identifier of the place.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the guest is removed from the waitlist, and the
+		/// `DispatchResult` contains no error. If the operation fails, the `DispatchResult`
+		/// contains an error describing the reason for failure.
+		#[pallet::call_index(14)]
+		pub fn leave_waitlist(origin: OriginFor<T>, place_id: T::Hash) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_leave_waitlist(sender.clone(), place_id)?;
+
+			// Deposit our "WaitlistLeft" event.
+			Self::deposit_event(Event::WaitlistLeft { place_id, guest: sender });
+			Ok(())
+		}
+
+		/// Create an itinerary with a booking for each leg of a trip.
+		///
+		/// This extrinsic allows any signed account (`origin`) to book several places for
+		/// consecutive legs of a trip at once. Every leg is validated and booked as if it was
+		/// created through `create_booking`, and if any of them fails, none is created. Each host
+		/// confirms its own leg. If any leg is rejected or canceled, or the itinerary is not fully
+		/// confirmed within `ItineraryConfirmationPeriod` or before the first leg starts, every
+		/// leg is canceled and all the funds reserved are released.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account identifier of the guest creating the itinerary.
+		/// * `legs` - The legs of the trip, in chronological order.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the itinerary creation
+		/// operation. If the operation is successful, a booking is created for every leg, and the
+		/// `DispatchResult` contains no error. If the itinerary creation fails, the
+		/// `DispatchResult` contains an error describing the reason for failure.
+		#[pallet::call_index(15)]
+		pub fn create_itinerary(
+			origin: OriginFor<T>,
+			legs: Vec<ItineraryLeg<T>>,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			let itinerary_id = Self::_create_itinerary(sender.clone(), legs)?;
+
+			// Deposit our "Placed" event for every leg.
+			for booking_id in Self::get_itinerary_by_id(itinerary_id)
+				.map(|itinerary| itinerary.bookings)
+				.unwrap_or_default()
+			{
+				Self::deposit_event(Event::BookingPlaced {
+					id: booking_id,
+					sender: sender.clone(),
+				});
+			}
+
+			// Deposit our "ItineraryCreated" event.
+			Self::deposit_event(Event::ItineraryCreated { id: itinerary_id, sender });
+			Ok(())
+		}
+
+		/// Create a coupon for the places of a host.
+		///
+		/// This extrinsic allows any signed account (`origin`) to create a promotional code that
+		/// guests can redeem when booking its places. The coupon is identified by the hash of its
+		/// code, so the code itself is never published. If the terms list some places, all of them
+		/// must be owned by the sender, otherwise the coupon applies to every place of the sender.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account identifier of the host creating the coupon.
+		/// * `code` - The hash of the coupon code.
+		/// * `terms` - The discount and the conditions under which the coupon can be redeemed.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the coupon is created, and the `DispatchResult` contains no
+		/// error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(16)]
+		pub fn create_coupon(
+			origin: OriginFor<T>,
+			code: T::Hash,
+			terms: CouponTerms<T>,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_create_coupon(Some(sender.clone()), code, terms)?;
+
+			// Deposit our "CouponCreated" event.
+			Self::deposit_event(Event::CouponCreated { code, issuer: Some(sender) });
+			Ok(())
+		}
+
+		/// Create a platform-wide coupon.
+		///
+		/// This extrinsic allows the `CouponOrigin` to create a promotional code that guests can
+		/// redeem in any place, or in the places listed in the terms.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The `CouponOrigin`.
+		/// * `code` - The hash of the coupon code.
+		/// * `terms` - The discount and the conditions under which the coupon can be redeemed.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the coupon is created, and the `DispatchResult` contains no
+		/// error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(17)]
+		pub fn create_platform_coupon(
+			origin: OriginFor<T>,
+			code: T::Hash,
+			terms: CouponTerms<T>,
+		) -> DispatchResult {
+			// Check origin
+			T::CouponOrigin::ensure_origin(origin)?;
+
+			Self::_create_coupon(None, code, terms)?;
+
+			// Deposit our "CouponCreated" event.
+			Self::deposit_event(Event::CouponCreated { code, issuer: None });
+			Ok(())
+		}
+
+		/// Remove a coupon.
+		///
+		/// This extrinsic allows the issuer of a coupon to remove it, so it can no longer be
+		/// redeemed. Platform coupons are removed by the `CouponOrigin`. Bookings that already
+		/// redeemed the coupon are not affected.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The issuer of the coupon, or the `CouponOrigin` for platform coupons.
+		/// * `code` - The hash of the coupon code.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the coupon is removed, and the `DispatchResult` contains no
+		/// error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(18)]
+		pub fn remove_coupon(origin: OriginFor<T>, code: T::Hash) -> DispatchResult {
+			// Check origin
+			let sender = match T::CouponOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			Self::_remove_coupon(sender, code)?;
+
+			// Deposit our "CouponRemoved" event.
+			Self::deposit_event(Event::CouponRemoved { code });
+			Ok(())
+		}
+
+		/// Reveal the exact address of the place to the guest of a Confirmed Booking.
+		///
+		/// This extrinsic allows the host of a booking, or an account with the `MANAGE_BOOKINGS`
+		/// permission in the place, to post the exact address of the place encrypted to the
+		/// public key of the guest. Listings only publish a coarse location, so the address is
+		/// only available for the guests of confirmed bookings. Posting it again replaces the
+		/// previous one.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account identifier of the sender revealing the address.
+		/// * `booking_id` - The identifier of the booking.
+		/// * `encrypted_address` - The address of the place, encrypted to the guest public key.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the encrypted address is stored for the booking, and the
+		/// `DispatchResult` contains no error. If the operation fails, the `DispatchResult`
+		/// contains an error describing the reason for failure.
+		#[pallet::call_index(8)]
+		pub fn reveal_address(
+			origin: OriginFor<T>,
+			booking_id: T::Hash,
+			encrypted_address: Bytes,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_reveal_address(sender.clone(), &booking_id, encrypted_address)?;
+
+			// Deposit our "AddressRevealed" event.
+			Self::deposit_event(Event::AddressRevealed { id: booking_id, sender });
+			Ok(())
+		}
+
+		/// Post a message to the channel of a Booking.
+		///
+		/// This extrinsic allows the guest and the host of a booking to communicate about it. The
+		/// message is either the hash of some content stored off-chain or the content encrypted to
+		/// the public key of the recipient, so the chain only keeps an audit trail that can be
+		/// used as evidence in disputes. A sender can post a message every `MessageInterval`, up to
+		/// `MaxMessagesPerBooking` messages per booking. Once the booking is rejected, completed
+		/// or marked as a no-show, messages can only be posted until `DisputeWindow` after its
+		/// checkout. Its weight grows with the length of the content.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account identifier of the guest or the host posting the message.
+		/// * `booking_id` - The identifier of the booking.
+		/// * `content` - The hash of the content, or the encrypted content.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the message is appended to the channel of the booking, and
+		/// the `DispatchResult` contains no error. If the operation fails, the `DispatchResult`
+		/// contains an error describing the reason for failure.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(5, 4).saturating_add(
+			Weight::from_parts(MESSAGE_WEIGHT_PER_BYTE, 0)
+				.saturating_mul(content.encoded_size() as u64)
+		))]
+		pub fn post_message(
+			origin: OriginFor<T>,
+			booking_id: T::Hash,
+			content: MessageContent<T::Hash>,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			let index = Self::_post_message(sender.clone(), &booking_id, content.clone())?;
+
+			// Deposit our "MessagePosted" event.
+			Self::deposit_event(Event::MessagePosted { id: booking_id, sender, index, content });
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,61 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{BalanceOf, BookingData, BookingState, BookingsData, Config, Pallet};
+use codec::{Decode, Encode};
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Migration from the first layout of the bookings, which reserved the whole amount upfront.
+pub mod v1 {
+	use super::*;
+
+	/// The data of a booking before the storage version 1.
+	#[derive(Encode, Decode)]
+	pub struct OldBookingData<T: Config> {
+		pub place_id: T::Hash,
+		pub host: T::AccountId,
+		pub guest: T::AccountId,
+		pub start_date: T::Moment,
+		pub end_date: T::Moment,
+		pub amount: BalanceOf<T>,
+		pub state: BookingState,
+	}
+
+	/// Translate the bookings to the storage version 1.
+	///
+	/// The bookings created before the payment schedules reserved their whole amount, so it is
+	/// fully paid and no balance is pending. The states keep their encoding, as the new ones are
+	/// appended.
+	///
+	/// # Returns
+	///
+	/// Returns the weight consumed by the migration.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated: u64 = 0;
+		<BookingsData<T>>::translate::<OldBookingData<T>, _>(|_, old| {
+			translated += 1;
+			Some(BookingData {
+				place_id: old.place_id,
+				host: old.host,
+				guest: old.guest,
+				start_date: old.start_date,
+				end_date: old.end_date,
+				amount: old.amount,
+				paid: old.amount,
+				balance_due_date: None,
+				state: old.state,
+			})
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "{} bookings have been migrated to the storage version 1.", translated);
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
@@ -0,0 +1,28 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{Bytes, CalendarDate, PlaceCalendar};
+use codec::Codec;
+use frame_support::sp_runtime::DispatchError;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to query the bookings of the chain.
+	pub trait BookingsApi<Hash, AccountId, Balance>
+	where
+		Hash: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Get the amount a guest has to pay to book a place between two dates, once the discount
+		/// of the coupon code and of the loyalty points of the account, if any, are applied.
+		fn quote_booking(
+			place_id: Hash,
+			start_date: CalendarDate,
+			end_date: CalendarDate,
+			coupon: Option<Bytes>,
+			redeem_points: Option<AccountId>,
+		) -> Result<Balance, DispatchError>;
+
+		/// Get the confirmed bookings and the blocked periods of a place, without the identity of
+		/// the guests.
+		fn place_calendar(place_id: Hash) -> Option<PlaceCalendar<Hash>>;
+	}
+}
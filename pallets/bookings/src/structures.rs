@@ -1,9 +1,67 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use super::{BalanceOf, Config};
+use super::{BalanceOf, Bytes, Config};
 use codec::{Decode, Encode};
-use frame_support::sp_std::prelude::*;
+use frame_support::{sp_runtime::Perbill, sp_std::prelude::*};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// A calendar date, without time of day nor timezone. Dates are ordered chronologically.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Encode, Decode, TypeInfo, Clone, Copy, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CalendarDate {
+	pub year: u16,
+	/// Month of the year, from 1 to 12
+	pub month: u8,
+	/// Day of the month, from 1 to 31
+	pub day: u8,
+}
+
+impl CalendarDate {
+	pub fn new(year: u16, month: u8, day: u8) -> Self {
+		CalendarDate { year, month, day }
+	}
+
+	/// Returns `true` if the year has 366 days.
+	pub fn is_leap_year(year: u16) -> bool {
+		(year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+	}
+
+	/// Returns the number of days of a month, or 0 if the month does not exist.
+	pub fn days_in_month(year: u16, month: u8) -> u8 {
+		match month {
+			1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+			4 | 6 | 9 | 11 => 30,
+			2 if Self::is_leap_year(year) => 29,
+			2 => 28,
+			_ => 0,
+		}
+	}
+
+	/// Returns `true` if the date exists and is not earlier than the Unix epoch.
+	pub fn is_valid(&self) -> bool {
+		self.year >= 1970 && self.day >= 1 && self.day <= Self::days_in_month(self.year, self.month)
+	}
+
+	/// Returns the number of days elapsed since 1970-01-01, or `None` if the date is not valid.
+	pub fn days_since_epoch(&self) -> Option<u64> {
+		if !self.is_valid() {
+			return None
+		}
+
+		// Count the years from March, so the leap day is the last day of the year
+		let month = self.month as u64;
+		let year = self.year as u64 - if month <= 2 { 1 } else { 0 };
+		let era = year / 400;
+		let year_of_era = year - era * 400;
+		let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + self.day as u64 - 1;
+		let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+		// 719_468 days go from 0000-03-01 to 1970-01-01
+		Some(era * 146_097 + day_of_era - 719_468)
+	}
+}
 
 #[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
 pub enum BookingState {
@@ -14,6 +72,10 @@ pub enum BookingState {
 	UserCanWithdraw,
 	OwnerCanWithdraw,
 	Completed,
+	// New states are appended to keep the encoding of the stored bookings
+	CheckedIn,
+	CheckedOut,
+	NoShow,
 }
 
 #[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
@@ -25,6 +87,10 @@ pub struct BookingData<T: Config> {
 	pub start_date: T::Moment,
 	pub end_date: T::Moment,
 	pub amount: BalanceOf<T>,
+	/// The part of the amount already reserved from the guest.
+	pub paid: BalanceOf<T>,
+	/// The moment at which the remaining balance is collected, if it is still pending.
+	pub balance_due_date: Option<T::Moment>,
 	pub state: BookingState,
 }
 
@@ -36,6 +102,8 @@ impl<T: Config> BookingData<T> {
 		start_date: T::Moment,
 		end_date: T::Moment,
 		amount: BalanceOf<T>,
+		paid: BalanceOf<T>,
+		balance_due_date: Option<T::Moment>,
 	) -> Self {
 		BookingData {
 			place_id,
@@ -44,6 +112,8 @@ impl<T: Config> BookingData<T> {
 			start_date,
 			end_date,
 			amount,
+			paid,
+			balance_due_date,
 			state: BookingState::Created,
 		}
 	}
@@ -67,3 +137,170 @@ impl<T: Config> From<BookingData<T>> for BookingHashingData<T> {
 		Self { place_id, host, guest, start_date, end_date, amount }
 	}
 }
+
+/// A guest waiting for some dates of a fully booked place.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct WaitlistEntry<T: Config> {
+	pub guest: T::AccountId,
+	pub start_date: CalendarDate,
+	pub end_date: CalendarDate,
+	/// The funds reserved from the guest to book the dates automatically once they are freed.
+	pub reserved: BalanceOf<T>,
+	/// The moment until which the dates are held for the guest, once notified.
+	pub claim_until: Option<T::Moment>,
+}
+
+impl<T: Config> WaitlistEntry<T> {
+	pub fn new(
+		guest: T::AccountId,
+		start_date: CalendarDate,
+		end_date: CalendarDate,
+		reserved: BalanceOf<T>,
+	) -> Self {
+		WaitlistEntry { guest, start_date, end_date, reserved, claim_until: None }
+	}
+}
+
+/// A leg of an itinerary, booked in a single place.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct ItineraryLeg<T: Config> {
+	pub place_id: T::Hash,
+	pub start_date: CalendarDate,
+	pub end_date: CalendarDate,
+	pub amount: BalanceOf<T>,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub enum ItineraryState {
+	/// Some legs have not been confirmed yet
+	Pending,
+	/// Every leg has been confirmed by its host
+	Confirmed,
+	/// Every leg has been canceled
+	Canceled,
+}
+
+/// A group of bookings of consecutive legs of a trip, confirmed or canceled as a whole.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct ItineraryData<T: Config> {
+	pub guest: T::AccountId,
+	/// The bookings of the legs, in order.
+	pub bookings: Vec<T::Hash>,
+	/// The moment at which the itinerary is canceled if some legs are still unconfirmed.
+	pub deadline: T::Moment,
+	pub state: ItineraryState,
+}
+
+impl<T: Config> ItineraryData<T> {
+	pub fn new(guest: T::AccountId, bookings: Vec<T::Hash>, deadline: T::Moment) -> Self {
+		ItineraryData { guest, bookings, deadline, state: ItineraryState::Pending }
+	}
+}
+
+/// The discount applied by a coupon to the amount of a booking.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub enum Discount<Balance> {
+	/// A share of the amount of the booking
+	Percentage(Perbill),
+	/// A fixed amount, up to the amount of the booking
+	Fixed(Balance),
+}
+
+/// The conditions under which a coupon can be redeemed.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct CouponTerms<T: Config> {
+	pub discount: Discount<BalanceOf<T>>,
+	/// The places where the coupon can be redeemed. If empty, it can be redeemed in any place of
+	/// the issuer, or in any place for platform coupons.
+	pub places: Vec<T::Hash>,
+	/// The maximum number of bookings that can redeem the coupon, if limited.
+	pub max_redemptions: Option<u32>,
+	/// The maximum number of bookings of the same account that can redeem the coupon, if limited.
+	pub max_redemptions_per_account: Option<u32>,
+	/// The moment from which the coupon can be redeemed.
+	pub valid_from: T::Moment,
+	/// The moment until which the coupon can be redeemed.
+	pub valid_until: T::Moment,
+	/// The minimum number of nights of the stay.
+	pub min_nights: u32,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct CouponData<T: Config> {
+	/// The host that created the coupon, or `None` for platform coupons.
+	pub issuer: Option<T::AccountId>,
+	pub terms: CouponTerms<T>,
+	/// The number of bookings that have redeemed the coupon.
+	pub redemptions: u32,
+}
+
+impl<T: Config> CouponData<T> {
+	pub fn new(issuer: Option<T::AccountId>, terms: CouponTerms<T>) -> Self {
+		CouponData { issuer, terms, redemptions: 0 }
+	}
+}
+
+/// Kind of a period of the calendar of a place.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Copy, Debug)]
+pub enum CalendarEntryKind {
+	/// The place is booked by a confirmed booking.
+	Booked,
+	/// The host blocked the dates, for example because the place is booked on another platform.
+	Blocked,
+}
+
+/// Period in which a place is occupied.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub struct CalendarEntry<Hash> {
+	/// Stable identifier of the period, derived from the place and the booking or the dates.
+	pub uid: Hash,
+	pub kind: CalendarEntryKind,
+	/// The checkin moment, in milliseconds since the Unix epoch.
+	pub start: u64,
+	/// The checkout moment, in milliseconds since the Unix epoch.
+	pub end: u64,
+}
+
+/// Occupation of a place, exported by the nodes as an iCalendar document.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub struct PlaceCalendar<Hash> {
+	pub name: Bytes,
+	/// The hour picked by the owner for the checkin 0-23, in the local time of the place.
+	pub checkin_hour: u32,
+	/// The hour picked by the owner for the checkout 0-23, in the local time of the place.
+	pub checkout_hour: u32,
+	/// The offset of the local time of the place from UTC, in minutes.
+	pub utc_offset_minutes: i16,
+	/// The booked and blocked periods, sorted by their checkin.
+	pub entries: Vec<CalendarEntry<Hash>>,
+}
+
+/// Content of a message between the guest and the host of a booking.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub enum MessageContent<Hash> {
+	/// The hash of the content, which is stored off-chain
+	Reference(Hash),
+	/// The content, encrypted to the public key of the recipient
+	Encrypted(Bytes),
+}
+
+/// A message posted to the channel of a booking.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct BookingMessage<T: Config> {
+	pub sender: T::AccountId,
+	pub content: MessageContent<T::Hash>,
+	/// The moment at which the message was posted.
+	pub sent_at: T::Moment,
+}
+
+impl<T: Config> BookingMessage<T> {
+	pub fn new(sender: T::AccountId, content: MessageContent<T::Hash>, sent_at: T::Moment) -> Self {
+		BookingMessage { sender, content, sent_at }
+	}
+}
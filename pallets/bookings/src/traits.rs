@@ -0,0 +1,134 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::CalendarDate;
+use frame_support::{dispatch::DispatchResult, sp_runtime::traits::Zero, sp_std::prelude::*};
+
+/// Reputation of the hosts, built from the outcome of their bookings.
+///
+/// `pallet_bookings` reports every booking completed and every booking canceled by a host, so
+/// other pallets can reward reliable hosts or penalize the unreliable ones.
+pub trait HostReputation<AccountId, Balance> {
+	/// A booking of the host has been completed and paid out.
+	///
+	/// # Arguments
+	///
+	/// * `host` - The host of the booking.
+	fn on_booking_completed(host: &AccountId);
+
+	/// The host has canceled a confirmed booking.
+	///
+	/// # Arguments
+	///
+	/// * `host` - The host of the booking.
+	/// * `amount` - The amount of the canceled booking.
+	fn on_host_cancellation(host: &AccountId, amount: Balance);
+}
+
+/// The reputation of the hosts is not tracked.
+impl<AccountId, Balance> HostReputation<AccountId, Balance> for () {
+	fn on_booking_completed(_host: &AccountId) {}
+
+	fn on_host_cancellation(_host: &AccountId, _amount: Balance) {}
+}
+
+/// Loyalty program rewarding the guests of completed bookings.
+///
+/// `pallet_bookings` reports every booking completed so points can be earned, and lets guests
+/// redeem them as a discount when creating a booking.
+pub trait LoyaltyProgram<AccountId, Balance> {
+	/// A booking has been completed and paid out.
+	///
+	/// # Arguments
+	///
+	/// * `guest` - The guest of the booking.
+	/// * `amount` - The amount paid by the guest, once any discount has been applied.
+	fn on_booking_completed(guest: &AccountId, amount: Balance);
+
+	/// Get the maximum discount an account can get on a booking by redeeming its points.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account redeeming the points.
+	/// * `amount` - The amount of the booking before the discount.
+	fn redeemable_discount(account: &AccountId, amount: Balance) -> Balance;
+
+	/// Redeem the points of an account as a discount on a booking.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account redeeming the points.
+	/// * `discount` - The discount obtained, as given by `redeemable_discount`.
+	fn redeem_points(account: &AccountId, discount: Balance) -> DispatchResult;
+
+	/// Give back the points redeemed on a booking that has been canceled.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account that redeemed the points.
+	/// * `discount` - The discount obtained when the points were redeemed.
+	fn restore_points(account: &AccountId, discount: Balance);
+}
+
+/// There is no loyalty program, so no discount is ever available.
+impl<AccountId, Balance: Zero> LoyaltyProgram<AccountId, Balance> for () {
+	fn on_booking_completed(_guest: &AccountId, _amount: Balance) {}
+
+	fn redeemable_discount(_account: &AccountId, _amount: Balance) -> Balance {
+		Zero::zero()
+	}
+
+	fn redeem_points(_account: &AccountId, _discount: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn restore_points(_account: &AccountId, _discount: Balance) {}
+}
+
+/// Hook for a referral program, rewarding the referrer of an account with a share of the amount
+/// of its completed bookings, paid by the platform.
+pub trait ReferralProgram<AccountId, Balance> {
+	/// A booking of an account, as guest or as host, has been completed.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The guest or the host of the booking.
+	/// * `amount` - The amount of the booking.
+	///
+	/// # Returns
+	///
+	/// Returns the referrer of the account and its reward, if the referrer is still rewarded for
+	/// the bookings of the account.
+	fn on_booking_completed(account: &AccountId, amount: Balance) -> Option<(AccountId, Balance)>;
+}
+
+/// There is no referral program, so no reward is ever paid.
+impl<AccountId, Balance> ReferralProgram<AccountId, Balance> for () {
+	fn on_booking_completed(
+		_account: &AccountId,
+		_amount: Balance,
+	) -> Option<(AccountId, Balance)> {
+		None
+	}
+}
+
+/// Dates of the places blocked by their hosts outside of the bookings, such as the stays booked
+/// on other platforms.
+pub trait BlockedDates<Hash> {
+	/// Get the periods in which a place cannot be booked.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the place.
+	///
+	/// # Returns
+	///
+	/// Returns the checkin and checkout dates of every blocked period, in the local time of the
+	/// place.
+	fn blocked_periods(place_id: &Hash) -> Vec<(CalendarDate, CalendarDate)>;
+}
+
+/// The hosts cannot block dates, so the places are only unavailable when booked.
+impl<Hash> BlockedDates<Hash> for () {
+	fn blocked_periods(_place_id: &Hash) -> Vec<(CalendarDate, CalendarDate)> {
+		Vec::new()
+	}
+}
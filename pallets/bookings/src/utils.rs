@@ -1,36 +1,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-use crate::{Config, Pallet};
-use chrono::{DateTime, Duration, NaiveDateTime, Timelike, Utc};
-use frame_support::{
-	sp_runtime::{traits::CheckedSub, ArithmeticError, DispatchError},
-	sp_std::cmp::Ordering,
-};
+use crate::{CalendarDate, Config, Error, Pallet};
+use frame_support::{ensure, sp_runtime::DispatchError, traits::Get};
+
+const MILLIS_PER_MINUTE: u64 = 60_000;
+const MILLIS_PER_HOUR: u64 = 60 * MILLIS_PER_MINUTE;
+const MILLIS_PER_DAY: u64 = 24 * MILLIS_PER_HOUR;
 
 /// Utils functions implementation
 impl<T: Config> Pallet<T> {
-	/// This function takes a u64 value and calculates its size
-	///
-	/// # Arguments
-	///
-	/// * `n` - The u64 number to process
-	///
-	/// # Returns
-	///
-	/// Returns a `usize` with the length of the number.
-	fn u64_len(mut n: u64) -> usize {
-		if n == 0 {
-			return 1 // A single-digit number has length 1.
-		}
-
-		let mut len = 0;
-		while n > 0 {
-			len += 1;
-			n /= 10;
-		}
-
-		len
-	}
-
 	/// Convert a Moment to a 64-bit unsigned integer representing milliseconds.
 	///
 	/// This function takes a Moment 64-bit unsigned integer representing milliseconds since the
@@ -47,13 +24,7 @@ impl<T: Config> Pallet<T> {
 	/// If the conversion fails, the `Result` contains a `DispatchError` explaining the reason for
 	/// the failure.
 	pub fn convert_moment_to_u64_in_milliseconds(date: T::Moment) -> Result<u64, DispatchError> {
-		let date_as_u64_millis;
-		if let Some(_date_as_u64) = TryInto::<u64>::try_into(date).ok() {
-			date_as_u64_millis = _date_as_u64;
-		} else {
-			return Err(DispatchError::Other("Unable to convert Moment to u64 for date"))
-		}
-		return Ok(date_as_u64_millis)
+		TryInto::<u64>::try_into(date).map_err(|_| Error::<T>::DateOutOfRange.into())
 	}
 
 	/// Convert a 64-bit unsigned integer timestamp to a Moment.
@@ -74,98 +45,201 @@ impl<T: Config> Pallet<T> {
 	/// If the conversion fails, the `Result` contains a `DispatchError` explaining the reason for
 	/// the failure.
 	fn convert_u64_to_moment(timestamp: u64) -> Result<T::Moment, DispatchError> {
-		if let Some(moment) = TryInto::<T::Moment>::try_into(timestamp).ok() {
-			return Ok(moment)
+		TryInto::<T::Moment>::try_into(timestamp).map_err(|_| Error::<T>::DateOutOfRange.into())
+	}
+
+	/// Convert a calendar date and an hour of the day in the local time of a place to a Moment.
+	///
+	/// The conversion is deterministic: the Moment holds the milliseconds elapsed since the Unix
+	/// epoch until the given hour of the date, once the offset of the place is subtracted.
+	///
+	/// # Arguments
+	///
+	/// * `date` - The calendar date.
+	/// * `hour` - The hour of the day (in 24-hour format), in the local time of the place.
+	/// * `utc_offset_minutes` - The offset of the local time of the place from UTC, in minutes.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` containing the Moment on success. If the date does not exist, the hour
+	/// is out of range or the resulting Moment cannot be represented, the `Result` contains the
+	/// specific error.
+	pub fn calendar_date_to_moment(
+		date: &CalendarDate,
+		hour: u32,
+		utc_offset_minutes: i16,
+	) -> Result<T::Moment, DispatchError> {
+		ensure!(hour <= 23, Error::<T>::HourOutOfRange);
+		let days = date.days_since_epoch().ok_or(Error::<T>::InvalidCalendarDate)?;
+
+		let local_timestamp = days
+			.checked_mul(MILLIS_PER_DAY)
+			.and_then(|millis| millis.checked_add(hour as u64 * MILLIS_PER_HOUR))
+			.ok_or(Error::<T>::DateOutOfRange)?;
+
+		let offset = utc_offset_minutes.unsigned_abs() as u64 * MILLIS_PER_MINUTE;
+		let timestamp = if utc_offset_minutes >= 0 {
+			local_timestamp.checked_sub(offset)
 		} else {
-			return Err(DispatchError::Other("Unable to convert u64 to Moment"))
+			local_timestamp.checked_add(offset)
 		}
+		.ok_or(Error::<T>::DateOutOfRange)?;
+
+		Self::convert_u64_to_moment(timestamp)
 	}
 
-	/// Convert a Moment to a UTC DateTime.
+	/// Calculate the moment at which the remaining balance of a booking is collected.
 	///
-	/// This function takes a `Moment` and converts it into a UTC `DateTime` with 13-digit
-	/// precision. If the provided `Moment` is not represented with 13-digit precision (i.e.,
-	/// milliseconds), the function returns a `DispatchError` indicating the failure.
+	/// # Arguments
+	///
+	/// * `start_date` - The checkin moment of the booking.
+	/// * `balance_due_days` - The number of days before the checkin at which the balance is due.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` containing the due moment on success. Due dates earlier than the Unix
+	/// epoch are moved to the epoch. If the conversion fails, it returns the specific error.
+	pub fn calculate_balance_due_date(
+		start_date: T::Moment,
+		balance_due_days: u32,
+	) -> Result<T::Moment, DispatchError> {
+		let start_timestamp = Self::convert_moment_to_u64_in_milliseconds(start_date)?;
+		let due_timestamp = start_timestamp
+			.saturating_sub((balance_due_days as u64).saturating_mul(MILLIS_PER_DAY));
+
+		Self::convert_u64_to_moment(due_timestamp)
+	}
+
+	/// Calculate the moment from which a booking can be marked as a no-show.
 	///
 	/// # Arguments
 	///
-	/// * `timestamp` - The `Moment` to convert to a UTC `DateTime` with 13-digit precision.
+	/// * `start_date` - The checkin moment of the booking.
+	/// * `grace_period_hours` - The number of hours the host has to wait after the checkin.
 	///
 	/// # Returns
 	///
-	/// Returns a `Result` containing the converted UTC `DateTime` on success.
-	/// If the provided `Moment` does not have 13-digit precision or any other error occurs during
-	/// the conversion, the `Result` contains a `DispatchError` explaining the reason for the
-	/// failure.
-	fn convert_moment_to_datetime(timestamp: T::Moment) -> Result<DateTime<Utc>, DispatchError> {
-		let mut timestamp_parsed: u64 = Self::convert_moment_to_u64_in_milliseconds(timestamp)?;
-		match Self::u64_len(timestamp_parsed) {
-			10 => {
-				// The timestamp is in seconds. Multiply it by 1000 to convert it to milliseconds.
-				timestamp_parsed = timestamp_parsed
-					.checked_mul(1000)
-					.ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))?;
-			},
-			13 => {}, // The timestamp is in milliseconds. No need to do anything.
-			_ => return Err(DispatchError::Other("Timestamp precision is not 10 nor 13")),
-		}
-		let timestamp_i64: i64 = i64::try_from(timestamp_parsed).unwrap();
-		let datetime = DateTime::<Utc>::from_utc(
-			NaiveDateTime::from_timestamp_millis(timestamp_i64).unwrap(),
-			Utc,
-		);
-		if datetime.timestamp_millis() == timestamp_i64 {
-			// This does not fix anything it seems, we might need to check if the year is valid or
-			// else
-			return Ok(datetime)
-		}
-		Err(DispatchError::Other("Provided timestamp is not a 13-digit precision timestamp"))
+	/// Returns a `Result` containing the moment on success. If the moment cannot be represented,
+	/// it returns the specific error.
+	pub fn calculate_no_show_date(
+		start_date: T::Moment,
+		grace_period_hours: u32,
+	) -> Result<T::Moment, DispatchError> {
+		let start_timestamp = Self::convert_moment_to_u64_in_milliseconds(start_date)?;
+		let no_show_timestamp = start_timestamp
+			.checked_add((grace_period_hours as u64).saturating_mul(MILLIS_PER_HOUR))
+			.ok_or(Error::<T>::DateOutOfRange)?;
+
+		Self::convert_u64_to_moment(no_show_timestamp)
 	}
 
-	/// Modify the timestamp by setting the desired time of day.
+	/// Calculate the moment until which some dates are held for a guest of a waitlist.
 	///
-	/// This function takes a Moment (`timestamp`) and a desired hour of the day (`desired_time` in
-	/// 24-hour format). It modifies the timestamp to have the desired time, setting minutes,
-	/// seconds, and nanoseconds to zero. The function returns a Moment with the new time set.
+	/// # Arguments
+	///
+	/// * `current_moment` - The moment at which the guest is notified.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` containing the moment on success, once the `WaitlistClaimPeriod` has
+	/// passed. If the moment cannot be represented, it returns the specific error.
+	pub fn calculate_waitlist_claim_date(
+		current_moment: T::Moment,
+	) -> Result<T::Moment, DispatchError> {
+		let current_timestamp = Self::convert_moment_to_u64_in_milliseconds(current_moment)?;
+		let claim_timestamp = current_timestamp
+			.checked_add(T::WaitlistClaimPeriod::get())
+			.ok_or(Error::<T>::DateOutOfRange)?;
+
+		Self::convert_u64_to_moment(claim_timestamp)
+	}
+
+	/// Calculate the moment at which an itinerary is canceled if some legs are unconfirmed.
 	///
 	/// # Arguments
 	///
-	/// * `timestamp` - The original Moment representing the starting timestamp.
-	/// * `desired_time` - The desired hour of the day (in 24-hour format) to set in the modified
-	///   timestamp.
+	/// * `current_moment` - The moment at which the itinerary is created.
 	///
 	/// # Returns
 	///
-	/// Returns a `Result` containing the modified Moment on success.
-	/// If the `desired_time` is out of range or any other error occurs during the conversion, the
-	/// `Result` contains a `DispatchError` explaining the reason for the failure.
-	pub fn modify_timestamp(
-		timestamp: T::Moment,
-		desired_time: u32,
+	/// Returns a `Result` containing the moment on success, once the
+	/// `ItineraryConfirmationPeriod` has passed. If the moment cannot be represented, it returns
+	/// the specific error.
+	pub fn calculate_itinerary_deadline(
+		current_moment: T::Moment,
 	) -> Result<T::Moment, DispatchError> {
-		match (desired_time.cmp(&0), desired_time.cmp(&23)) {
-			(Ordering::Greater, Ordering::Less) |
-			(Ordering::Equal, Ordering::Less) |
-			(Ordering::Greater, Ordering::Equal) => {},
-			_ => return Err(DispatchError::Other("desired time out of range")),
-		}
-		let datetime = Self::convert_moment_to_datetime(timestamp)?;
-		let formatted_timestamp = datetime
-			.with_hour(desired_time)
-			.and_then(|dt| dt.with_minute(0))
-			.and_then(|dt| dt.with_second(0))
-			.and_then(|dt| dt.with_nanosecond(0))
-			.map(|dt| dt.timestamp_millis())
-			.unwrap();
-
-		Ok(Self::convert_u64_to_moment(formatted_timestamp.try_into().unwrap())?)
+		let current_timestamp = Self::convert_moment_to_u64_in_milliseconds(current_moment)?;
+		let deadline_timestamp = current_timestamp
+			.checked_add(T::ItineraryConfirmationPeriod::get())
+			.ok_or(Error::<T>::DateOutOfRange)?;
+
+		Self::convert_u64_to_moment(deadline_timestamp)
+	}
+
+	/// Calculate the moment from which a finished booking can be archived.
+	///
+	/// # Arguments
+	///
+	/// * `end_date` - The checkout moment of the booking.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` containing the moment on success, once the `BookingRetentionPeriod` has
+	/// passed. If the moment cannot be represented, it returns the specific error.
+	pub fn calculate_archival_date(end_date: T::Moment) -> Result<T::Moment, DispatchError> {
+		let end_timestamp = Self::convert_moment_to_u64_in_milliseconds(end_date)?;
+		let archival_timestamp = end_timestamp
+			.checked_add(T::BookingRetentionPeriod::get())
+			.ok_or(Error::<T>::DateOutOfRange)?;
+
+		Self::convert_u64_to_moment(archival_timestamp)
+	}
+
+	/// Calculate the moment until which the participants of a finished booking can post messages.
+	///
+	/// # Arguments
+	///
+	/// * `end_date` - The checkout moment of the booking.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` containing the moment on success, once the `DisputeWindow` has passed.
+	/// If the moment cannot be represented, it returns the specific error.
+	pub fn calculate_dispute_deadline(end_date: T::Moment) -> Result<T::Moment, DispatchError> {
+		let end_timestamp = Self::convert_moment_to_u64_in_milliseconds(end_date)?;
+		let deadline_timestamp = end_timestamp
+			.checked_add(T::DisputeWindow::get())
+			.ok_or(Error::<T>::DateOutOfRange)?;
+
+		Self::convert_u64_to_moment(deadline_timestamp)
+	}
+
+	/// Calculate the number of nights between two calendar dates.
+	///
+	/// # Arguments
+	///
+	/// * `start_date` - The start date of the booking.
+	/// * `end_date` - The end date of the booking.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` containing the number of nights on success. If the dates are not valid
+	/// or the end date is earlier than the start date, it returns the specific error.
+	pub fn calculate_nights(
+		start_date: &CalendarDate,
+		end_date: &CalendarDate,
+	) -> Result<u64, DispatchError> {
+		let start_day = start_date.days_since_epoch().ok_or(Error::<T>::InvalidCalendarDate)?;
+		let end_day = end_date.days_since_epoch().ok_or(Error::<T>::InvalidCalendarDate)?;
+
+		end_day.checked_sub(start_day).ok_or(Error::<T>::InvalidDates.into())
 	}
 
 	/// Calculate the total amount of a booking
 	///
-	/// This function takes two dates (T::Moment) and the price per night of a certain place.
-	/// Then, it calculates the days between both provided moments and multiplies that by the price
-	/// per night. All the arithmetic operations are performed with overflow control.
+	/// This function takes two calendar dates and the price per night of a certain place.
+	/// Then, it counts the nights between both dates and multiplies that by the price per night.
+	/// All the arithmetic operations are performed with overflow control.
 	///
 	/// # Arguments
 	///
@@ -178,24 +252,14 @@ impl<T: Config> Pallet<T> {
 	/// Returns a `Result` containing the total price of the booking if success. If some operation
 	/// fails, it returns the specific error.
 	pub fn calculate_total_amount(
-		start_date: T::Moment,
-		end_date: T::Moment,
+		start_date: &CalendarDate,
+		end_date: &CalendarDate,
 		price_per_night: u64,
 	) -> Result<u64, DispatchError> {
-		let days_in_between = end_date
-			.checked_sub(&start_date)
-			.ok_or(DispatchError::Arithmetic(ArithmeticError::Underflow))?;
-
-		let timestamp_u64 = Self::convert_moment_to_u64_in_milliseconds(days_in_between)?;
-		let timestamp_i64: i64 = i64::try_from(timestamp_u64).unwrap();
-		let days = u64::try_from(Duration::milliseconds(timestamp_i64).num_days()).unwrap();
-		// Add one extra night as it is rounded down
-		let total_days = days
-			.checked_add(1)
-			.ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))?;
-
-		return total_days
+		let nights = Self::calculate_nights(start_date, end_date)?;
+
+		return nights
 			.checked_mul(price_per_night)
-			.ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))
+			.ok_or(Error::<T>::TotalAmountOverflow.into())
 	}
 }
@@ -1,10 +1,27 @@
 #[cfg(test)]
 pub mod mock;
 pub mod utils;
-use frame_support::{assert_noop, assert_ok, traits::Currency};
-use pallet_bookings::{BookingData, BookingState, BookingsData, Error};
-use pallet_places::{Error as PlaceError, PlaceType};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::GetDispatchInfo,
+	traits::{Currency, Get, GetStorageVersion, Hooks, ReservableCurrency, StorageVersion},
+	weights::Weight,
+};
+use pallet_bookings::{
+	BookingData, BookingState, BookingsData, CalendarDate, CalendarEntryKind, CouponData,
+	CouponTerms, Discount, Error, ItineraryData, ItineraryLeg, ItineraryState, MessageContent,
+	PendingItineraries, WaitlistClaims,
+};
+use pallet_places::{
+	Coordinates, Error as PlaceError, NoShowPolicy, PaymentSchedule, PlaceAttributes, PlaceRole,
+	PlaceType, VerificationLevel,
+};
 use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchError, DispatchResult, Perbill,
+};
 
 use crate::{mock::*, utils::*};
 
@@ -12,19 +29,26 @@ use crate::{mock::*, utils::*};
 const OWNER: u64 = 0;
 const GUEST_A: u64 = 1;
 const GUEST_B: u64 = 2;
+const MANAGER: u64 = 3;
+
+const MILLIS_PER_HOUR: u64 = 3_600_000;
+const MILLIS_PER_DAY: u64 = 24 * MILLIS_PER_HOUR;
 
 fn create_default_place() {
 	let _ = Places::create_place(
 		RuntimeOrigin::signed(OWNER),
 		PlaceType::Apartment,
 		b"Demo Place".to_vec(),
-		b"Demo Address".to_vec(),
+		b"Demo City".to_vec(),
+		Coordinates { latitude: 40_416_775, longitude: -3_703_790 },
 		create_hash("Demo Description"),
 		10,
 		17,
 		12,
+		0,
 		vec![create_hash("image_1"), create_hash("image_2")],
 		None,
+		PlaceAttributes::default(),
 	);
 	// To emit events, we need to be past block 0
 	setup_blocks(1);
@@ -35,8 +59,8 @@ fn create_default_booking() {
 	let month = 4;
 	let start_day = 10;
 	let end_day = 13;
-	let start_date: u64 = generate_timestamp(year, month, start_day, 17, 33, 44);
-	let end_date: u64 = generate_timestamp(year, month, end_day, 17, 33, 44);
+	let start_date = CalendarDate::new(year, month, start_day);
+	let end_date = CalendarDate::new(year, month, end_day);
 
 	let place_id: H256 = Places::get_all_places()[0];
 	let place_data = Places::get_place_by_id(place_id).unwrap();
@@ -50,6 +74,18 @@ fn create_default_booking() {
 		start_date,
 		end_date,
 		amount,
+		None,
+		false,
+	);
+}
+
+fn set_default_payment_schedule() {
+	let place_id: H256 = Places::get_all_places()[0];
+	// 30% deposit, with the balance due 14 days before the checkin
+	let _ = Places::set_payment_schedule(
+		RuntimeOrigin::signed(OWNER),
+		place_id,
+		Some(PaymentSchedule { deposit: Perbill::from_percent(30), balance_due_days: 14 }),
 	);
 }
 
@@ -58,6 +94,20 @@ fn confirm_default_booking() {
 	let _ = Bookings::confirm_booking(RuntimeOrigin::signed(OWNER), booking_id);
 }
 
+fn check_in_default_booking() {
+	let booking_id: H256 = Bookings::get_all_bookings()[0];
+	let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+	// Set current chain time to the expected start_date + 1 ot enable the checkin
+	<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(booking_data.start_date + 1);
+	let _ = Bookings::checkin(RuntimeOrigin::signed(GUEST_A), booking_id);
+}
+
+fn check_out_default_booking() {
+	let booking_id: H256 = Bookings::get_all_bookings()[0];
+	let _ = Bookings::checkout(RuntimeOrigin::signed(GUEST_A), booking_id);
+	let _ = Bookings::acknowledge_checkout(RuntimeOrigin::signed(OWNER), booking_id);
+}
+
 fn build_with_defult_place() -> sp_io::TestExternalities {
 	let mut ext = build_with_funded_accounts();
 	ext.execute_with(create_default_place);
@@ -76,76 +126,174 @@ fn build_with_default_confirmed_booking() -> sp_io::TestExternalities {
 	ext
 }
 
+fn build_with_default_checked_in_booking() -> sp_io::TestExternalities {
+	let mut ext = build_with_default_confirmed_booking();
+	ext.execute_with(check_in_default_booking);
+	ext
+}
+
 // ========================================================
 // Functions Unit Tests
 // ========================================================
 #[test]
-fn test_modify_timestamp_function() {
-	// Should work with seconds
+fn test_calendar_date_to_moment_function() {
 	build_with_funded_accounts().execute_with(|| {
-		let year = 2025;
-		let month = 4;
-		let start_day = 10;
-		let end_day = 13;
-
-		let checkin_hour = 17;
-		let checkout_hour = 12;
-
-		let start_date: u64 = generate_timestamp(year, month, start_day, 17, 33, 44);
-		let end_date: u64 = generate_timestamp(year, month, end_day, 17, 33, 44);
-
-		// Expected times
-		let expected_start_date: u64 =
-			generate_timestamp_millis(year, month, start_day, checkin_hour, 0, 0);
-		let expected_end_date: u64 =
-			generate_timestamp_millis(year, month, end_day, checkout_hour, 0, 0);
+		let date = CalendarDate::new(2025, 4, 10);
 
 		assert_eq!(
-			Bookings::modify_timestamp(start_date, checkin_hour).unwrap(),
-			expected_start_date
+			Bookings::calendar_date_to_moment(&date, 17, 0).unwrap(),
+			generate_timestamp_millis(2025, 4, 10, 17, 0, 0)
+		);
+		assert_eq!(
+			Bookings::calendar_date_to_moment(&CalendarDate::new(1970, 1, 1), 0, 0).unwrap(),
+			0
+		);
+		// Leap days
+		assert_eq!(
+			Bookings::calendar_date_to_moment(&CalendarDate::new(2024, 2, 29), 12, 0).unwrap(),
+			generate_timestamp_millis(2024, 2, 29, 12, 0, 0)
+		);
+		assert_eq!(
+			Bookings::calendar_date_to_moment(&CalendarDate::new(2000, 3, 1), 12, 0).unwrap(),
+			generate_timestamp_millis(2000, 3, 1, 12, 0, 0)
 		);
-		assert_eq!(Bookings::modify_timestamp(end_date, checkout_hour).unwrap(), expected_end_date);
 	});
+}
 
-	// Should work with milliseconds
+#[test]
+fn test_calendar_date_to_moment_with_wrong_input_should_fail() {
 	build_with_funded_accounts().execute_with(|| {
-		let year = 2025;
-		let month = 4;
-		let start_day = 10;
-		let end_day = 13;
+		assert_noop!(
+			Bookings::calendar_date_to_moment(&CalendarDate::new(2025, 4, 10), 24, 0),
+			Error::<Test>::HourOutOfRange
+		);
+		for invalid_date in [
+			CalendarDate::new(2025, 2, 29),
+			CalendarDate::new(2025, 4, 31),
+			CalendarDate::new(2025, 13, 1),
+			CalendarDate::new(2025, 1, 0),
+			CalendarDate::new(1969, 12, 31),
+		] {
+			assert_noop!(
+				Bookings::calendar_date_to_moment(&invalid_date, 12, 0),
+				Error::<Test>::InvalidCalendarDate
+			);
+		}
+		// The first hour of the epoch in a place ahead of UTC is before the epoch
+		assert_noop!(
+			Bookings::calendar_date_to_moment(&CalendarDate::new(1970, 1, 1), 0, 60),
+			Error::<Test>::DateOutOfRange
+		);
+	});
+}
 
-		let checkin_hour = 17;
-		let checkout_hour = 12;
+#[test]
+fn test_calendar_date_to_moment_with_utc_offset_function() {
+	build_with_funded_accounts().execute_with(|| {
+		let date = CalendarDate::new(2025, 4, 10);
 
-		let start_date: u64 = generate_timestamp_millis(year, month, start_day, 17, 33, 44);
-		let end_date: u64 = generate_timestamp_millis(year, month, end_day, 17, 33, 44);
+		// A 15:00 checkin in Tokyo, 9 hours ahead of UTC, is 06:00 UTC
+		assert_eq!(
+			Bookings::calendar_date_to_moment(&date, 15, 9 * 60).unwrap(),
+			generate_timestamp_millis(2025, 4, 10, 6, 0, 0)
+		);
+		// A 22:00 checkin in New York, 4 hours behind UTC, is 02:00 UTC of the next day
+		assert_eq!(
+			Bookings::calendar_date_to_moment(&date, 22, -4 * 60).unwrap(),
+			generate_timestamp_millis(2025, 4, 11, 2, 0, 0)
+		);
+	});
+}
 
-		// Expected times
-		let expected_start_date: u64 =
-			generate_timestamp_millis(year, month, start_day, checkin_hour, 0, 0);
-		let expected_end_date: u64 =
-			generate_timestamp_millis(year, month, end_day, checkout_hour, 0, 0);
+#[test]
+fn test_calculate_total_amount_function() {
+	build_with_funded_accounts().execute_with(|| {
+		let price_per_night = 10;
 
 		assert_eq!(
-			Bookings::modify_timestamp(start_date, checkin_hour).unwrap(),
-			expected_start_date
+			Bookings::calculate_total_amount(
+				&CalendarDate::new(2025, 4, 10),
+				&CalendarDate::new(2025, 4, 13),
+				price_per_night
+			),
+			Ok(30)
+		);
+		// Across months and a leap day
+		assert_eq!(
+			Bookings::calculate_total_amount(
+				&CalendarDate::new(2024, 2, 27),
+				&CalendarDate::new(2024, 3, 2),
+				price_per_night
+			),
+			Ok(40)
+		);
+		assert_noop!(
+			Bookings::calculate_total_amount(
+				&CalendarDate::new(2025, 4, 13),
+				&CalendarDate::new(2025, 4, 10),
+				price_per_night
+			),
+			Error::<Test>::InvalidDates
 		);
-		assert_eq!(Bookings::modify_timestamp(end_date, checkout_hour).unwrap(), expected_end_date);
 	});
+}
 
-	build_with_funded_accounts().execute_with(|| {
-		let year = 2025;
-		let month = 4;
-		let start_day = 10;
+#[test]
+fn test_is_place_available_function() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id: H256 = Places::get_all_places()[0];
 
-		let checkin_hour = 25;
+		// The confirmed booking takes the place
+		let start_date = CalendarDate::new(2025, 4, 10);
+		let end_date = CalendarDate::new(2025, 4, 13);
+		assert!(!Bookings::is_place_available(&place_id, &start_date, &end_date));
 
-		let start_date: u64 = generate_timestamp_millis(year, month, start_day, 17, 33, 44);
+		let start_date = CalendarDate::new(2025, 5, 10);
+		let end_date = CalendarDate::new(2025, 5, 13);
+		assert!(Bookings::is_place_available(&place_id, &start_date, &end_date));
 
-		assert_noop!(
-			Bookings::modify_timestamp(start_date, checkin_hour),
-			sp_runtime::DispatchError::Other("desired time out of range")
+		// Switched dates and unknown places are never available
+		assert!(!Bookings::is_place_available(&place_id, &end_date, &start_date));
+		assert!(!Bookings::is_place_available(&create_hash("Unknown"), &start_date, &end_date));
+	});
+}
+
+#[test]
+fn test_place_calendar_function() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id: H256 = Places::get_all_places()[0];
+		let booking_id = Bookings::get_all_bookings()[0];
+		let booking = Bookings::get_booking_by_id(booking_id).unwrap();
+		BlockedPeriods::set(vec![(
+			place_id,
+			CalendarDate::new(2025, 5, 1),
+			CalendarDate::new(2025, 5, 3),
+		)]);
+
+		let calendar = Bookings::place_calendar(&place_id).unwrap();
+		assert_eq!(calendar.name, b"Demo Place".to_vec());
+		assert_eq!((calendar.checkin_hour, calendar.checkout_hour), (17, 12));
+		assert_eq!(
+			calendar
+				.entries
+				.iter()
+				.map(|entry| (entry.kind, entry.start, entry.end))
+				.collect::<Vec<_>>(),
+			vec![
+				(CalendarEntryKind::Booked, booking.start_date, booking.end_date),
+				(
+					CalendarEntryKind::Blocked,
+					Bookings::calendar_date_to_moment(&CalendarDate::new(2025, 5, 1), 17, 0)
+						.unwrap(),
+					Bookings::calendar_date_to_moment(&CalendarDate::new(2025, 5, 3), 12, 0)
+						.unwrap()
+				),
+			]
 		);
+		// The identifiers are stable across exports
+		assert_eq!(calendar.entries[0].uid, BlakeTwo256::hash_of(&(place_id, booking_id)));
+
+		assert_eq!(Bookings::place_calendar(&create_hash("Unknown")), None);
 	});
 }
 
@@ -163,8 +311,8 @@ fn test_create_booking_should_work() {
 		let start_day = 10;
 		let end_day = 13;
 
-		let start_date: u64 = generate_timestamp_millis(year, month, start_day, 17, 33, 44);
-		let end_date: u64 = generate_timestamp_millis(year, month, end_day, 17, 33, 44);
+		let start_date = CalendarDate::new(year, month, start_day);
+		let end_date = CalendarDate::new(year, month, end_day);
 
 		let n_days = end_day - start_day;
 		let amount = (n_days as u64) * place_data.price_per_night;
@@ -174,16 +322,26 @@ fn test_create_booking_should_work() {
 			place_id,
 			start_date,
 			end_date,
-			amount
+			amount,
+			None,
+			false
 		));
 
 		let booking_id = Bookings::get_all_bookings()[0];
 		let booking_data = Bookings::get_booking_by_id(booking_id);
 
-		let formatted_start_date =
-			Bookings::modify_timestamp(start_date, place_data.checkin_hour).unwrap();
-		let formatted_end_date =
-			Bookings::modify_timestamp(end_date, place_data.checkout_hour).unwrap();
+		let formatted_start_date = Bookings::calendar_date_to_moment(
+			&start_date,
+			place_data.checkin_hour,
+			place_data.utc_offset_minutes,
+		)
+		.unwrap();
+		let formatted_end_date = Bookings::calendar_date_to_moment(
+			&end_date,
+			place_data.checkout_hour,
+			place_data.utc_offset_minutes,
+		)
+		.unwrap();
 
 		// Check the place has been created correctly
 		assert_eq!(
@@ -195,6 +353,8 @@ fn test_create_booking_should_work() {
 				start_date: formatted_start_date,
 				end_date: formatted_end_date,
 				amount,
+				paid: amount,
+				balance_due_date: None,
 				state: BookingState::Created
 			})
 		);
@@ -218,8 +378,8 @@ fn test_create_booking_with_invalid_place_should_fail() {
 		let start_day = 10;
 		let end_day = 13;
 
-		let start_date: u64 = generate_timestamp_millis(year, month, start_day, 17, 33, 44);
-		let end_date: u64 = generate_timestamp_millis(year, month, end_day, 17, 33, 44);
+		let start_date = CalendarDate::new(year, month, start_day);
+		let end_date = CalendarDate::new(year, month, end_day);
 
 		let amount: u64 = 10;
 
@@ -231,7 +391,9 @@ fn test_create_booking_with_invalid_place_should_fail() {
 				place_id,
 				start_date,
 				end_date,
-				amount
+				amount,
+				None,
+				false
 			),
 			PlaceError::<Test>::PlaceNotFound
 		);
@@ -249,8 +411,8 @@ fn test_create_booking_with_invalid_dates_should_fail() {
 		let start_day = 10;
 		let end_day = 13;
 
-		let start_date: u64 = generate_timestamp_millis(year, month, start_day, 17, 33, 44);
-		let end_date: u64 = generate_timestamp_millis(year, month, end_day, 17, 33, 44);
+		let start_date = CalendarDate::new(year, month, start_day);
+		let end_date = CalendarDate::new(year, month, end_day);
 
 		let n_days = end_day - start_day;
 		let amount = (n_days as u64) * place_data.price_per_night;
@@ -261,7 +423,9 @@ fn test_create_booking_with_invalid_dates_should_fail() {
 				place_id,
 				end_date, // switched dates
 				start_date,
-				amount
+				amount,
+				None,
+				false
 			),
 			Error::<Test>::InvalidDates
 		);
@@ -279,8 +443,8 @@ fn test_create_booking_without_funds_should_fail() {
 		let start_day = 10;
 		let end_day = 13;
 
-		let start_date: u64 = generate_timestamp_millis(year, month, start_day, 17, 33, 44);
-		let end_date: u64 = generate_timestamp_millis(year, month, end_day, 17, 33, 44);
+		let start_date = CalendarDate::new(year, month, start_day);
+		let end_date = CalendarDate::new(year, month, end_day);
 
 		let n_days = end_day - start_day;
 		let amount = (n_days as u64) * place_data.price_per_night;
@@ -291,13 +455,81 @@ fn test_create_booking_without_funds_should_fail() {
 				place_id,
 				start_date,
 				end_date,
-				amount
+				amount,
+				None,
+				false
 			),
 			Error::<Test>::NotEnoughFreeBalance
 		);
 	})
 }
 
+#[test]
+fn test_create_booking_with_wrong_amount_should_fail() {
+	build_with_defult_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let start_date = CalendarDate::new(2025, 4, 10);
+		let end_date = CalendarDate::new(2025, 4, 13);
+
+		let expected_amount =
+			Bookings::quote_booking(&place_id, &start_date, &end_date, None, None).unwrap();
+		assert_eq!(expected_amount, 30);
+
+		assert_noop!(
+			Bookings::create_booking(
+				RuntimeOrigin::signed(GUEST_A),
+				place_id,
+				start_date,
+				end_date,
+				expected_amount - 1,
+				None,
+				false
+			),
+			Error::<Test>::AmountMismatch
+		);
+		assert_noop!(
+			Bookings::create_booking(
+				RuntimeOrigin::signed(GUEST_A),
+				place_id,
+				start_date,
+				end_date,
+				expected_amount + 1,
+				None,
+				false
+			),
+			Error::<Test>::AmountMismatch
+		);
+	})
+}
+
+#[test]
+fn test_quote_booking_with_wrong_input_should_fail() {
+	build_with_defult_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let start_date = CalendarDate::new(2025, 4, 10);
+		let end_date = CalendarDate::new(2025, 4, 13);
+
+		assert_noop!(
+			Bookings::quote_booking(&create_hash("dummy"), &start_date, &end_date, None, None),
+			PlaceError::<Test>::PlaceNotFound
+		);
+		assert_noop!(
+			Bookings::quote_booking(&place_id, &end_date, &start_date, None, None),
+			Error::<Test>::InvalidDates
+		);
+		assert_noop!(
+			Bookings::quote_booking(
+				&place_id,
+				&start_date,
+				&CalendarDate::new(2025, 4, 31),
+				None,
+				None
+			),
+			Error::<Test>::InvalidCalendarDate
+		);
+	})
+}
+
 #[test]
 fn test_create_booking_in_owned_place_should_fail() {
 	build_with_defult_place().execute_with(|| {
@@ -309,8 +541,8 @@ fn test_create_booking_in_owned_place_should_fail() {
 		let start_day = 10;
 		let end_day = 13;
 
-		let start_date: u64 = generate_timestamp_millis(year, month, start_day, 17, 33, 44);
-		let end_date: u64 = generate_timestamp_millis(year, month, end_day, 17, 33, 44);
+		let start_date = CalendarDate::new(year, month, start_day);
+		let end_date = CalendarDate::new(year, month, end_day);
 
 		let n_days = end_day - start_day;
 		let amount = (n_days as u64) * place_data.price_per_night;
@@ -321,13 +553,102 @@ fn test_create_booking_in_owned_place_should_fail() {
 				place_id,
 				start_date,
 				end_date,
-				amount
+				amount,
+				None,
+				false
 			),
 			Error::<Test>::CannotBookOwnedPlace
 		);
 	})
 }
 
+#[test]
+fn test_create_booking_without_required_verification_should_fail() {
+	build_with_defult_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let start_date = CalendarDate::new(2025, 4, 10);
+		let end_date = CalendarDate::new(2025, 4, 13);
+
+		assert_ok!(Places::set_guest_verification(
+			RuntimeOrigin::signed(OWNER),
+			place_id,
+			Some(VerificationLevel::IdVerified)
+		));
+		VerifiedAccounts::set(vec![(GUEST_A, VerificationLevel::PhoneVerified)]);
+
+		assert_noop!(
+			Bookings::create_booking(
+				RuntimeOrigin::signed(GUEST_A),
+				place_id,
+				start_date,
+				end_date,
+				30,
+				None,
+				false
+			),
+			Error::<Test>::GuestNotVerified
+		);
+
+		// Once the identity of the guest is verified, the place can be booked
+		VerifiedAccounts::set(vec![(GUEST_A, VerificationLevel::IdVerified)]);
+		assert_ok!(Bookings::create_booking(
+			RuntimeOrigin::signed(GUEST_A),
+			place_id,
+			start_date,
+			end_date,
+			30,
+			None,
+			false
+		));
+	})
+}
+
+#[test]
+fn test_create_booking_in_blocked_dates_should_fail() {
+	build_with_defult_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		BlockedPeriods::set(vec![(
+			place_id,
+			CalendarDate::new(2025, 4, 11),
+			CalendarDate::new(2025, 4, 12),
+		)]);
+
+		assert_noop!(
+			Bookings::create_booking(
+				RuntimeOrigin::signed(GUEST_A),
+				place_id,
+				CalendarDate::new(2025, 4, 10),
+				CalendarDate::new(2025, 4, 13),
+				30,
+				None,
+				false
+			),
+			Error::<Test>::BookingDatesNotAvailable
+		);
+		assert!(!Bookings::is_place_available(
+			&place_id,
+			&CalendarDate::new(2025, 4, 10),
+			&CalendarDate::new(2025, 4, 12)
+		));
+
+		// The stays can end the day the blocked period starts, and start the day it ends
+		assert!(Bookings::is_place_available(
+			&place_id,
+			&CalendarDate::new(2025, 4, 12),
+			&CalendarDate::new(2025, 4, 13)
+		));
+		assert_ok!(Bookings::create_booking(
+			RuntimeOrigin::signed(GUEST_A),
+			place_id,
+			CalendarDate::new(2025, 4, 10),
+			CalendarDate::new(2025, 4, 11),
+			10,
+			None,
+			false
+		));
+	})
+}
+
 #[test]
 fn test_create_booking_with_outdated_start_day_should_fail() {
 	build_with_defult_place().execute_with(|| {
@@ -339,13 +660,18 @@ fn test_create_booking_with_outdated_start_day_should_fail() {
 		let start_day = 10;
 		let end_day = 13;
 
-		let start_date: u64 = generate_timestamp_millis(year, month, start_day, 17, 33, 44);
-		let end_date: u64 = generate_timestamp_millis(year, month, end_day, 17, 33, 44);
+		let start_date = CalendarDate::new(year, month, start_day);
+		let end_date = CalendarDate::new(year, month, end_day);
 
 		let n_days = end_day - start_day;
 		let amount = (n_days as u64) * place_data.price_per_night;
 
-		let current_time = Bookings::modify_timestamp(start_date, place_data.checkin_hour).unwrap();
+		let current_time = Bookings::calendar_date_to_moment(
+			&start_date,
+			place_data.checkin_hour,
+			place_data.utc_offset_minutes,
+		)
+		.unwrap();
 
 		// Set current chain time to the expected start_date + 1
 		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(current_time + 1);
@@ -358,7 +684,9 @@ fn test_create_booking_with_outdated_start_day_should_fail() {
 				place_id,
 				start_date,
 				end_date,
-				amount
+				amount,
+				None,
+				false
 			),
 			Error::<Test>::InvalidStartDate
 		);
@@ -366,206 +694,1846 @@ fn test_create_booking_with_outdated_start_day_should_fail() {
 }
 
 // ========================================================
-// Confirm Bookings Unit Tests
+// Coupons Unit Tests
 // ========================================================
+fn default_coupon_terms() -> CouponTerms<Test> {
+	CouponTerms {
+		discount: Discount::Percentage(Perbill::from_percent(10)),
+		places: vec![],
+		max_redemptions: Some(2),
+		max_redemptions_per_account: Some(1),
+		valid_from: 0,
+		valid_until: MILLIS_PER_DAY,
+		min_nights: 2,
+	}
+}
+
+fn coupon_code_hash(code: &str) -> H256 {
+	BlakeTwo256::hash(code.as_bytes())
+}
+
+fn book_default_dates_with_coupon(guest: u64, amount: u64, code: &str) -> DispatchResult {
+	let place_id: H256 = Places::get_all_places()[0];
+	Bookings::create_booking(
+		RuntimeOrigin::signed(guest),
+		place_id,
+		CalendarDate::new(2025, 4, 10),
+		CalendarDate::new(2025, 4, 13),
+		amount,
+		Some(code.as_bytes().to_vec()),
+		false,
+	)
+}
+
 #[test]
-fn test_confirm_booking_should_work() {
-	build_with_defult_place_and_booking().execute_with(|| {
-		let booking_id: H256 = Bookings::get_all_bookings()[0];
+fn test_create_coupon_should_work() {
+	build_with_defult_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let code = coupon_code_hash("SPRING10");
+		let terms = CouponTerms { places: vec![place_id], ..default_coupon_terms() };
 
-		assert_ok!(Bookings::confirm_booking(RuntimeOrigin::signed(OWNER), booking_id));
+		// Hosts can only create coupons for their own places
+		assert_noop!(
+			Bookings::create_coupon(RuntimeOrigin::signed(GUEST_B), code, terms.clone()),
+			Error::<Test>::NotPlaceOwner
+		);
+		assert_noop!(
+			Bookings::create_coupon(
+				RuntimeOrigin::signed(OWNER),
+				code,
+				CouponTerms { valid_until: 0, ..terms.clone() }
+			),
+			Error::<Test>::InvalidCouponTerms
+		);
 
-		let booking_data = Bookings::get_booking_by_id(booking_id).unwrap();
-		assert_eq!(booking_data.state, BookingState::Confirmed);
+		assert_ok!(Bookings::create_coupon(RuntimeOrigin::signed(OWNER), code, terms.clone()));
+		let coupon: CouponData<Test> = Bookings::get_coupon(code).unwrap();
+		assert_eq!(coupon.issuer, Some(OWNER));
+		assert_eq!(coupon.terms, terms);
+		assert_eq!(coupon.redemptions, 0);
 
 		// Check emitted events
 		System::assert_last_event(
-			pallet_bookings::Event::BookingUpdated {
-				id: booking_id,
-				sender: OWNER,
-				state: BookingState::Confirmed,
-			}
-			.into(),
+			pallet_bookings::Event::CouponCreated { code, issuer: Some(OWNER) }.into(),
 		);
-	})
-}
-
-#[test]
-fn test_confirm_missing_booking_should_fail() {
-	build_with_funded_accounts().execute_with(|| {
-		let booking_id: H256 = create_hash("dummy");
 
 		assert_noop!(
-			Bookings::confirm_booking(RuntimeOrigin::signed(GUEST_A), booking_id),
-			Error::<Test>::BookingNotFound
+			Bookings::create_coupon(RuntimeOrigin::signed(OWNER), code, terms),
+			Error::<Test>::CouponAlreadyExists
 		);
 	})
 }
 
 #[test]
-fn test_confirm_booking_not_owner_should_fail() {
-	build_with_defult_place_and_booking().execute_with(|| {
-		let booking_id: H256 = Bookings::get_all_bookings()[0];
+fn test_create_platform_coupon_should_work() {
+	build_with_defult_place().execute_with(|| {
+		let code = coupon_code_hash("WELCOME");
 
 		assert_noop!(
-			Bookings::confirm_booking(RuntimeOrigin::signed(GUEST_A), booking_id),
-			Error::<Test>::NotPlaceOwner
+			Bookings::create_platform_coupon(
+				RuntimeOrigin::signed(OWNER),
+				code,
+				default_coupon_terms()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Bookings::create_platform_coupon(
+			RuntimeOrigin::root(),
+			code,
+			default_coupon_terms()
+		));
+		assert_eq!(Bookings::get_coupon(code).unwrap().issuer, None);
+
+		// Only the platform removes its coupons
+		assert_noop!(
+			Bookings::remove_coupon(RuntimeOrigin::signed(OWNER), code),
+			Error::<Test>::NotCouponIssuer
 		);
+		assert_ok!(Bookings::remove_coupon(RuntimeOrigin::root(), code));
+		assert_eq!(Bookings::get_coupon(code), None);
 	})
 }
 
 #[test]
-fn test_confirm_booking_with_wrong_state_should_fail() {
-	build_with_defult_place_and_booking().execute_with(|| {
-		let booking_id: H256 = Bookings::get_all_bookings()[0];
-		let mut booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
-		booking_data.state = BookingState::Confirmed; // state != Created
-		BookingsData::insert(booking_id, booking_data);
+fn test_create_booking_with_coupon_should_work() {
+	build_with_defult_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let code = coupon_code_hash("SPRING10");
+		assert_ok!(Bookings::create_coupon(
+			RuntimeOrigin::signed(OWNER),
+			code,
+			default_coupon_terms()
+		));
+
+		let start_date = CalendarDate::new(2025, 4, 10);
+		let end_date = CalendarDate::new(2025, 4, 13);
+		let expected_amount = Bookings::quote_booking(
+			&place_id,
+			&start_date,
+			&end_date,
+			Some(&b"SPRING10".to_vec()),
+			None,
+		)
+		.unwrap();
+		assert_eq!(expected_amount, 27);
 
 		assert_noop!(
-			Bookings::confirm_booking(RuntimeOrigin::signed(OWNER), booking_id),
-			Error::<Test>::WrongState
+			book_default_dates_with_coupon(GUEST_A, 30, "SPRING10"),
+			Error::<Test>::AmountMismatch
 		);
-	})
-}
+		assert_ok!(book_default_dates_with_coupon(GUEST_A, expected_amount, "SPRING10"));
 
-#[test]
-fn test_confirm_outdated_booking_should_fail() {
-	build_with_defult_place_and_booking().execute_with(|| {
-		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_id = Bookings::get_all_bookings()[0];
 		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.amount, 27);
+		assert_eq!(Balances::reserved_balance(&GUEST_A), 27);
+		assert_eq!(Bookings::get_coupon(code).unwrap().redemptions, 1);
+		assert_eq!(Bookings::get_coupon_redemptions(code, GUEST_A), 1);
 
-		// Set current chain time to the expected start_date + 1
-		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(booking_data.start_date + 1);
-		// Advance one block to update chain now() time
-		setup_blocks(2);
+		// Check emitted events
+		System::assert_has_event(
+			pallet_bookings::Event::CouponRedeemed { code, id: booking_id, guest: GUEST_A }.into(),
+		);
 
+		// Each account can only redeem the coupon once
 		assert_noop!(
-			Bookings::confirm_booking(RuntimeOrigin::signed(OWNER), booking_id),
-			Error::<Test>::CannotConfirmOutdatedBooking
+			Bookings::create_booking(
+				RuntimeOrigin::signed(GUEST_A),
+				place_id,
+				CalendarDate::new(2025, 5, 10),
+				CalendarDate::new(2025, 5, 13),
+				27,
+				Some(b"SPRING10".to_vec()),
+				false
+			),
+			Error::<Test>::CouponExhausted
 		);
 	})
 }
 
-// ========================================================
-// Reject Bookings Unit Tests
+#[test]
+fn test_create_booking_with_unredeemable_coupon_should_fail() {
+	build_with_defult_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let code = coupon_code_hash("SPRING10");
+		assert_ok!(Bookings::create_coupon(
+			RuntimeOrigin::signed(OWNER),
+			code,
+			default_coupon_terms()
+		));
+
+		assert_noop!(
+			book_default_dates_with_coupon(GUEST_A, 27, "DUMMY"),
+			Error::<Test>::CouponNotFound
+		);
+
+		// The stay is shorter than the minimum number of nights
+		assert_noop!(
+			Bookings::create_booking(
+				RuntimeOrigin::signed(GUEST_A),
+				place_id,
+				CalendarDate::new(2025, 4, 10),
+				CalendarDate::new(2025, 4, 11),
+				9,
+				Some(b"SPRING10".to_vec()),
+				false
+			),
+			Error::<Test>::CouponNotApplicable
+		);
+
+		// Coupons of other hosts cannot be redeemed in the place
+		let other_code = coupon_code_hash("OTHER10");
+		assert_ok!(Bookings::create_coupon(
+			RuntimeOrigin::signed(GUEST_B),
+			other_code,
+			default_coupon_terms()
+		));
+		assert_noop!(
+			book_default_dates_with_coupon(GUEST_A, 27, "OTHER10"),
+			Error::<Test>::CouponNotApplicable
+		);
+
+		// The validity window is over
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(MILLIS_PER_DAY);
+		assert_noop!(
+			book_default_dates_with_coupon(GUEST_A, 27, "SPRING10"),
+			Error::<Test>::CouponNotActive
+		);
+	})
+}
+
+#[test]
+fn test_fixed_discount_is_capped_to_the_amount() {
+	build_with_defult_place().execute_with(|| {
+		let code = coupon_code_hash("FREESTAY");
+		let terms = CouponTerms { discount: Discount::Fixed(50), ..default_coupon_terms() };
+		assert_ok!(Bookings::create_platform_coupon(RuntimeOrigin::root(), code, terms));
+
+		assert_ok!(book_default_dates_with_coupon(GUEST_A, 0, "FREESTAY"));
+		assert_eq!(Balances::reserved_balance(&GUEST_A), 0);
+	})
+}
+
+#[test]
+fn test_rejected_booking_should_restore_the_coupon_redemptions() {
+	build_with_defult_place().execute_with(|| {
+		let code = coupon_code_hash("SPRING10");
+		assert_ok!(Bookings::create_coupon(
+			RuntimeOrigin::signed(OWNER),
+			code,
+			default_coupon_terms()
+		));
+		assert_ok!(book_default_dates_with_coupon(GUEST_A, 27, "SPRING10"));
+		let booking_id = Bookings::get_all_bookings()[0];
+		assert_eq!(Bookings::get_booking_coupon(booking_id), Some((code, 0)));
+
+		assert_ok!(Bookings::reject_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		assert_eq!(Bookings::get_coupon(code).unwrap().redemptions, 0);
+		assert_eq!(Bookings::get_coupon_redemptions(code, GUEST_A), 0);
+		assert_eq!(Bookings::get_booking_coupon(booking_id), None);
+
+		// The guest can redeem the coupon again
+		assert_ok!(Bookings::create_booking(
+			RuntimeOrigin::signed(GUEST_A),
+			Places::get_all_places()[0],
+			CalendarDate::new(2025, 5, 10),
+			CalendarDate::new(2025, 5, 13),
+			27,
+			Some(b"SPRING10".to_vec()),
+			false
+		));
+	})
+}
+
+#[test]
+fn test_platform_coupon_discount_is_funded_by_the_platform() {
+	build_with_defult_place().execute_with(|| {
+		let code = coupon_code_hash("WELCOME");
+		assert_ok!(Bookings::create_platform_coupon(
+			RuntimeOrigin::root(),
+			code,
+			default_coupon_terms()
+		));
+		let _ = Balances::deposit_creating(&PlatformAccount::get(), BASE_TOKEN_AMOUNT);
+		assert_ok!(book_default_dates_with_coupon(GUEST_A, 27, "WELCOME"));
+		let booking_id = Bookings::get_all_bookings()[0];
+		assert_eq!(Bookings::get_booking_coupon(booking_id), Some((code, 3)));
+
+		confirm_default_booking();
+		check_in_default_booking();
+		check_out_default_booking();
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// The host is paid the full price of the stay
+		assert_eq!(Balances::total_balance(&OWNER), BASE_TOKEN_AMOUNT + 30);
+		assert_eq!(Balances::total_balance(&GUEST_A), BASE_TOKEN_AMOUNT - 27);
+		assert_eq!(Balances::total_balance(&PlatformAccount::get()), BASE_TOKEN_AMOUNT - 3);
+		assert_eq!(Bookings::get_booking_coupon(booking_id), None);
+	})
+}
+
+#[test]
+fn test_quote_booking_with_loyalty_points_should_work() {
+	build_with_defult_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let start_date = CalendarDate::new(2025, 4, 10);
+		let end_date = CalendarDate::new(2025, 4, 13);
+		LoyaltyPoints::set(10);
+
+		assert_eq!(
+			Bookings::quote_booking(&place_id, &start_date, &end_date, None, Some(&GUEST_A)),
+			Ok(20)
+		);
+	})
+}
+
+#[test]
+fn test_rejected_booking_should_restore_the_loyalty_points() {
+	build_with_defult_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		LoyaltyPoints::set(10);
+		assert_ok!(Bookings::create_booking(
+			RuntimeOrigin::signed(GUEST_A),
+			place_id,
+			CalendarDate::new(2025, 4, 10),
+			CalendarDate::new(2025, 4, 13),
+			20,
+			None,
+			true
+		));
+		let booking_id = Bookings::get_all_bookings()[0];
+		assert_eq!(LoyaltyPoints::get(), 0);
+		assert_eq!(Bookings::get_redeemed_points(booking_id), Some(10));
+
+		assert_ok!(Bookings::reject_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		assert_eq!(LoyaltyPoints::get(), 10);
+		assert_eq!(Bookings::get_redeemed_points(booking_id), None);
+	})
+}
+
+// ========================================================
+// Payment Schedule Unit Tests
+// ========================================================
+#[test]
+fn test_create_booking_with_payment_schedule_should_work() {
+	build_with_defult_place().execute_with(|| {
+		set_default_payment_schedule();
+		create_default_booking();
+
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		// Only the deposit has been reserved
+		assert_eq!(booking_data.amount, 30);
+		assert_eq!(booking_data.paid, 9);
+		assert_eq!(
+			booking_data.balance_due_date,
+			Some(booking_data.start_date - 14 * MILLIS_PER_DAY)
+		);
+		assert_eq!(Balances::reserved_balance(&GUEST_A), 9);
+		assert_eq!(Bookings::get_pending_balance(booking_id), booking_data.balance_due_date);
+	})
+}
+
+#[test]
+fn test_collect_pending_balance_should_work() {
+	build_with_defult_place().execute_with(|| {
+		set_default_payment_schedule();
+		create_default_booking();
+		confirm_default_booking();
+
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		// Nothing is collected before the due date
+		Bookings::on_initialize(2);
+		assert_eq!(Balances::reserved_balance(&GUEST_A), 9);
+
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+			booking_data.balance_due_date.unwrap(),
+		);
+		Bookings::on_initialize(3);
+
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.paid, booking_data.amount);
+		assert_eq!(booking_data.balance_due_date, None);
+		assert_eq!(booking_data.state, BookingState::Confirmed);
+		assert_eq!(Balances::reserved_balance(&GUEST_A), 30);
+		assert_eq!(Bookings::get_pending_balance(booking_id), None);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::BalanceCollected { id: booking_id, guest: GUEST_A, amount: 21 }
+				.into(),
+		);
+	})
+}
+
+#[test]
+fn test_missed_balance_payment_should_cancel_booking() {
+	build_with_defult_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		set_default_payment_schedule();
+		create_default_booking();
+
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		// Leave GUEST_A without enough free balance to pay the remaining 21
+		assert_ok!(Balances::reserve(&GUEST_A, Balances::free_balance(&GUEST_A) - 10));
+		let reserved_by_others = Balances::reserved_balance(&GUEST_A) - booking_data.paid;
+
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+			booking_data.balance_due_date.unwrap(),
+		);
+		Bookings::on_initialize(2);
+
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::Rejected);
+		// The deposit is returned to the guest
+		assert_eq!(Balances::reserved_balance(&GUEST_A), reserved_by_others);
+		assert_eq!(
+			Bookings::get_pending_booking_withdraws_by_account(GUEST_A),
+			vec![(booking_id, booking_data.paid)]
+		);
+		assert_eq!(Bookings::get_pending_booking_withdraws_by_account(OWNER), vec![]);
+		assert_eq!(Bookings::get_place_bookings(place_id), vec![]);
+		assert_eq!(Bookings::get_pending_balance(booking_id), None);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::BalancePaymentMissed { id: booking_id, guest: GUEST_A }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_collect_pending_balances_should_be_bounded() {
+	build_with_defult_place().execute_with(|| {
+		let place_id: H256 = Places::get_all_places()[0];
+		set_default_payment_schedule();
+		for month in [5, 6, 7] {
+			assert_ok!(Bookings::create_booking(
+				RuntimeOrigin::signed(GUEST_B),
+				place_id,
+				CalendarDate::new(2025, month, 10),
+				CalendarDate::new(2025, month, 13),
+				30,
+				None,
+				false
+			));
+		}
+		let bookings_ids = Bookings::get_all_bookings();
+		let last_booking = Bookings::get_booking_by_id(bookings_ids[2]).unwrap();
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+			last_booking.balance_due_date.unwrap(),
+		);
+
+		// Only `MaxScheduledChecks` balances are collected per block
+		Bookings::on_initialize(2);
+		assert_eq!(Balances::reserved_balance(&GUEST_B), 30 + 30 + 9);
+		assert!(Bookings::get_pending_balances_cursor().is_some());
+
+		Bookings::on_initialize(3);
+		assert_eq!(Balances::reserved_balance(&GUEST_B), 90);
+		assert!(bookings_ids.iter().all(|id| Bookings::get_pending_balance(id).is_none()));
+		assert_eq!(Bookings::get_pending_balances_cursor(), None);
+	})
+}
+
+#[test]
+fn test_checkin_with_pending_balance_should_fail() {
+	build_with_defult_place().execute_with(|| {
+		set_default_payment_schedule();
+		create_default_booking();
+		confirm_default_booking();
+
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(booking_data.start_date + 1);
+		assert_noop!(
+			Bookings::checkin(RuntimeOrigin::signed(GUEST_A), booking_id),
+			Error::<Test>::BalanceNotPaid
+		);
+	})
+}
+
+// ========================================================
+// Confirm Bookings Unit Tests
+// ========================================================
+#[test]
+fn test_confirm_booking_should_work() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_ok!(Bookings::confirm_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		let booking_data = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::Confirmed);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::BookingUpdated {
+				id: booking_id,
+				sender: OWNER,
+				state: BookingState::Confirmed,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn test_confirm_booking_as_cohost_should_work() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_ok!(Places::assign_place_role(
+			RuntimeOrigin::signed(OWNER),
+			place_id,
+			MANAGER,
+			PlaceRole::CoHost,
+			None,
+			None,
+		));
+
+		assert_ok!(Bookings::confirm_booking(RuntimeOrigin::signed(MANAGER), booking_id));
+
+		let booking_data = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::Confirmed);
+	})
+}
+
+#[test]
+fn test_confirm_booking_as_viewer_should_fail() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_ok!(Places::assign_place_role(
+			RuntimeOrigin::signed(OWNER),
+			place_id,
+			MANAGER,
+			PlaceRole::Viewer,
+			None,
+			None,
+		));
+
+		assert_noop!(
+			Bookings::confirm_booking(RuntimeOrigin::signed(MANAGER), booking_id),
+			Error::<Test>::NotPlaceOwner
+		);
+	})
+}
+
+#[test]
+fn test_confirm_missing_booking_should_fail() {
+	build_with_funded_accounts().execute_with(|| {
+		let booking_id: H256 = create_hash("dummy");
+
+		assert_noop!(
+			Bookings::confirm_booking(RuntimeOrigin::signed(GUEST_A), booking_id),
+			Error::<Test>::BookingNotFound
+		);
+	})
+}
+
+#[test]
+fn test_confirm_booking_not_owner_should_fail() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_noop!(
+			Bookings::confirm_booking(RuntimeOrigin::signed(GUEST_A), booking_id),
+			Error::<Test>::NotPlaceOwner
+		);
+	})
+}
+
+#[test]
+fn test_confirm_booking_with_wrong_state_should_fail() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let mut booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		booking_data.state = BookingState::Confirmed; // state != Created
+		BookingsData::insert(booking_id, booking_data);
+
+		assert_noop!(
+			Bookings::confirm_booking(RuntimeOrigin::signed(OWNER), booking_id),
+			Error::<Test>::WrongState
+		);
+	})
+}
+
+#[test]
+fn test_confirm_outdated_booking_should_fail() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		// Set current chain time to the expected start_date + 1
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(booking_data.start_date + 1);
+		// Advance one block to update chain now() time
+		setup_blocks(2);
+
+		assert_noop!(
+			Bookings::confirm_booking(RuntimeOrigin::signed(OWNER), booking_id),
+			Error::<Test>::CannotConfirmOutdatedBooking
+		);
+	})
+}
+
+// ========================================================
+// Reject Bookings Unit Tests
+// ========================================================
+#[test]
+fn test_reject_booking_should_work() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let bookings_data = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		assert_ok!(Bookings::reject_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		let booking_data = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::Rejected);
+
+		// Ensure structures have been updated correctly
+		assert_eq!(Bookings::get_place_bookings(place_id), vec![]);
+		assert_eq!(Bookings::get_pending_booking_withdraws_by_account(OWNER), vec![]);
+		assert_eq!(
+			Bookings::get_pending_booking_withdraws_by_account(GUEST_A),
+			vec![(booking_id, bookings_data.amount)]
+		);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::BookingUpdated {
+				id: booking_id,
+				sender: OWNER,
+				state: BookingState::Rejected,
+			}
+			.into(),
+		);
+	})
+}
+
+// ========================================================
+// Host Cancellation Unit Tests
+// ========================================================
+#[test]
+fn test_host_cancel_confirmed_booking_should_work() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_ok!(Bookings::host_cancel_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::Rejected);
+
+		// The guest is fully refunded and compensated with 10% of the amount
+		let compensation = 3;
+		assert_eq!(Balances::reserved_balance(&GUEST_A), 0);
+		assert_eq!(Balances::total_balance(&GUEST_A), BASE_TOKEN_AMOUNT + compensation);
+		assert_eq!(Balances::total_balance(&OWNER), BASE_TOKEN_AMOUNT - compensation);
+		assert_eq!(
+			Bookings::get_pending_booking_withdraws_by_account(GUEST_A),
+			vec![(booking_id, booking_data.amount)]
+		);
+		assert_eq!(Bookings::get_pending_booking_withdraws_by_account(OWNER), vec![]);
+		assert_eq!(Bookings::get_place_bookings(place_id), vec![]);
+
+		// The cancellation is recorded for the host
+		assert_eq!(Bookings::get_host_cancellations(OWNER), 1);
+
+		// Check emitted events
+		System::assert_has_event(
+			pallet_bookings::Event::GuestCompensated {
+				id: booking_id,
+				guest: GUEST_A,
+				amount: compensation,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			pallet_bookings::Event::BookingCanceled { id: booking_id, sender: OWNER }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_remove_place_with_active_bookings_should_fail() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		// The bond cannot be released before the host cancels the stay
+		assert_noop!(
+			Places::remove_place(RuntimeOrigin::signed(OWNER), place_id),
+			PlaceError::<Test>::PlaceHasActiveBookings
+		);
+
+		assert_ok!(Bookings::host_cancel_booking(RuntimeOrigin::signed(OWNER), booking_id));
+		assert_ok!(Places::remove_place(RuntimeOrigin::signed(OWNER), place_id));
+		assert_eq!(Places::get_place_by_id(place_id), None);
+	})
+}
+
+#[test]
+fn test_host_cancel_unconfirmed_booking_should_fail() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_noop!(
+			Bookings::host_cancel_booking(RuntimeOrigin::signed(OWNER), booking_id),
+			Error::<Test>::WrongState
+		);
+	})
+}
+
+#[test]
+fn test_host_cancel_booking_not_owner_should_fail() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_noop!(
+			Bookings::host_cancel_booking(RuntimeOrigin::signed(GUEST_B), booking_id),
+			Error::<Test>::NotPlaceOwner
+		);
+		assert_eq!(Bookings::get_host_cancellations(OWNER), 0);
+	})
+}
+
+// ========================================================
+// Waitlist Unit Tests
+// ========================================================
+fn join_default_waitlist(guest: u64, reserve_funds: bool) -> DispatchResult {
+	let place_id: H256 = Places::get_all_places()[0];
+	Bookings::join_waitlist(
+		RuntimeOrigin::signed(guest),
+		place_id,
+		CalendarDate::new(2025, 4, 10),
+		CalendarDate::new(2025, 4, 13),
+		reserve_funds,
+	)
+}
+
+#[test]
+fn test_join_waitlist_should_work() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_ok!(join_default_waitlist(GUEST_B, true));
+
+		let waitlist = Bookings::get_place_waitlist(place_id);
+		assert_eq!(waitlist.len(), 1);
+		assert_eq!(waitlist[0].guest, GUEST_B);
+		assert_eq!(waitlist[0].reserved, 30);
+		assert_eq!(waitlist[0].claim_until, None);
+		assert_eq!(Balances::reserved_balance(&GUEST_B), 30);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::WaitlistJoined { place_id, guest: GUEST_B, reserved: 30 }
+				.into(),
+		);
+
+		assert_noop!(join_default_waitlist(GUEST_B, false), Error::<Test>::AlreadyInWaitlist);
+	})
+}
+
+#[test]
+fn test_join_waitlist_with_available_dates_should_fail() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		// Booking requests do not block the dates until they are confirmed
+		assert_noop!(join_default_waitlist(GUEST_B, false), Error::<Test>::BookingDatesAvailable);
+	})
+}
+
+#[test]
+fn test_join_full_waitlist_should_fail() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		assert_ok!(join_default_waitlist(GUEST_B, false));
+		assert_ok!(join_default_waitlist(MANAGER, false));
+
+		assert_noop!(join_default_waitlist(GUEST_A, false), Error::<Test>::WaitlistFull);
+	})
+}
+
+#[test]
+fn test_leave_waitlist_should_work() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		assert_ok!(join_default_waitlist(GUEST_B, true));
+
+		assert_ok!(Bookings::leave_waitlist(RuntimeOrigin::signed(GUEST_B), place_id));
+		assert_eq!(Bookings::get_place_waitlist(place_id), vec![]);
+		assert_eq!(Balances::reserved_balance(&GUEST_B), 0);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::WaitlistLeft { place_id, guest: GUEST_B }.into(),
+		);
+
+		assert_noop!(
+			Bookings::leave_waitlist(RuntimeOrigin::signed(GUEST_B), place_id),
+			Error::<Test>::NotInWaitlist
+		);
+	})
+}
+
+#[test]
+fn test_waitlist_with_reserved_funds_is_promoted() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		assert_ok!(join_default_waitlist(GUEST_B, true));
+
+		assert_ok!(Bookings::host_cancel_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// A booking request is created with the funds reserved
+		let promoted_booking_id: H256 = Bookings::get_all_bookings()[1];
+		let booking_data: BookingData<Test> =
+			Bookings::get_booking_by_id(promoted_booking_id).unwrap();
+		assert_eq!(booking_data.guest, GUEST_B);
+		assert_eq!(booking_data.state, BookingState::Created);
+		assert_eq!(Bookings::get_place_bookings(place_id), vec![promoted_booking_id]);
+		assert_eq!(Bookings::get_place_waitlist(place_id), vec![]);
+		assert_eq!(Balances::reserved_balance(&GUEST_B), 30);
+
+		// Check emitted events
+		System::assert_has_event(
+			pallet_bookings::Event::WaitlistPromoted {
+				place_id,
+				guest: GUEST_B,
+				booking_id: promoted_booking_id,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn test_waitlist_without_funds_is_notified() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		assert_ok!(join_default_waitlist(GUEST_B, false));
+
+		assert_ok!(Bookings::host_cancel_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// The dates are held for the guest of the waitlist
+		let waitlist = Bookings::get_place_waitlist(place_id);
+		assert_eq!(waitlist[0].claim_until, Some(MILLIS_PER_DAY));
+		assert!(Bookings::has_waitlist_claims(place_id));
+		System::assert_has_event(
+			pallet_bookings::Event::WaitlistSpotAvailable {
+				place_id,
+				guest: GUEST_B,
+				claim_until: MILLIS_PER_DAY,
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			Bookings::create_booking(
+				RuntimeOrigin::signed(MANAGER),
+				place_id,
+				CalendarDate::new(2025, 4, 10),
+				CalendarDate::new(2025, 4, 13),
+				30,
+				None,
+				false,
+			),
+			Error::<Test>::BookingDatesNotAvailable
+		);
+		assert_noop!(
+			Bookings::create_booking(
+				RuntimeOrigin::signed(MANAGER),
+				place_id,
+				CalendarDate::new(2025, 4, 11),
+				CalendarDate::new(2025, 4, 14),
+				30,
+				None,
+				false,
+			),
+			Error::<Test>::BookingDatesNotAvailable
+		);
+
+		// The notified guest claims the dates
+		assert_ok!(Bookings::create_booking(
+			RuntimeOrigin::signed(GUEST_B),
+			place_id,
+			CalendarDate::new(2025, 4, 10),
+			CalendarDate::new(2025, 4, 13),
+			30,
+			None,
+			false,
+		));
+		assert_eq!(Bookings::get_place_waitlist(place_id), vec![]);
+	})
+}
+
+#[test]
+fn test_waitlist_claim_expiration_should_work() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		assert_ok!(join_default_waitlist(GUEST_B, false));
+		assert_ok!(Bookings::host_cancel_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(MILLIS_PER_DAY);
+		Bookings::expire_waitlist_claims();
+
+		assert_eq!(Bookings::get_place_waitlist(place_id), vec![]);
+		assert!(!Bookings::has_waitlist_claims(place_id));
+		System::assert_last_event(
+			pallet_bookings::Event::WaitlistClaimExpired { place_id, guest: GUEST_B }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_booking_other_dates_should_keep_the_waitlist_entry() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		assert_ok!(join_default_waitlist(GUEST_B, true));
+
+		assert_ok!(Bookings::create_booking(
+			RuntimeOrigin::signed(GUEST_B),
+			place_id,
+			CalendarDate::new(2025, 5, 10),
+			CalendarDate::new(2025, 5, 13),
+			30,
+			None,
+			false,
+		));
+
+		// The guest keeps waiting for the dates of the waitlist
+		let waitlist = Bookings::get_place_waitlist(place_id);
+		assert_eq!(waitlist.len(), 1);
+		assert_eq!(waitlist[0].start_date, CalendarDate::new(2025, 4, 10));
+		assert_eq!(Balances::reserved_balance(&GUEST_B), 60);
+	})
+}
+
+#[test]
+fn test_waitlist_claim_expiration_should_be_bounded() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let other_places = [create_hash("Place B"), create_hash("Place C")];
+		for other_place_id in other_places {
+			WaitlistClaims::<Test>::insert(other_place_id, true);
+		}
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		assert_ok!(join_default_waitlist(GUEST_B, false));
+		assert_ok!(Bookings::host_cancel_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// Only two places are checked per block, the rest are checked on the next one
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(MILLIS_PER_DAY);
+		Bookings::expire_waitlist_claims();
+		assert!(Bookings::get_waitlist_claims_cursor().is_some());
+		Bookings::expire_waitlist_claims();
+		assert_eq!(Bookings::get_waitlist_claims_cursor(), None);
+
+		assert!(!Bookings::has_waitlist_claims(place_id));
+		assert!(other_places
+			.iter()
+			.all(|other_place_id| !Bookings::has_waitlist_claims(other_place_id)));
+		assert_eq!(Bookings::get_place_waitlist(place_id), vec![]);
+	})
+}
+
+// ========================================================
+// Itinerary Unit Tests
+// ========================================================
+fn create_second_place() -> H256 {
+	let _ = Places::create_place(
+		RuntimeOrigin::signed(GUEST_B),
+		PlaceType::House,
+		b"Second Place".to_vec(),
+		b"Second City".to_vec(),
+		Coordinates { latitude: 41_385_064, longitude: 2_173_403 },
+		create_hash("Second Description"),
+		10,
+		17,
+		12,
+		0,
+		vec![create_hash("image_3")],
+		None,
+		PlaceAttributes::default(),
+	);
+	Places::get_all_places()[1]
+}
+
+fn default_itinerary_legs() -> Vec<ItineraryLeg<Test>> {
+	let first_place_id: H256 = Places::get_all_places()[0];
+	let second_place_id = create_second_place();
+	vec![
+		ItineraryLeg {
+			place_id: first_place_id,
+			start_date: CalendarDate::new(2025, 4, 10),
+			end_date: CalendarDate::new(2025, 4, 13),
+			amount: 30,
+		},
+		ItineraryLeg {
+			place_id: second_place_id,
+			start_date: CalendarDate::new(2025, 4, 13),
+			end_date: CalendarDate::new(2025, 4, 15),
+			amount: 20,
+		},
+	]
+}
+
+fn build_with_default_itinerary() -> sp_io::TestExternalities {
+	let mut ext = build_with_defult_place();
+	ext.execute_with(|| {
+		let _ =
+			Bookings::create_itinerary(RuntimeOrigin::signed(GUEST_A), default_itinerary_legs());
+	});
+	ext
+}
+
+#[test]
+fn test_create_itinerary_should_work() {
+	build_with_defult_place().execute_with(|| {
+		assert_ok!(Bookings::create_itinerary(
+			RuntimeOrigin::signed(GUEST_A),
+			default_itinerary_legs()
+		));
+
+		let booking_ids = Bookings::get_all_bookings();
+		assert_eq!(booking_ids.len(), 2);
+		let itinerary_id = Bookings::get_booking_itinerary(booking_ids[0]).unwrap();
+		assert_eq!(Bookings::get_booking_itinerary(booking_ids[1]), Some(itinerary_id));
+
+		let itinerary: ItineraryData<Test> = Bookings::get_itinerary_by_id(itinerary_id).unwrap();
+		assert_eq!(itinerary.guest, GUEST_A);
+		assert_eq!(itinerary.bookings, booking_ids);
+		assert_eq!(itinerary.deadline, 2 * MILLIS_PER_DAY);
+		assert_eq!(itinerary.state, ItineraryState::Pending);
+		assert_eq!(Bookings::get_pending_itinerary(itinerary_id), Some(2 * MILLIS_PER_DAY));
+		assert_eq!(Balances::reserved_balance(&GUEST_A), 50);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::ItineraryCreated { id: itinerary_id, sender: GUEST_A }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_create_itinerary_with_wrong_legs_should_fail() {
+	build_with_defult_place().execute_with(|| {
+		assert_noop!(
+			Bookings::create_itinerary(RuntimeOrigin::signed(GUEST_A), vec![]),
+			Error::<Test>::EmptyItinerary
+		);
+
+		let mut legs = default_itinerary_legs();
+		legs[1].start_date = CalendarDate::new(2025, 4, 12);
+		legs[1].amount = 30;
+		assert_noop!(
+			Bookings::create_itinerary(RuntimeOrigin::signed(GUEST_A), legs.clone()),
+			Error::<Test>::OverlappingItineraryLegs
+		);
+
+		// A single leg failing its validation discards the whole itinerary
+		legs[1].start_date = CalendarDate::new(2025, 4, 13);
+		assert_noop!(
+			Bookings::create_itinerary(RuntimeOrigin::signed(GUEST_A), legs),
+			Error::<Test>::AmountMismatch
+		);
+	})
+}
+
+#[test]
+fn test_confirm_every_itinerary_leg_should_work() {
+	build_with_default_itinerary().execute_with(|| {
+		let booking_ids = Bookings::get_all_bookings();
+		let itinerary_id = Bookings::get_booking_itinerary(booking_ids[0]).unwrap();
+
+		assert_ok!(Bookings::confirm_booking(RuntimeOrigin::signed(OWNER), booking_ids[0]));
+		let itinerary: ItineraryData<Test> = Bookings::get_itinerary_by_id(itinerary_id).unwrap();
+		assert_eq!(itinerary.state, ItineraryState::Pending);
+
+		// Each host confirms its own leg
+		assert_noop!(
+			Bookings::confirm_booking(RuntimeOrigin::signed(OWNER), booking_ids[1]),
+			Error::<Test>::NotPlaceOwner
+		);
+		assert_ok!(Bookings::confirm_booking(RuntimeOrigin::signed(GUEST_B), booking_ids[1]));
+
+		let itinerary: ItineraryData<Test> = Bookings::get_itinerary_by_id(itinerary_id).unwrap();
+		assert_eq!(itinerary.state, ItineraryState::Confirmed);
+		assert_eq!(Bookings::get_pending_itinerary(itinerary_id), None);
+		System::assert_has_event(
+			pallet_bookings::Event::ItineraryConfirmed { id: itinerary_id }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_reject_itinerary_leg_should_cancel_every_leg() {
+	build_with_default_itinerary().execute_with(|| {
+		let booking_ids = Bookings::get_all_bookings();
+		let itinerary_id = Bookings::get_booking_itinerary(booking_ids[0]).unwrap();
+
+		assert_ok!(Bookings::confirm_booking(RuntimeOrigin::signed(OWNER), booking_ids[0]));
+		assert_ok!(Bookings::reject_booking(RuntimeOrigin::signed(GUEST_B), booking_ids[1]));
+
+		for booking_id in booking_ids {
+			let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+			assert_eq!(booking_data.state, BookingState::Rejected);
+		}
+		let itinerary: ItineraryData<Test> = Bookings::get_itinerary_by_id(itinerary_id).unwrap();
+		assert_eq!(itinerary.state, ItineraryState::Canceled);
+		assert_eq!(Bookings::get_pending_itinerary(itinerary_id), None);
+		assert_eq!(Balances::reserved_balance(&GUEST_A), 0);
+		System::assert_has_event(
+			pallet_bookings::Event::ItineraryCanceled { id: itinerary_id }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_expired_itinerary_should_cancel_every_leg() {
+	build_with_default_itinerary().execute_with(|| {
+		let booking_ids = Bookings::get_all_bookings();
+		let itinerary_id = Bookings::get_booking_itinerary(booking_ids[0]).unwrap();
+		assert_ok!(Bookings::confirm_booking(RuntimeOrigin::signed(OWNER), booking_ids[0]));
+
+		// Nothing happens before the deadline
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(2 * MILLIS_PER_DAY - 1);
+		Bookings::expire_itineraries();
+		assert!(Bookings::get_pending_itinerary(itinerary_id).is_some());
+
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(2 * MILLIS_PER_DAY);
+		Bookings::expire_itineraries();
+
+		let itinerary: ItineraryData<Test> = Bookings::get_itinerary_by_id(itinerary_id).unwrap();
+		assert_eq!(itinerary.state, ItineraryState::Canceled);
+		assert_eq!(Bookings::get_pending_itinerary(itinerary_id), None);
+		assert_eq!(Balances::reserved_balance(&GUEST_A), 0);
+		for booking_id in booking_ids {
+			let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+			assert_eq!(booking_data.state, BookingState::Rejected);
+		}
+	})
+}
+
+#[test]
+fn test_expire_itineraries_should_be_bounded() {
+	build_with_default_itinerary().execute_with(|| {
+		let booking_ids = Bookings::get_all_bookings();
+		let itinerary_id = Bookings::get_booking_itinerary(booking_ids[0]).unwrap();
+		let stale_itineraries = [create_hash("Itinerary B"), create_hash("Itinerary C")];
+		for stale_itinerary_id in stale_itineraries {
+			PendingItineraries::<Test>::insert(stale_itinerary_id, 2 * MILLIS_PER_DAY);
+		}
+
+		// Only two itineraries are checked per block, the rest are checked on the next one
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(2 * MILLIS_PER_DAY);
+		Bookings::expire_itineraries();
+		assert!(Bookings::get_pending_itineraries_cursor().is_some());
+		Bookings::expire_itineraries();
+		assert_eq!(Bookings::get_pending_itineraries_cursor(), None);
+
+		let itinerary: ItineraryData<Test> = Bookings::get_itinerary_by_id(itinerary_id).unwrap();
+		assert_eq!(itinerary.state, ItineraryState::Canceled);
+		assert_eq!(PendingItineraries::<Test>::iter().count(), 0);
+	})
+}
+
+// ========================================================
+// Checkin Bookings Unit Tests
+// ========================================================
+#[test]
+fn test_checkin_confirmed_booking_should_work() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		// Set current chain time to the expected start_date + 1 ot enable the checkin
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(booking_data.start_date + 1);
+		assert_ok!(Bookings::checkin(RuntimeOrigin::signed(GUEST_A), booking_id));
+
+		// Retrieve latest state
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::CheckedIn);
+
+		// The host cannot collect the payout during the stay
+		assert_noop!(
+			Bookings::withdraw_booking(RuntimeOrigin::signed(OWNER), booking_id),
+			Error::<Test>::WrongState
+		);
+	})
+}
+
+#[test]
+fn test_checkin_booking_with_wrong_guest_should_fail() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		// Set current chain time to the expected start_date + 1 ot enable the checkin
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(booking_data.start_date + 1);
+		assert_noop!(
+			Bookings::checkin(RuntimeOrigin::signed(GUEST_B), booking_id),
+			Error::<Test>::NotPlaceGuest
+		);
+	})
+}
+
+#[test]
+fn test_checkin_booking_earlier_should_fail() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_noop!(
+			Bookings::checkin(RuntimeOrigin::signed(GUEST_A), booking_id),
+			Error::<Test>::CheckinNotAvailableYet
+		);
+	})
+}
+
+// ========================================================
+// Checkout Bookings Unit Tests
+// ========================================================
+#[test]
+fn test_checkout_checked_in_booking_should_work() {
+	build_with_default_checked_in_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_ok!(Bookings::checkout(RuntimeOrigin::signed(GUEST_A), booking_id));
+
+		// Retrieve latest state
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::CheckedOut);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::BookingUpdated {
+				id: booking_id,
+				sender: GUEST_A,
+				state: BookingState::CheckedOut,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn test_checkout_before_end_date_with_wrong_guest_should_fail() {
+	build_with_default_checked_in_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_noop!(
+			Bookings::checkout(RuntimeOrigin::signed(OWNER), booking_id),
+			Error::<Test>::NotPlaceGuest
+		);
+	})
+}
+
+#[test]
+fn test_checkout_after_end_date_should_work() {
+	build_with_default_checked_in_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		// Once the checkout hour has passed, anyone can record the checkout
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(booking_data.end_date);
+		assert_ok!(Bookings::checkout(RuntimeOrigin::signed(GUEST_B), booking_id));
+
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::CheckedOut);
+	})
+}
+
+#[test]
+fn test_checkout_not_checked_in_booking_should_fail() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_noop!(
+			Bookings::checkout(RuntimeOrigin::signed(GUEST_A), booking_id),
+			Error::<Test>::WrongState
+		);
+	})
+}
+
+#[test]
+fn test_acknowledge_checkout_should_work() {
+	build_with_default_checked_in_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_ok!(Bookings::checkout(RuntimeOrigin::signed(GUEST_A), booking_id));
+		assert_ok!(Bookings::acknowledge_checkout(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// Retrieve latest state
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::OwnerCanWithdraw);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::BookingUpdated {
+				id: booking_id,
+				sender: OWNER,
+				state: BookingState::OwnerCanWithdraw,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn test_acknowledge_checkout_during_stay_should_fail() {
+	build_with_default_checked_in_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_noop!(
+			Bookings::acknowledge_checkout(RuntimeOrigin::signed(OWNER), booking_id),
+			Error::<Test>::CheckoutNotAvailableYet
+		);
+	})
+}
+
+#[test]
+fn test_acknowledge_automatic_checkout_should_work() {
+	build_with_default_checked_in_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		// The guest did not check out, but the checkout hour has passed
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(booking_data.end_date);
+		assert_ok!(Bookings::acknowledge_checkout(RuntimeOrigin::signed(OWNER), booking_id));
+
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::OwnerCanWithdraw);
+	})
+}
+
+#[test]
+fn test_acknowledge_checkout_not_owner_should_fail() {
+	build_with_default_checked_in_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_ok!(Bookings::checkout(RuntimeOrigin::signed(GUEST_A), booking_id));
+		assert_noop!(
+			Bookings::acknowledge_checkout(RuntimeOrigin::signed(GUEST_A), booking_id),
+			Error::<Test>::NotPlaceOwner
+		);
+	})
+}
+
+#[test]
+fn test_loyalty_points_discount_is_funded_by_the_platform() {
+	build_with_defult_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let _ = Balances::deposit_creating(&PlatformAccount::get(), BASE_TOKEN_AMOUNT);
+		LoyaltyPoints::set(10);
+		assert_ok!(Bookings::create_booking(
+			RuntimeOrigin::signed(GUEST_A),
+			place_id,
+			CalendarDate::new(2025, 4, 10),
+			CalendarDate::new(2025, 4, 13),
+			20,
+			None,
+			true
+		));
+		let booking_id = Bookings::get_all_bookings()[0];
+
+		confirm_default_booking();
+		check_in_default_booking();
+		check_out_default_booking();
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// The host is paid the full price of the stay
+		assert_eq!(Balances::total_balance(&OWNER), BASE_TOKEN_AMOUNT + 30);
+		assert_eq!(Balances::total_balance(&GUEST_A), BASE_TOKEN_AMOUNT - 20);
+		assert_eq!(Balances::total_balance(&PlatformAccount::get()), BASE_TOKEN_AMOUNT - 10);
+		assert_eq!(Bookings::get_redeemed_points(booking_id), None);
+	})
+}
+
+// ========================================================
+// No-Show Unit Tests
 // ========================================================
 #[test]
-fn test_reject_booking_should_work() {
+fn test_mark_no_show_should_work() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		// The host keeps half of the amount after a grace period of 2 hours
+		assert_ok!(Places::set_no_show_policy(
+			RuntimeOrigin::signed(OWNER),
+			place_id,
+			Some(NoShowPolicy { host_share: Perbill::from_percent(50), grace_period_hours: 2 }),
+		));
+
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+			booking_data.start_date + 2 * MILLIS_PER_HOUR,
+		);
+		assert_ok!(Bookings::mark_no_show(RuntimeOrigin::signed(OWNER), booking_id));
+
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::NoShow);
+		assert_eq!(Bookings::get_place_bookings(place_id), vec![]);
+		assert_eq!(
+			Bookings::get_pending_booking_withdraws_by_account(OWNER),
+			vec![(booking_id, 15)]
+		);
+		assert_eq!(
+			Bookings::get_pending_booking_withdraws_by_account(GUEST_A),
+			vec![(booking_id, 15)]
+		);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::NoShowMarked {
+				id: booking_id,
+				sender: OWNER,
+				host_amount: 15,
+				guest_amount: 15,
+			}
+			.into(),
+		);
+
+		// Each party withdraws its part
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(GUEST_A), booking_id));
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::NoShow);
+		assert_noop!(
+			Bookings::withdraw_booking(RuntimeOrigin::signed(GUEST_A), booking_id),
+			Error::<Test>::NothingToWithdraw
+		);
+
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(OWNER), booking_id));
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::Completed);
+
+		assert_eq!(Balances::reserved_balance(&GUEST_A), 0);
+		assert_eq!(Balances::total_balance(&GUEST_A), BASE_TOKEN_AMOUNT - 15);
+		assert_eq!(Balances::total_balance(&OWNER), BASE_TOKEN_AMOUNT + 15);
+	})
+}
+
+#[test]
+fn test_mark_no_show_should_settle_the_platform_coupon() {
+	build_with_defult_place().execute_with(|| {
+		let code = coupon_code_hash("WELCOME");
+		assert_ok!(Bookings::create_platform_coupon(
+			RuntimeOrigin::root(),
+			code,
+			default_coupon_terms()
+		));
+		let _ = Balances::deposit_creating(&PlatformAccount::get(), BASE_TOKEN_AMOUNT);
+		assert_ok!(book_default_dates_with_coupon(GUEST_A, 27, "WELCOME"));
+		let booking_id = Bookings::get_all_bookings()[0];
+		confirm_default_booking();
+
+		// The default policy keeps the full amount for the host after 24 hours
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+			booking_data.start_date + 24 * MILLIS_PER_HOUR,
+		);
+		assert_ok!(Bookings::mark_no_show(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// The coupon stays redeemed and its discount is paid by the platform
+		assert_eq!(Bookings::get_booking_coupon(booking_id), None);
+		assert_eq!(Bookings::get_coupon(code).unwrap().redemptions, 1);
+		assert_eq!(Balances::total_balance(&PlatformAccount::get()), BASE_TOKEN_AMOUNT - 3);
+
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(OWNER), booking_id));
+		assert_eq!(Balances::total_balance(&OWNER), BASE_TOKEN_AMOUNT + 30);
+		assert_eq!(Balances::total_balance(&GUEST_A), BASE_TOKEN_AMOUNT - 27);
+	})
+}
+
+#[test]
+fn test_mark_no_show_before_grace_period_should_fail() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		// The default policy has a grace period of 24 hours
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+			booking_data.start_date + 23 * MILLIS_PER_HOUR,
+		);
+		assert_noop!(
+			Bookings::mark_no_show(RuntimeOrigin::signed(OWNER), booking_id),
+			Error::<Test>::NoShowNotAvailableYet
+		);
+	})
+}
+
+#[test]
+fn test_mark_no_show_checked_in_booking_should_fail() {
+	build_with_default_checked_in_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+			booking_data.start_date + 24 * MILLIS_PER_HOUR,
+		);
+		assert_noop!(
+			Bookings::mark_no_show(RuntimeOrigin::signed(OWNER), booking_id),
+			Error::<Test>::WrongState
+		);
+	})
+}
+
+// ========================================================
+// Withdrawals Unit Tests
+// ========================================================
+#[test]
+fn test_withdraw_checked_out_booking_should_work() {
+	// Caller: OWNER
+	build_with_default_checked_in_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		check_out_default_booking();
+
+		// As the owner, withdraw the booking
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// Retrieve latest state
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::Completed);
+
+		// Check OWNER has successfully withdrawed the funds
+		assert_eq!(Balances::total_balance(&OWNER), BASE_TOKEN_AMOUNT + booking_data.amount);
+		// Check GUEST_A has less funds
+		assert_eq!(Balances::total_balance(&GUEST_A), BASE_TOKEN_AMOUNT - booking_data.amount);
+
+		// Check structs have been updated correctly
+		assert_eq!(Bookings::get_place_bookings(booking_id), vec![]);
+		assert_eq!(Bookings::get_pending_booking_withdraws_by_account(OWNER), vec![]);
+		assert_eq!(Bookings::get_place_bookings(place_id), vec![]);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::BookingUpdated {
+				id: booking_id,
+				sender: OWNER,
+				state: BookingState::Completed,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn test_withdraw_rejected_booking_should_keep_other_reservations() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		// GUEST_B waits for the dates of GUEST_A, and holds a bond as host of its own places
+		assert_ok!(join_default_waitlist(GUEST_B, true));
+		assert_ok!(Balances::reserve(&GUEST_B, 20));
+		assert_ok!(Bookings::create_booking(
+			RuntimeOrigin::signed(GUEST_B),
+			place_id,
+			CalendarDate::new(2025, 5, 10),
+			CalendarDate::new(2025, 5, 13),
+			30,
+			None,
+			false
+		));
+		let booking_id: H256 = Bookings::get_all_bookings()[1];
+		assert_ok!(Bookings::reject_booking(RuntimeOrigin::signed(OWNER), booking_id));
+		assert_eq!(Balances::reserved_balance(&GUEST_B), 50);
+
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(GUEST_B), booking_id));
+
+		// Only the funds of the booking were released
+		assert_eq!(Balances::reserved_balance(&GUEST_B), 50);
+		assert_eq!(Bookings::get_place_waitlist(place_id)[0].reserved, 30);
+		assert_eq!(Bookings::get_pending_booking_withdraws_by_account(GUEST_B), vec![]);
+	})
+}
+
+#[test]
+fn test_withdraw_rejected_booking_should_work() {
+	// Caller: GUEST_A
 	build_with_defult_place_and_booking().execute_with(|| {
 		let place_id = Places::get_all_places()[0];
 		let booking_id: H256 = Bookings::get_all_bookings()[0];
-		let bookings_data = Bookings::get_booking_by_id(booking_id).unwrap();
 
-		assert_ok!(Bookings::reject_booking(RuntimeOrigin::signed(OWNER), booking_id));
+		// Reject booking
+		assert_ok!(Bookings::reject_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// As the GUEST_A, withdraw the rejected booking
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(GUEST_A), booking_id));
+
+		// Retrieve latest state
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::Completed);
+
+		// Check GUEST_A has successfully unreserved his funds
+		assert_eq!(Balances::reserved_balance(&GUEST_A), 0);
+
+		// Check structs have been updated correctly
+		assert_eq!(Bookings::get_place_bookings(booking_id), vec![]);
+		assert_eq!(Bookings::get_pending_booking_withdraws_by_account(OWNER), vec![]);
+		assert_eq!(Bookings::get_pending_booking_withdraws_by_account(GUEST_A), vec![]);
+		assert_eq!(Bookings::get_place_bookings(place_id), vec![]);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::BookingUpdated {
+				id: booking_id,
+				sender: GUEST_A,
+				state: BookingState::Completed,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn test_withdraw_booking_with_manager_payout_share_should_work() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		// The manager gets 20% of every payout
+		assert_ok!(Places::assign_place_role(
+			RuntimeOrigin::signed(OWNER),
+			place_id,
+			MANAGER,
+			PlaceRole::CoHost,
+			None,
+			Some(Perbill::from_percent(20)),
+		));
 
-		let booking_data = Bookings::get_booking_by_id(booking_id).unwrap();
-		assert_eq!(booking_data.state, BookingState::Rejected);
+		check_in_default_booking();
+		check_out_default_booking();
 
-		// Ensure structures have been updated correctly
-		assert_eq!(Bookings::get_place_bookings(place_id), vec![]);
-		assert_eq!(Bookings::get_pending_booking_withdraws_by_account(OWNER), vec![]);
+		// A co-host cannot withdraw the payout by default
+		assert_noop!(
+			Bookings::withdraw_booking(RuntimeOrigin::signed(MANAGER), booking_id),
+			Error::<Test>::NotPlaceOwner
+		);
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		let manager_amount = Perbill::from_percent(20) * booking_data.amount;
+		assert_eq!(Balances::total_balance(&MANAGER), manager_amount);
 		assert_eq!(
-			Bookings::get_pending_booking_withdraws_by_account(GUEST_A),
-			vec![(booking_id, bookings_data.amount)]
+			Balances::total_balance(&OWNER),
+			BASE_TOKEN_AMOUNT + booking_data.amount - manager_amount
 		);
+		assert_eq!(Balances::total_balance(&GUEST_A), BASE_TOKEN_AMOUNT - booking_data.amount);
+	})
+}
+
+#[test]
+fn test_withdraw_booking_with_payout_split_should_work() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+
+		assert_ok!(Places::set_payout_split(
+			RuntimeOrigin::signed(OWNER),
+			place_id,
+			vec![
+				(GUEST_B, Perbill::from_percent(50)),
+				(MANAGER, Perbill::from_percent(25)),
+				(OWNER, Perbill::from_percent(25)),
+			],
+		));
+
+		check_in_default_booking();
+		check_out_default_booking();
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// 30 split in 15 + 7 + 7, the rounding dust goes to the first beneficiary
+		assert_eq!(booking_data.amount, 30);
+		assert_eq!(Balances::total_balance(&GUEST_B), BASE_TOKEN_AMOUNT + 16);
+		assert_eq!(Balances::total_balance(&MANAGER), 7);
+		assert_eq!(Balances::total_balance(&OWNER), BASE_TOKEN_AMOUNT + 7);
+		assert_eq!(Balances::total_balance(&GUEST_A), BASE_TOKEN_AMOUNT - booking_data.amount);
+
+		// Check an itemized event has been emitted per recipient
+		for (recipient, amount) in [(GUEST_B, 16), (MANAGER, 7), (OWNER, 7)] {
+			System::assert_has_event(
+				pallet_bookings::Event::PayoutDistributed { id: booking_id, recipient, amount }
+					.into(),
+			);
+		}
+	})
+}
+
+#[test]
+fn test_withdraw_booking_with_referral_reward_should_work() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let _ = Balances::deposit_creating(&PlatformAccount::get(), BASE_TOKEN_AMOUNT);
+		Referrers::set(vec![(GUEST_A, GUEST_B)]);
+
+		check_in_default_booking();
+		check_out_default_booking();
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// The platform pays the referrer of the guest a tenth of the 30 paid, and the host gets
+		// the full amount
+		assert_eq!(Balances::total_balance(&GUEST_B), BASE_TOKEN_AMOUNT + 3);
+		assert_eq!(Balances::total_balance(&PlatformAccount::get()), BASE_TOKEN_AMOUNT - 3);
+		assert_eq!(Balances::total_balance(&OWNER), BASE_TOKEN_AMOUNT + 30);
+		assert_eq!(Balances::total_balance(&GUEST_A), BASE_TOKEN_AMOUNT - 30);
 
 		// Check emitted events
-		System::assert_last_event(
-			pallet_bookings::Event::BookingUpdated {
+		System::assert_has_event(
+			pallet_bookings::Event::ReferralRewardPaid {
 				id: booking_id,
-				sender: OWNER,
-				state: BookingState::Rejected,
+				referrer: GUEST_B,
+				referred: GUEST_A,
+				amount: 3,
 			}
 			.into(),
 		);
 	})
 }
 
+#[test]
+fn test_unpayable_referral_reward_should_not_block_the_payout() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let _ = Balances::deposit_creating(&PlatformAccount::get(), BASE_TOKEN_AMOUNT);
+		// The reward of 3 cannot create the empty account of the referrer
+		let referrer = 9;
+		ExistentialDeposit::set(5);
+		Referrers::set(vec![(GUEST_A, referrer)]);
+
+		check_in_default_booking();
+		check_out_default_booking();
+		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(OWNER), booking_id));
+
+		// The reward is skipped, and the host is paid
+		assert_eq!(Balances::total_balance(&referrer), 0);
+		assert_eq!(Balances::total_balance(&PlatformAccount::get()), BASE_TOKEN_AMOUNT);
+		assert_eq!(Balances::total_balance(&OWNER), BASE_TOKEN_AMOUNT + 30);
+		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking_data.state, BookingState::Completed);
+	})
+}
+
 // ========================================================
-// Checkin Bookings Unit Tests
+// Address Reveal Unit Tests
 // ========================================================
 #[test]
-fn test_checkin_confirmed_booking_should_work() {
+fn test_reveal_address_should_work() {
 	build_with_default_confirmed_booking().execute_with(|| {
 		let booking_id: H256 = Bookings::get_all_bookings()[0];
-		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
+		let encrypted_address = b"encrypted address".to_vec();
 
-		// Set current chain time to the expected start_date + 1 ot enable the checkin
-		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(booking_data.start_date + 1);
-		assert_ok!(Bookings::checkin(RuntimeOrigin::signed(GUEST_A), booking_id));
+		assert_ok!(Bookings::reveal_address(
+			RuntimeOrigin::signed(OWNER),
+			booking_id,
+			encrypted_address.clone()
+		));
 
-		// Retrieve latest state
-		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
-		assert_eq!(booking_data.state, BookingState::OwnerCanWithdraw);
+		assert_eq!(Bookings::get_booking_address(booking_id), Some(encrypted_address));
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_bookings::Event::AddressRevealed { id: booking_id, sender: OWNER }.into(),
+		);
 	})
 }
 
 #[test]
-fn test_checkin_booking_with_wrong_guest_should_fail() {
-	build_with_default_confirmed_booking().execute_with(|| {
+fn test_reveal_address_of_unconfirmed_booking_should_fail() {
+	build_with_defult_place_and_booking().execute_with(|| {
 		let booking_id: H256 = Bookings::get_all_bookings()[0];
-		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
 
-		// Set current chain time to the expected start_date + 1 ot enable the checkin
-		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(booking_data.start_date + 1);
 		assert_noop!(
-			Bookings::checkin(RuntimeOrigin::signed(GUEST_B), booking_id),
-			Error::<Test>::NotPlaceGuest
+			Bookings::reveal_address(
+				RuntimeOrigin::signed(OWNER),
+				booking_id,
+				b"encrypted address".to_vec()
+			),
+			Error::<Test>::WrongState
 		);
 	})
 }
 
 #[test]
-fn test_checkin_booking_earlier_should_fail() {
+fn test_reveal_address_not_owner_should_fail() {
 	build_with_default_confirmed_booking().execute_with(|| {
 		let booking_id: H256 = Bookings::get_all_bookings()[0];
 
 		assert_noop!(
-			Bookings::checkin(RuntimeOrigin::signed(GUEST_A), booking_id),
-			Error::<Test>::CheckinNotAvailableYet
+			Bookings::reveal_address(
+				RuntimeOrigin::signed(GUEST_B),
+				booking_id,
+				b"encrypted address".to_vec()
+			),
+			Error::<Test>::NotPlaceOwner
 		);
 	})
 }
 
-// ========================================================
-// Withdrawals Unit Tests
-// ========================================================
 #[test]
-fn test_withdraw_checked_in_booking_should_work() {
-	// Caller: OWNER
+fn test_reveal_too_long_address_should_fail() {
 	build_with_default_confirmed_booking().execute_with(|| {
-		let place_id = Places::get_all_places()[0];
 		let booking_id: H256 = Bookings::get_all_bookings()[0];
-		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
-
-		// Set current chain time to the expected start_date + 1 ot enable the checkin
-		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(booking_data.start_date + 1);
-		assert_ok!(Bookings::checkin(RuntimeOrigin::signed(GUEST_A), booking_id));
 
-		// As the owner, withdraw the booking
-		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(OWNER), booking_id));
+		assert_noop!(
+			Bookings::reveal_address(RuntimeOrigin::signed(OWNER), booking_id, vec![0; 513]),
+			Error::<Test>::EncryptedAddressTooLong
+		);
+	})
+}
 
-		// Retrieve latest state
-		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
-		assert_eq!(booking_data.state, BookingState::Completed);
+// ========================================================
+// Messaging Unit Tests
+// ========================================================
+#[test]
+fn test_post_message_should_work() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let reference = MessageContent::Reference(create_hash("Check-in instructions"));
+		let encrypted = MessageContent::Encrypted(b"encrypted message".to_vec());
 
-		// Check OWNER has successfully withdrawed the funds
-		assert_eq!(Balances::total_balance(&OWNER), BASE_TOKEN_AMOUNT + booking_data.amount);
-		// Check GUEST_A has less funds
-		assert_eq!(Balances::total_balance(&GUEST_A), BASE_TOKEN_AMOUNT - booking_data.amount);
+		assert_ok!(Bookings::post_message(
+			RuntimeOrigin::signed(GUEST_A),
+			booking_id,
+			reference.clone()
+		));
+		assert_ok!(Bookings::post_message(
+			RuntimeOrigin::signed(OWNER),
+			booking_id,
+			encrypted.clone()
+		));
 
-		// Check structs have been updated correctly
-		assert_eq!(Bookings::get_place_bookings(booking_id), vec![]);
-		assert_eq!(Bookings::get_pending_booking_withdraws_by_account(OWNER), vec![]);
-		assert_eq!(Bookings::get_place_bookings(place_id), vec![]);
+		let messages = Bookings::booking_messages(&booking_id);
+		assert_eq!(
+			messages
+				.into_iter()
+				.map(|message| (message.sender, message.content))
+				.collect::<Vec<_>>(),
+			vec![(GUEST_A, reference), (OWNER, encrypted.clone())]
+		);
+		assert_eq!(Bookings::get_messages_count(booking_id), 2);
+		assert_eq!(Bookings::get_booking_message(booking_id, 1).unwrap().sender, OWNER);
 
 		// Check emitted events
 		System::assert_last_event(
-			pallet_bookings::Event::BookingUpdated {
+			pallet_bookings::Event::MessagePosted {
 				id: booking_id,
 				sender: OWNER,
-				state: BookingState::Completed,
+				index: 1,
+				content: encrypted,
 			}
 			.into(),
 		);
@@ -573,39 +2541,340 @@ fn test_withdraw_checked_in_booking_should_work() {
 }
 
 #[test]
-fn test_withdraw_rejected_booking_should_work() {
-	// Caller: GUEST_A
+fn test_post_message_not_participant_should_fail() {
 	build_with_defult_place_and_booking().execute_with(|| {
-		let place_id = Places::get_all_places()[0];
 		let booking_id: H256 = Bookings::get_all_bookings()[0];
 
-		// Reject booking
+		assert_noop!(
+			Bookings::post_message(
+				RuntimeOrigin::signed(GUEST_B),
+				booking_id,
+				MessageContent::Reference(create_hash("Hello"))
+			),
+			Error::<Test>::NotBookingParticipant
+		);
+		assert_noop!(
+			Bookings::post_message(
+				RuntimeOrigin::signed(GUEST_A),
+				create_hash("Unknown"),
+				MessageContent::Reference(create_hash("Hello"))
+			),
+			Error::<Test>::BookingNotFound
+		);
+	})
+}
+
+#[test]
+fn test_post_message_too_often_should_fail() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let content = MessageContent::Reference(create_hash("Hello"));
+		let now = <pallet_places::pallet_timestamp::Pallet<Test>>::now();
+
+		assert_ok!(Bookings::post_message(
+			RuntimeOrigin::signed(GUEST_A),
+			booking_id,
+			content.clone()
+		));
+		assert_noop!(
+			Bookings::post_message(RuntimeOrigin::signed(GUEST_A), booking_id, content.clone()),
+			Error::<Test>::MessageRateLimited
+		);
+		// The limit applies to each sender
+		assert_ok!(Bookings::post_message(
+			RuntimeOrigin::signed(OWNER),
+			booking_id,
+			content.clone()
+		));
+
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+			now + MessageInterval::get(),
+		);
+		assert_ok!(Bookings::post_message(RuntimeOrigin::signed(GUEST_A), booking_id, content));
+	})
+}
+
+#[test]
+fn test_post_message_over_limits_should_fail() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+
+		assert_noop!(
+			Bookings::post_message(
+				RuntimeOrigin::signed(GUEST_A),
+				booking_id,
+				MessageContent::Encrypted(vec![0; 65])
+			),
+			Error::<Test>::MessageTooLong
+		);
+
+		for _ in 0..MaxMessagesPerBooking::get() {
+			let now = <pallet_places::pallet_timestamp::Pallet<Test>>::now();
+			assert_ok!(Bookings::post_message(
+				RuntimeOrigin::signed(GUEST_A),
+				booking_id,
+				MessageContent::Encrypted(vec![0; 64])
+			));
+			<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+				now + MessageInterval::get(),
+			);
+		}
+		assert_noop!(
+			Bookings::post_message(
+				RuntimeOrigin::signed(OWNER),
+				booking_id,
+				MessageContent::Reference(create_hash("Hello"))
+			),
+			Error::<Test>::TooManyMessages
+		);
+	})
+}
+
+#[test]
+fn test_post_message_weight_should_grow_with_content() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let message_weight = |content: MessageContent<H256>| {
+			pallet_bookings::Call::<Test>::post_message { booking_id, content }
+				.get_dispatch_info()
+				.weight
+		};
+
+		let short = message_weight(MessageContent::Encrypted(vec![0; 1]));
+		let long = message_weight(MessageContent::Encrypted(vec![0; 64]));
+		assert_eq!(
+			long.saturating_sub(short),
+			Weight::from_parts(
+				pallet_bookings::MESSAGE_WEIGHT_PER_BYTE *
+					(vec![0u8; 64].encoded_size() - vec![0u8; 1].encoded_size()) as u64,
+				0
+			)
+		);
+		assert!(
+			message_weight(MessageContent::Reference(create_hash("Hello"))).ref_time() <
+				long.ref_time()
+		);
+	})
+}
+
+#[test]
+fn test_post_message_after_dispute_window_should_fail() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		let content = MessageContent::Reference(create_hash("Hello"));
 		assert_ok!(Bookings::reject_booking(RuntimeOrigin::signed(OWNER), booking_id));
+		let booking = Bookings::get_booking_by_id(booking_id).unwrap();
 
-		// As the GUEST_A, withdraw the rejected booking
-		assert_ok!(Bookings::withdraw_booking(RuntimeOrigin::signed(GUEST_A), booking_id));
+		// The participants of a finished booking can still post during the dispute window
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+			booking.end_date + DisputeWindow::get() - 1,
+		);
+		assert_ok!(Bookings::post_message(
+			RuntimeOrigin::signed(GUEST_A),
+			booking_id,
+			content.clone()
+		));
 
-		// Retrieve latest state
-		let booking_data: BookingData<Test> = Bookings::get_booking_by_id(booking_id).unwrap();
-		assert_eq!(booking_data.state, BookingState::Completed);
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+			booking.end_date + DisputeWindow::get(),
+		);
+		assert_noop!(
+			Bookings::post_message(RuntimeOrigin::signed(OWNER), booking_id, content),
+			Error::<Test>::MessagingClosed
+		);
+	})
+}
 
-		// Check GUEST_A has successfully unreserved his funds
-		assert_eq!(Balances::reserved_balance(&GUEST_A), 0);
+// ========================================================
+// Pruning Unit Tests
+// ========================================================
+fn reject_bookings_with_guest_b(months: Vec<u8>) -> Vec<H256> {
+	let place_id: H256 = Places::get_all_places()[0];
+	for month in months {
+		assert_ok!(Bookings::create_booking(
+			RuntimeOrigin::signed(GUEST_B),
+			place_id,
+			CalendarDate::new(2025, month, 10),
+			CalendarDate::new(2025, month, 13),
+			30,
+			None,
+			false
+		));
+	}
+	for booking_id in Bookings::get_all_bookings() {
+		let _ = Bookings::reject_booking(RuntimeOrigin::signed(OWNER), booking_id);
+	}
+	Bookings::get_all_bookings()
+}
 
-		// Check structs have been updated correctly
-		assert_eq!(Bookings::get_place_bookings(booking_id), vec![]);
-		assert_eq!(Bookings::get_pending_booking_withdraws_by_account(OWNER), vec![]);
+fn set_timestamp_after_retention(booking_id: H256) {
+	let booking = Bookings::get_booking_by_id(booking_id).unwrap();
+	<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+		booking.end_date + BookingRetentionPeriod::get(),
+	);
+}
+
+#[test]
+fn test_prune_finished_booking_should_work() {
+	build_with_defult_place_and_booking().execute_with(|| {
+		let booking_id: H256 = Bookings::get_all_bookings()[0];
+		assert_ok!(Bookings::post_message(
+			RuntimeOrigin::signed(GUEST_A),
+			booking_id,
+			MessageContent::Reference(create_hash("Hello"))
+		));
+		let message = Bookings::get_booking_message(booking_id, 0).unwrap();
+		let message_log = BlakeTwo256::hash_of(&(H256::default(), &message));
+		assert_eq!(Bookings::get_message_log(booking_id), Some(message_log));
+		assert_ok!(Bookings::reject_booking(RuntimeOrigin::signed(OWNER), booking_id));
+		let booking = Bookings::get_booking_by_id(booking_id).unwrap();
+		set_timestamp_after_retention(booking_id);
+
+		Bookings::on_idle(2, Weight::MAX);
+
+		let commitment = BlakeTwo256::hash_of(&(&booking, message_log));
+		assert_eq!(Bookings::get_booking_by_id(booking_id), None);
+		assert_eq!(Bookings::get_all_bookings(), vec![]);
+		assert_eq!(Bookings::get_archived_booking(booking_id), Some(commitment));
+		assert_eq!(Bookings::booking_messages(&booking_id), vec![]);
+		assert_eq!(Bookings::get_messages_count(booking_id), 0);
+		assert_eq!(Bookings::get_message_log(booking_id), None);
+		assert_eq!(Bookings::get_last_message_at(booking_id, GUEST_A), None);
 		assert_eq!(Bookings::get_pending_booking_withdraws_by_account(GUEST_A), vec![]);
-		assert_eq!(Bookings::get_place_bookings(place_id), vec![]);
 
 		// Check emitted events
 		System::assert_last_event(
-			pallet_bookings::Event::BookingUpdated {
+			pallet_bookings::Event::BookingArchived {
 				id: booking_id,
-				sender: GUEST_A,
-				state: BookingState::Completed,
+				booking,
+				message_log,
+				commitment,
 			}
 			.into(),
 		);
 	})
 }
+
+#[test]
+fn test_prune_bookings_before_retention_should_keep_them() {
+	build_with_default_confirmed_booking().execute_with(|| {
+		let confirmed_id: H256 = Bookings::get_all_bookings()[0];
+		let bookings_ids = reject_bookings_with_guest_b(vec![6]);
+		let rejected_id = bookings_ids[1];
+		let booking = Bookings::get_booking_by_id(rejected_id).unwrap();
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(
+			booking.end_date + BookingRetentionPeriod::get() - 1,
+		);
+
+		Bookings::on_idle(2, Weight::MAX);
+
+		assert_eq!(Bookings::get_all_bookings(), bookings_ids);
+		assert_eq!(Bookings::get_archived_booking(rejected_id), None);
+
+		// Active bookings are never archived. The first block only resets the cursor, since the
+		// previous one reached the end of the bookings
+		set_timestamp_after_retention(rejected_id);
+		Bookings::on_idle(3, Weight::MAX);
+		Bookings::on_idle(4, Weight::MAX);
+
+		assert_eq!(Bookings::get_all_bookings(), vec![confirmed_id]);
+		assert!(Bookings::get_booking_by_id(confirmed_id).is_some());
+		assert!(Bookings::get_archived_booking(rejected_id).is_some());
+	})
+}
+
+#[test]
+fn test_prune_finished_bookings_should_be_bounded() {
+	build_with_defult_place().execute_with(|| {
+		let bookings_ids = reject_bookings_with_guest_b(vec![5, 6, 7]);
+		set_timestamp_after_retention(bookings_ids[2]);
+
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		// Nothing is pruned without enough weight
+		assert_eq!(Bookings::on_idle(2, Weight::zero()), Weight::zero());
+		assert_eq!(Bookings::get_all_bookings(), bookings_ids);
+
+		// Nor when the list of ids does not fit, after reading its length
+		let check_weight = db_weight.reads_writes(
+			8 + <Test as pallet_bookings::Config>::MaxItineraryLegs::get() as u64,
+			13 + MaxMessagesPerBooking::get() as u64,
+		);
+		let remaining_weight = db_weight.reads_writes(5, 3).saturating_add(check_weight);
+		assert_eq!(Bookings::on_idle(2, remaining_weight), db_weight.reads(1));
+		assert_eq!(Bookings::get_all_bookings(), bookings_ids);
+
+		// Reading the cursor, the moment, the length of the ids, two bookings and the ids, then
+		// archiving both bookings and rewriting the three ids
+		let consumed_weight = Bookings::on_idle(2, Weight::MAX);
+		let ids_size = 3 * 32;
+		assert_eq!(
+			consumed_weight,
+			db_weight.reads_writes(6 + 2 * 6, 2 + 2 * 12).saturating_add(Weight::from_parts(
+				pallet_bookings::BOOKINGS_IDS_WEIGHT_PER_BYTE * ids_size,
+				ids_size
+			))
+		);
+		let remaining_ids = Bookings::get_all_bookings();
+		assert_eq!(remaining_ids.len(), 1);
+		assert!(Bookings::get_booking_by_id(remaining_ids[0]).is_some());
+		assert!(Bookings::get_pruning_cursor().is_some());
+
+		// The next block resumes from the cursor
+		Bookings::on_idle(3, Weight::MAX);
+		assert_eq!(Bookings::get_all_bookings(), vec![]);
+		assert_eq!(Bookings::get_pruning_cursor(), None);
+		assert!(bookings_ids.iter().all(|id| Bookings::get_archived_booking(id).is_some()));
+	})
+}
+
+// ========================================================
+// Migrations Unit Tests
+// ========================================================
+#[test]
+fn test_booking_states_keep_their_encoding() {
+	assert_eq!(BookingState::Created.encode(), vec![0]);
+	assert_eq!(BookingState::Confirmed.encode(), vec![1]);
+	assert_eq!(BookingState::Rejected.encode(), vec![2]);
+	assert_eq!(BookingState::Withdrawable.encode(), vec![3]);
+	assert_eq!(BookingState::UserCanWithdraw.encode(), vec![4]);
+	assert_eq!(BookingState::OwnerCanWithdraw.encode(), vec![5]);
+	assert_eq!(BookingState::Completed.encode(), vec![6]);
+	assert_eq!(BookingState::CheckedIn.encode(), vec![7]);
+	assert_eq!(BookingState::CheckedOut.encode(), vec![8]);
+	assert_eq!(BookingState::NoShow.encode(), vec![9]);
+}
+
+#[test]
+fn test_migration_to_v1_should_translate_bookings() {
+	build_with_defult_place().execute_with(|| {
+		let booking_id = create_hash("Old Booking");
+		let place_id = Places::get_all_places()[0];
+		let old_booking = pallet_bookings::migrations::v1::OldBookingData::<Test> {
+			place_id,
+			host: OWNER,
+			guest: GUEST_A,
+			start_date: 1_744_300_800_000,
+			end_date: 1_744_545_600_000,
+			amount: 30,
+			state: BookingState::Confirmed,
+		};
+		StorageVersion::new(0).put::<Bookings>();
+		frame_support::storage::unhashed::put(
+			&BookingsData::<Test>::hashed_key_for(booking_id),
+			&old_booking,
+		);
+
+		Bookings::on_runtime_upgrade();
+
+		let booking = Bookings::get_booking_by_id(booking_id).unwrap();
+		assert_eq!(booking.amount, 30);
+		assert_eq!(booking.paid, 30);
+		assert_eq!(booking.balance_due_date, None);
+		assert_eq!(booking.state, BookingState::Confirmed);
+		assert_eq!(Bookings::on_chain_storage_version(), StorageVersion::new(1));
+
+		// The migration only runs once
+		Bookings::on_runtime_upgrade();
+		assert_eq!(Bookings::get_booking_by_id(booking_id), Some(booking));
+	})
+}
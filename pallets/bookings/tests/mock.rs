@@ -1,12 +1,16 @@
 #![cfg(test)]
-use frame_support::{parameter_types, traits::ConstU32};
-use pallet_bookings;
-use pallet_places::pallet_timestamp;
+use frame_support::{parameter_types, traits::ConstU32, weights::constants::RocksDbWeight};
+use pallet_bookings::{
+	self,
+	traits::{BlockedDates, LoyaltyProgram, ReferralProgram},
+	CalendarDate,
+};
+use pallet_places::{pallet_timestamp, traits::Verification, VerificationLevel};
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, Header as _, IdentityLookup},
-	BuildStorage,
+	BuildStorage, DispatchResult, Perbill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -46,7 +50,7 @@ impl frame_system::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
 	type BlockHashCount = BlockHashCount;
-	type DbWeight = ();
+	type DbWeight = RocksDbWeight;
 	type Version = ();
 	type PalletInfo = PalletInfo;
 	type OnNewAccount = ();
@@ -59,7 +63,7 @@ impl frame_system::Config for Test {
 }
 
 parameter_types! {
-	pub const ExistentialDeposit: u64 = 1;
+	pub static ExistentialDeposit: u64 = 1;
 }
 
 impl pallet_balances::Config for Test {
@@ -91,13 +95,144 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+/// Signs the results of the content checks with the keys set by `UintAuthorityId::set_all_keys`.
+pub struct TestAuthId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+	type GenericPublic = UintAuthorityId;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
+}
+
 impl pallet_places::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type MaxPayoutBeneficiaries = ConstU32<10>;
+	type MaxHouseRules = ConstU32<20>;
+	type MaxHouseRuleLength = ConstU32<32>;
+	type ListingBond = ();
+	type Verification = MockVerification;
+	type ActiveBookings = Bookings;
+	type InspectionCurrency = Balances;
+	type InspectorOrigin = frame_system::EnsureRoot<u64>;
+	type InspectionValidity = InspectionValidity;
+	type AuthorityId = TestAuthId;
+	type UnsignedPriority = PlacesUnsignedPriority;
+	type MaxContentChecks = ConstU32<5>;
+}
+
+parameter_types! {
+	pub static VerifiedAccounts: Vec<(u64, VerificationLevel)> = vec![];
+	pub const InspectionValidity: u64 = 365 * 24 * 60 * 60 * 1000;
+	pub const PlacesUnsignedPriority: u64 = 1 << 20;
+}
+
+/// Verification judgements read from `VerifiedAccounts`.
+pub struct MockVerification;
+
+impl Verification<u64> for MockVerification {
+	fn verification_level(account: &u64) -> VerificationLevel {
+		VerifiedAccounts::get()
+			.into_iter()
+			.find(|(verified, _)| verified == account)
+			.map_or(VerificationLevel::Unverified, |(_, level)| level)
+	}
+}
+
+parameter_types! {
+	pub const HostCancellationPenalty: Perbill = Perbill::from_percent(10);
+	pub const WaitlistClaimPeriod: u64 = 24 * 60 * 60 * 1000;
+	pub const ItineraryConfirmationPeriod: u64 = 2 * 24 * 60 * 60 * 1000;
+	pub const MessageInterval: u64 = 60 * 1000;
+	pub const DisputeWindow: u64 = 7 * 24 * 60 * 60 * 1000;
+	pub const BookingRetentionPeriod: u64 = 30 * 24 * 60 * 60 * 1000;
+	pub const MaxMessagesPerBooking: u32 = 3;
+	pub const PlatformAccount: u64 = 4;
+	pub static BlockedPeriods: Vec<(H256, CalendarDate, CalendarDate)> = vec![];
+	pub static LoyaltyPoints: u64 = 0;
+	pub static Referrers: Vec<(u64, u64)> = vec![];
+}
+
+/// Blocked dates read from `BlockedPeriods`.
+pub struct MockBlockedDates;
+
+impl BlockedDates<H256> for MockBlockedDates {
+	fn blocked_periods(place_id: &H256) -> Vec<(CalendarDate, CalendarDate)> {
+		BlockedPeriods::get()
+			.into_iter()
+			.filter(|(blocked, _, _)| blocked == place_id)
+			.map(|(_, start_date, end_date)| (start_date, end_date))
+			.collect()
+	}
+}
+
+/// Loyalty program where every account shares the points of `LoyaltyPoints`, up to half of the
+/// amount of a booking.
+pub struct MockLoyalty;
+
+impl LoyaltyProgram<u64, u64> for MockLoyalty {
+	fn on_booking_completed(_guest: &u64, _amount: u64) {}
+
+	fn redeemable_discount(_account: &u64, amount: u64) -> u64 {
+		LoyaltyPoints::get().min(amount / 2)
+	}
+
+	fn redeem_points(_account: &u64, discount: u64) -> DispatchResult {
+		LoyaltyPoints::set(LoyaltyPoints::get() - discount);
+		Ok(())
+	}
+
+	fn restore_points(_account: &u64, discount: u64) {
+		LoyaltyPoints::set(LoyaltyPoints::get() + discount);
+	}
+}
+
+/// Referral program where the referrers of `Referrers` get a tenth of the amount.
+pub struct MockReferrals;
+
+impl ReferralProgram<u64, u64> for MockReferrals {
+	fn on_booking_completed(account: &u64, amount: u64) -> Option<(u64, u64)> {
+		Referrers::get()
+			.into_iter()
+			.find(|(referred, _)| referred == account)
+			.map(|(_, referrer)| (referrer, amount / 10))
+	}
 }
 
 impl pallet_bookings::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type MaxEncryptedAddressLength = ConstU32<512>;
+	type HostCancellationPenalty = HostCancellationPenalty;
+	type MaxWaitlistLength = ConstU32<2>;
+	type WaitlistClaimPeriod = WaitlistClaimPeriod;
+	type MaxItineraryLegs = ConstU32<3>;
+	type ItineraryConfirmationPeriod = ItineraryConfirmationPeriod;
+	type CouponOrigin = frame_system::EnsureRoot<u64>;
+	type HostReputation = ();
+	type Loyalty = MockLoyalty;
+	type Referrals = MockReferrals;
+	type BlockedDates = MockBlockedDates;
+	type PlatformAccount = PlatformAccount;
+	type MaxMessageLength = ConstU32<64>;
+	type MaxMessagesPerBooking = MaxMessagesPerBooking;
+	type MessageInterval = MessageInterval;
+	type DisputeWindow = DisputeWindow;
+	type BookingRetentionPeriod = BookingRetentionPeriod;
+	type MaxPruningChecks = ConstU32<2>;
+	type MaxScheduledChecks = ConstU32<2>;
 }
 
 pub const BASE_TOKEN_AMOUNT: u64 = 100;
@@ -8,23 +8,6 @@ pub fn create_hash(data: &str) -> H256 {
 	H256::from_slice(&array)
 }
 
-pub fn generate_timestamp(
-	year: i32,
-	month: u32,
-	day: u32,
-	hour: u32,
-	minute: u32,
-	second: u32,
-) -> u64 {
-	NaiveDate::from_ymd_opt(year, month, day)
-		.unwrap()
-		.and_hms_opt(hour, minute, second)
-		.unwrap()
-		.timestamp()
-		.try_into()
-		.unwrap()
-}
-
 pub fn generate_timestamp_millis(
 	year: i32,
 	month: u32,
@@ -0,0 +1,153 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{
+	interface::CalendarInterface, BlockedPeriod, BlockedPeriods, Bytes, CalendarFeeds,
+	CalendarSyncers, Config, Error, Pallet,
+};
+use frame_support::{ensure, sp_runtime::DispatchError, sp_std::prelude::*, traits::Get};
+use pallet_bookings::{traits::BlockedDates, CalendarDate};
+use pallet_places::{Error as PlacesError, Permissions};
+
+impl<T: Config> CalendarInterface<T> for Pallet<T> {
+	fn _set_calendar_feed(
+		place_id: &T::Hash,
+		url: Bytes,
+		sender: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		Self::ensure_can_manage_calendar(place_id, sender)?;
+		ensure!(url.len() <= T::MaxFeedUrlLength::get() as usize, Error::<T>::FeedUrlTooLong);
+		ensure!(
+			url.starts_with(b"https://") || url.starts_with(b"http://"),
+			Error::<T>::InvalidFeedUrl
+		);
+
+		// Make persistance
+		<CalendarFeeds<T>>::insert(place_id, url);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "The calendar of Place with ID ➡ {:?} is synchronized with an iCal feed.", place_id);
+
+		Ok(())
+	}
+
+	fn _remove_calendar_feed(
+		place_id: &T::Hash,
+		sender: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		ensure!(<CalendarFeeds<T>>::contains_key(place_id), Error::<T>::CalendarFeedNotSet);
+		// The feeds of removed places are left behind, so anyone can clean them
+		if pallet_places::Pallet::<T>::get_place_by_id(place_id).is_some() {
+			Self::ensure_can_manage_calendar(place_id, sender)?;
+		}
+
+		// Make persistance
+		<CalendarFeeds<T>>::remove(place_id);
+		<BlockedPeriods<T>>::remove(place_id);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "The iCal feed of Place with ID ➡ {:?} has been removed.", place_id);
+
+		Ok(())
+	}
+
+	fn _sync_blocked_periods(
+		place_id: &T::Hash,
+		periods: Vec<BlockedPeriod<T::Hash>>,
+		sender: &T::AccountId,
+	) -> Result<(Vec<BlockedPeriod<T::Hash>>, Vec<BlockedPeriod<T::Hash>>), DispatchError> {
+		Self::ensure_can_sync_calendar(place_id, sender)?;
+		ensure!(<CalendarFeeds<T>>::contains_key(place_id), Error::<T>::CalendarFeedNotSet);
+		ensure!(
+			periods.len() <= T::MaxBlockedPeriods::get() as usize,
+			Error::<T>::TooManyBlockedPeriods
+		);
+		ensure!(
+			periods.iter().all(|period| period.start_date.is_valid() &&
+				period.end_date.is_valid() &&
+				period.end_date > period.start_date),
+			Error::<T>::InvalidBlockedPeriod
+		);
+
+		let current_periods = Self::get_blocked_periods(place_id);
+		let blocked: Vec<BlockedPeriod<T::Hash>> = periods
+			.iter()
+			.filter(|period| !current_periods.contains(period))
+			.cloned()
+			.collect();
+		let unblocked: Vec<BlockedPeriod<T::Hash>> =
+			current_periods.into_iter().filter(|period| !periods.contains(period)).collect();
+
+		// Make persistance
+		<BlockedPeriods<T>>::insert(place_id, periods);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "The calendar of Place with ID ➡ {:?} has been synchronized.", place_id);
+
+		Ok((blocked, unblocked))
+	}
+
+	fn _add_calendar_syncer(syncer: &T::AccountId) -> Result<(), DispatchError> {
+		<CalendarSyncers<T>>::try_mutate(|syncers| {
+			ensure!(!syncers.contains(syncer), Error::<T>::AlreadyCalendarSyncer);
+			syncers.push(syncer.clone());
+			Ok(())
+		})
+	}
+
+	fn _remove_calendar_syncer(syncer: &T::AccountId) -> Result<(), DispatchError> {
+		<CalendarSyncers<T>>::try_mutate(|syncers| {
+			let index = syncers
+				.iter()
+				.position(|account| account == syncer)
+				.ok_or(Error::<T>::NotCalendarSyncer)?;
+			syncers.swap_remove(index);
+			Ok(())
+		})
+	}
+}
+
+impl<T: Config> BlockedDates<T::Hash> for Pallet<T> {
+	fn blocked_periods(place_id: &T::Hash) -> Vec<(CalendarDate, CalendarDate)> {
+		Self::get_blocked_periods(place_id)
+			.into_iter()
+			.map(|period| (period.start_date, period.end_date))
+			.collect()
+	}
+}
+
+/// Auxiliar functions implementation
+impl<T: Config> Pallet<T> {
+	fn ensure_can_manage_calendar(
+		place_id: &T::Hash,
+		account: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		ensure!(
+			pallet_places::Pallet::<T>::get_place_by_id(place_id).is_some(),
+			PlacesError::<T>::PlaceNotFound
+		);
+		ensure!(
+			pallet_places::Pallet::<T>::has_permission(
+				place_id,
+				account,
+				Permissions::MANAGE_CALENDAR
+			),
+			PlacesError::<T>::MissingPermission
+		);
+		Ok(())
+	}
+
+	/// Check that an account can synchronize the calendar of a place, either because it can
+	/// manage it or because it is a calendar syncer of the platform.
+	pub(crate) fn ensure_can_sync_calendar(
+		place_id: &T::Hash,
+		account: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		if !Self::get_calendar_syncers().contains(account) {
+			return Self::ensure_can_manage_calendar(place_id, account)
+		}
+		ensure!(
+			pallet_places::Pallet::<T>::get_place_by_id(place_id).is_some(),
+			PlacesError::<T>::PlaceNotFound
+		);
+		Ok(())
+	}
+}
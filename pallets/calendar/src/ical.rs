@@ -0,0 +1,131 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use frame_support::sp_std::prelude::*;
+use pallet_bookings::CalendarDate;
+
+/// Event of an iCal feed whose dates are blocked.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct CalendarEvent {
+	/// The UID of the event, unique within the feed.
+	pub uid: Vec<u8>,
+	/// The first day of the event.
+	pub start_date: CalendarDate,
+	/// The day after the last day of the event.
+	pub end_date: CalendarDate,
+}
+
+/// Parse the events of an iCal (RFC 5545) feed.
+///
+/// Only the UID, DTSTART, DTEND and STATUS properties of the VEVENT components are read. The time
+/// of day of the dates is ignored, so an event blocks every day from its start date until the day
+/// before its end date. Events without end date last one day, as all-day events do. Canceled
+/// events and events without UID or with invalid dates are skipped.
+///
+/// # Arguments
+///
+/// * `feed` - The content of the iCal feed.
+///
+/// # Returns
+///
+/// Returns the events of the feed, in the order they appear.
+pub fn parse_events(feed: &[u8]) -> Vec<CalendarEvent> {
+	let mut events = Vec::new();
+	let mut current: Option<EventProperties> = None;
+
+	for line in unfold_lines(feed) {
+		let Some(separator) = line.iter().position(|c| *c == b':') else { continue };
+		let (name, value) = (&line[..separator], &line[separator + 1..]);
+		// Parameters such as `;VALUE=DATE` or `;TZID=...` follow the name
+		let name = name.split(|c| *c == b';').next().unwrap_or_default();
+
+		if name.eq_ignore_ascii_case(b"BEGIN") && value.eq_ignore_ascii_case(b"VEVENT") {
+			current = Some(EventProperties::default());
+		} else if name.eq_ignore_ascii_case(b"END") && value.eq_ignore_ascii_case(b"VEVENT") {
+			if let Some(event) = current.take().and_then(EventProperties::into_event) {
+				events.push(event);
+			}
+		} else if let Some(properties) = current.as_mut() {
+			if name.eq_ignore_ascii_case(b"UID") {
+				properties.uid = Some(value.to_vec());
+			} else if name.eq_ignore_ascii_case(b"DTSTART") {
+				properties.start_date = parse_date(value);
+			} else if name.eq_ignore_ascii_case(b"DTEND") {
+				properties.end_date = parse_date(value);
+			} else if name.eq_ignore_ascii_case(b"STATUS") {
+				properties.canceled = value.eq_ignore_ascii_case(b"CANCELLED");
+			}
+		}
+	}
+
+	events
+}
+
+/// Properties read from a VEVENT component.
+#[derive(Default)]
+struct EventProperties {
+	uid: Option<Vec<u8>>,
+	start_date: Option<CalendarDate>,
+	end_date: Option<CalendarDate>,
+	canceled: bool,
+}
+
+impl EventProperties {
+	fn into_event(self) -> Option<CalendarEvent> {
+		if self.canceled {
+			return None
+		}
+		let uid = self.uid?;
+		let start_date = self.start_date?;
+		let end_date = match self.end_date {
+			Some(end_date) => end_date,
+			None => next_day(&start_date)?,
+		};
+		if end_date <= start_date {
+			return None
+		}
+		Some(CalendarEvent { uid, start_date, end_date })
+	}
+}
+
+/// Split the content of a feed in lines, joining the folded lines, which start with a space or a
+/// tab.
+fn unfold_lines(feed: &[u8]) -> Vec<Vec<u8>> {
+	let mut lines: Vec<Vec<u8>> = Vec::new();
+	for line in feed.split(|c| *c == b'\n') {
+		let line = line.strip_suffix(b"\r").unwrap_or(line);
+		match (line.first(), lines.last_mut()) {
+			(Some(b' ' | b'\t'), Some(previous)) => previous.extend_from_slice(&line[1..]),
+			_ => lines.push(line.to_vec()),
+		}
+	}
+	lines
+}
+
+/// Parse the date of a DATE (`YYYYMMDD`) or DATE-TIME (`YYYYMMDDTHHMMSS`) value.
+fn parse_date(value: &[u8]) -> Option<CalendarDate> {
+	let digits = value.get(..8)?;
+	if !digits.iter().all(u8::is_ascii_digit) {
+		return None
+	}
+	let number = |digits: &[u8]| {
+		digits.iter().fold(0u16, |number, digit| number * 10 + (digit - b'0') as u16)
+	};
+
+	let date = CalendarDate::new(
+		number(&digits[..4]),
+		number(&digits[4..6]) as u8,
+		number(&digits[6..]) as u8,
+	);
+	date.is_valid().then_some(date)
+}
+
+/// Get the day after a date.
+fn next_day(date: &CalendarDate) -> Option<CalendarDate> {
+	let next = if date.day < CalendarDate::days_in_month(date.year, date.month) {
+		CalendarDate::new(date.year, date.month, date.day + 1)
+	} else if date.month < 12 {
+		CalendarDate::new(date.year, date.month + 1, 1)
+	} else {
+		CalendarDate::new(date.year.checked_add(1)?, 1, 1)
+	};
+	Some(next)
+}
@@ -0,0 +1,83 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{BlockedPeriod, Bytes, Config};
+use frame_support::{sp_runtime::DispatchError, sp_std::prelude::*};
+
+/// Interface for Calendar pallet
+pub trait CalendarInterface<T: Config> {
+	/// Store the iCal feed of a place.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the place.
+	/// * `url` - The HTTP or HTTPS URL of the feed.
+	/// * `sender` - The account identifier setting the feed.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. It fails if the
+	/// place does not exist, the sender cannot manage its calendar or the URL is not valid.
+	fn _set_calendar_feed(
+		place_id: &T::Hash,
+		url: Bytes,
+		sender: &T::AccountId,
+	) -> Result<(), DispatchError>;
+
+	/// Remove the iCal feed of a place and its blocked periods.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the place.
+	/// * `sender` - The account identifier removing the feed.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. It fails if the
+	/// place has no feed, or if it still exists and the sender cannot manage its calendar.
+	fn _remove_calendar_feed(
+		place_id: &T::Hash,
+		sender: &T::AccountId,
+	) -> Result<(), DispatchError>;
+
+	/// Replace the blocked periods of a place.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the place.
+	/// * `periods` - The periods blocked by the events of its feed.
+	/// * `sender` - The account identifier synchronizing the calendar.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the periods newly blocked and the periods no longer
+	/// blocked. Otherwise, it contains an error indicating the reason for failure.
+	fn _sync_blocked_periods(
+		place_id: &T::Hash,
+		periods: Vec<BlockedPeriod<T::Hash>>,
+		sender: &T::AccountId,
+	) -> Result<(Vec<BlockedPeriod<T::Hash>>, Vec<BlockedPeriod<T::Hash>>), DispatchError>;
+
+	/// Allow an account of the platform to synchronize the calendar of every place.
+	///
+	/// # Arguments
+	///
+	/// * `syncer` - The account identifier of the new calendar syncer.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. It fails if the
+	/// account is already a calendar syncer.
+	fn _add_calendar_syncer(syncer: &T::AccountId) -> Result<(), DispatchError>;
+
+	/// Remove a calendar syncer.
+	///
+	/// # Arguments
+	///
+	/// * `syncer` - The account identifier of the calendar syncer.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. It fails if the
+	/// account is not a calendar syncer.
+	fn _remove_calendar_syncer(syncer: &T::AccountId) -> Result<(), DispatchError>;
+}
@@ -0,0 +1,316 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub mod functions;
+pub mod ical;
+pub mod interface;
+pub mod offchain;
+pub mod structures;
+
+use sp_core::crypto::KeyTypeId;
+
+/// Key type of the accounts used by the off-chain worker to sign the calendar synchronizations.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"cldr");
+
+/// Crypto of the keys used by the off-chain worker. The keys must belong to the calendar syncers
+/// of the platform, to the owners of the places, or to accounts with the permission to manage
+/// their calendars.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::Signature as Sr25519Signature;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	pub struct CalendarAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for CalendarAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+
+	impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+		for CalendarAuthId
+	{
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use super::*;
+	use crate::interface::CalendarInterface;
+	use frame_support::{
+		pallet_prelude::{ValueQuery, *},
+		sp_std::prelude::*,
+	};
+	use frame_system::{
+		offchain::{AppCrypto, CreateSignedTransaction},
+		pallet_prelude::*,
+	};
+	use pallet_bookings::CalendarDate;
+
+	pub type Bytes = Vec<u8>;
+
+	pub use structures::*;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info] // This allows us to use unsafe storages, at some point we might need bounded storages
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + pallet_places::Config + CreateSignedTransaction<Call<Self>>
+	{
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The keys used by the off-chain worker to sign the calendar synchronizations.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// The origin allowed to approve and remove the calendar syncers.
+		type SyncerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum length of the URL of an iCal feed.
+		#[pallet::constant]
+		type MaxFeedUrlLength: Get<u32>;
+
+		/// The maximum number of periods blocked in the calendar of a place.
+		#[pallet::constant]
+		type MaxBlockedPeriods: Get<u32>;
+
+		/// The number of blocks between two synchronizations of the iCal feeds.
+		#[pallet::constant]
+		type SyncInterval: Get<Self::BlockNumber>;
+	}
+
+	/// Stores the URL of the iCal feed of every place synchronized with an external calendar.
+	#[pallet::storage]
+	#[pallet::getter(fn get_calendar_feed)]
+	pub type CalendarFeeds<T: Config> = StorageMap<_, Twox64Concat, T::Hash, Bytes>;
+
+	/// Stores the periods blocked in the calendar of every place, as read from its iCal feed.
+	#[pallet::storage]
+	#[pallet::getter(fn get_blocked_periods)]
+	pub type BlockedPeriods<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, Vec<BlockedPeriod<T::Hash>>, ValueQuery>;
+
+	/// Stores the accounts of the platform allowed to synchronize the calendar of every place.
+	#[pallet::storage]
+	#[pallet::getter(fn get_calendar_syncers)]
+	pub type CalendarSyncers<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+	// Pallets use events to inform users when important changes are made.
+	// https://docs.substrate.io/main-docs/build/events-errors/
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The iCal feed of a Place has been set
+		CalendarFeedSet { id: T::Hash, sender: T::AccountId },
+		/// The iCal feed of a Place has been removed, and its dates unblocked
+		CalendarFeedRemoved { id: T::Hash, sender: T::AccountId },
+		/// Some dates of a Place have been blocked by its external calendar
+		DatesBlocked { id: T::Hash, start_date: CalendarDate, end_date: CalendarDate },
+		/// Some dates of a Place are no longer blocked by its external calendar
+		DatesUnblocked { id: T::Hash, start_date: CalendarDate, end_date: CalendarDate },
+		/// An account has been allowed to synchronize the calendar of every place
+		CalendarSyncerAdded { syncer: T::AccountId },
+		/// A calendar syncer has been removed
+		CalendarSyncerRemoved { syncer: T::AccountId },
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The URL of the feed is not an HTTP or HTTPS URL
+		InvalidFeedUrl,
+		/// The URL of the feed exceeds the maximum length
+		FeedUrlTooLong,
+		/// The place does not have an iCal feed
+		CalendarFeedNotSet,
+		/// The calendar exceeds the maximum number of blocked periods
+		TooManyBlockedPeriods,
+		/// The dates of a blocked period are not valid
+		InvalidBlockedPeriod,
+		/// The account is already a calendar syncer
+		AlreadyCalendarSyncer,
+		/// The account is not a calendar syncer
+		NotCalendarSyncer,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn offchain_worker(block_number: T::BlockNumber) {
+			Self::sync_calendars(block_number);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the iCal feed of a place.
+		///
+		/// This extrinsic allows the owner of a place, or an account allowed to manage its
+		/// calendar, to synchronize it with an external calendar, such as the one of another
+		/// platform where the place is listed.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account setting the feed.
+		/// * `place_id` - The identifier of the place.
+		/// * `url` - The HTTP or HTTPS URL of the iCal feed.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the feed is stored, and the `DispatchResult` contains no
+		/// error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(1)]
+		pub fn set_calendar_feed(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+			url: Bytes,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+			Self::_set_calendar_feed(&place_id, url, &sender)?;
+			// Deposit our "CalendarFeedSet" event.
+			Self::deposit_event(Event::CalendarFeedSet { id: place_id, sender });
+			Ok(())
+		}
+
+		/// Remove the iCal feed of a place.
+		///
+		/// This extrinsic stops the synchronization of a place with its external calendar, and
+		/// unblocks the dates it blocked. The feeds of removed places can be removed by anyone.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account removing the feed.
+		/// * `place_id` - The identifier of the place.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the feed is removed, and the `DispatchResult` contains no
+		/// error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(2)]
+		pub fn remove_calendar_feed(origin: OriginFor<T>, place_id: T::Hash) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+			Self::_remove_calendar_feed(&place_id, &sender)?;
+			// Deposit our "CalendarFeedRemoved" event.
+			Self::deposit_event(Event::CalendarFeedRemoved { id: place_id, sender });
+			Ok(())
+		}
+
+		/// Synchronize the blocked periods of a place with its iCal feed.
+		///
+		/// This extrinsic is signed by the off-chain worker, with a key of a calendar syncer or of
+		/// an account allowed to manage the calendar of the place, whenever the events of the feed
+		/// change. The given periods replace the ones currently blocked.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account synchronizing the calendar.
+		/// * `place_id` - The identifier of the place.
+		/// * `periods` - The periods blocked by the events of the feed.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the blocked periods are replaced, and the `DispatchResult`
+		/// contains no error. If the operation fails, the `DispatchResult` contains an error
+		/// describing the reason for failure.
+		#[pallet::call_index(3)]
+		pub fn sync_blocked_periods(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+			periods: Vec<BlockedPeriod<T::Hash>>,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+			let (blocked, unblocked) = Self::_sync_blocked_periods(&place_id, periods, &sender)?;
+			// Deposit our "DatesUnblocked" and "DatesBlocked" events.
+			for period in unblocked {
+				Self::deposit_event(Event::DatesUnblocked {
+					id: place_id,
+					start_date: period.start_date,
+					end_date: period.end_date,
+				});
+			}
+			for period in blocked {
+				Self::deposit_event(Event::DatesBlocked {
+					id: place_id,
+					start_date: period.start_date,
+					end_date: period.end_date,
+				});
+			}
+			Ok(())
+		}
+
+		/// Allow an account of the platform to synchronize the calendar of every place.
+		///
+		/// This extrinsic can only be called by the `SyncerOrigin`. The off-chain workers holding
+		/// the key of a calendar syncer synchronize the places whose owners have no key in their
+		/// keystore.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin approving the syncer.
+		/// * `syncer` - The account of the calendar syncer.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the syncer is stored, and the `DispatchResult` contains no
+		/// error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(4)]
+		pub fn add_calendar_syncer(origin: OriginFor<T>, syncer: T::AccountId) -> DispatchResult {
+			// Check origin
+			T::SyncerOrigin::ensure_origin(origin)?;
+			Self::_add_calendar_syncer(&syncer)?;
+			// Deposit our "CalendarSyncerAdded" event.
+			Self::deposit_event(Event::CalendarSyncerAdded { syncer });
+			Ok(())
+		}
+
+		/// Remove a calendar syncer.
+		///
+		/// This extrinsic can only be called by the `SyncerOrigin`.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin removing the syncer.
+		/// * `syncer` - The account of the calendar syncer.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the syncer is removed, and the `DispatchResult` contains no
+		/// error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(5)]
+		pub fn remove_calendar_syncer(
+			origin: OriginFor<T>,
+			syncer: T::AccountId,
+		) -> DispatchResult {
+			// Check origin
+			T::SyncerOrigin::ensure_origin(origin)?;
+			Self::_remove_calendar_syncer(&syncer)?;
+			// Deposit our "CalendarSyncerRemoved" event.
+			Self::deposit_event(Event::CalendarSyncerRemoved { syncer });
+			Ok(())
+		}
+	}
+}
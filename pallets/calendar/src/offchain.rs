@@ -0,0 +1,131 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{ical, BlockedPeriod, CalendarFeeds, Call, Config, Pallet};
+use frame_support::{
+	sp_runtime::{
+		offchain::{http, Duration},
+		traits::{Hash, Zero},
+	},
+	sp_std::{prelude::*, str},
+	traits::Get,
+};
+use frame_system::offchain::{Account, SendSignedTransaction, Signer};
+
+/// Time to wait for the server of an iCal feed to answer a request.
+const HTTP_TIMEOUT_MILLIS: u64 = 5_000;
+
+/// Maximum size, in bytes, of an iCal feed. Larger feeds are not read, and the calendar of the
+/// place is not synchronized.
+pub const MAX_FEED_SIZE: usize = 512 * 1024;
+
+const MILLIS_PER_DAY: u64 = 24 * 60 * 60 * 1000;
+
+impl<T: Config> Pallet<T> {
+	/// Synchronize the blocked periods of the places with their iCal feeds.
+	///
+	/// Every `SyncInterval` blocks, the feed of every place is fetched and its events are
+	/// compared with the periods blocked on chain. When they differ, the new periods are
+	/// submitted in a transaction signed by a local key of a calendar syncer, or of an account
+	/// allowed to manage the calendar of the place. Places without such a key in the keystore of
+	/// the node are skipped.
+	///
+	/// # Arguments
+	///
+	/// * `block_number` - The block at which the off-chain worker runs.
+	pub(crate) fn sync_calendars(block_number: T::BlockNumber) {
+		if !(block_number % T::SyncInterval::get()).is_zero() {
+			return
+		}
+		let signer = Signer::<T, T::AuthorityId>::all_accounts();
+		if !signer.can_sign() {
+			return
+		}
+		let accounts: Vec<Account<T>> = signer.accounts_from_keys().collect();
+		let today = sp_io::offchain::timestamp().unix_millis() / MILLIS_PER_DAY;
+
+		for (place_id, url) in <CalendarFeeds<T>>::iter() {
+			let Some(account) = accounts
+				.iter()
+				.find(|account| Self::ensure_can_sync_calendar(&place_id, &account.id).is_ok())
+			else {
+				continue
+			};
+
+			let feed = match Self::fetch_feed(&url) {
+				Ok(feed) => feed,
+				Err(error) => {
+					log::debug!(target: "did", "The iCal feed of Place with ID ➡ {:?} could not be fetched: {:?}", place_id, error);
+					continue
+				},
+			};
+			let periods = Self::blocked_periods_from_feed(&feed, today);
+			if periods == Self::get_blocked_periods(place_id) {
+				continue
+			}
+
+			let results = Signer::<T, T::AuthorityId>::all_accounts()
+				.with_filter(vec![account.public.clone()])
+				.send_signed_transaction(|_| Call::sync_blocked_periods {
+					place_id,
+					periods: periods.clone(),
+				});
+			if results.iter().any(|(_, result)| result.is_err()) {
+				log::debug!(target: "did", "The calendar of Place with ID ➡ {:?} could not be submitted.", place_id);
+			}
+		}
+	}
+
+	/// Get the periods blocked by the events of an iCal feed.
+	///
+	/// The events that already ended are skipped, and the remaining ones are sorted by their
+	/// start date. If there are more than `MaxBlockedPeriods`, the latest ones are dropped.
+	///
+	/// # Arguments
+	///
+	/// * `feed` - The content of the iCal feed.
+	/// * `today` - The number of days elapsed since the Unix epoch.
+	///
+	/// # Returns
+	///
+	/// Returns the periods to block.
+	pub fn blocked_periods_from_feed(feed: &[u8], today: u64) -> Vec<BlockedPeriod<T::Hash>> {
+		let mut periods: Vec<BlockedPeriod<T::Hash>> = ical::parse_events(feed)
+			.into_iter()
+			.filter(|event| event.end_date.days_since_epoch().map_or(false, |end| end > today))
+			.map(|event| BlockedPeriod {
+				uid: T::Hashing::hash(&event.uid),
+				start_date: event.start_date,
+				end_date: event.end_date,
+			})
+			.collect();
+		periods.sort_by(|a, b| (a.start_date, a.end_date).cmp(&(b.start_date, b.end_date)));
+		periods.truncate(T::MaxBlockedPeriods::get() as usize);
+		periods
+	}
+
+	/// Fetch the content of an iCal feed.
+	///
+	/// # Returns
+	///
+	/// Returns the content of the feed, or an error if it could not be fetched or it exceeds the
+	/// `MAX_FEED_SIZE`.
+	fn fetch_feed(url: &[u8]) -> Result<Vec<u8>, http::Error> {
+		let url = str::from_utf8(url).map_err(|_| http::Error::Unknown)?;
+
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MILLIS));
+		let pending = http::Request::get(url)
+			.deadline(deadline)
+			.send()
+			.map_err(|_| http::Error::IoError)?;
+		let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+
+		if response.code != 200 {
+			return Err(http::Error::Unknown)
+		}
+		// Read one more byte than allowed to detect the feeds that are too large
+		let feed: Vec<u8> = response.body().take(MAX_FEED_SIZE + 1).collect();
+		if feed.len() > MAX_FEED_SIZE {
+			return Err(http::Error::Unknown)
+		}
+		Ok(feed)
+	}
+}
@@ -0,0 +1,16 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use pallet_bookings::CalendarDate;
+use scale_info::TypeInfo;
+
+/// Period in which the host has blocked a place, such as a stay booked on another platform.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub struct BlockedPeriod<Hash> {
+	/// The hash of the UID of the event of the external calendar.
+	pub uid: Hash,
+	/// The checkin date of the blocked period, in the local time of the place.
+	pub start_date: CalendarDate,
+	/// The checkout date of the blocked period, in the local time of the place.
+	pub end_date: CalendarDate,
+}
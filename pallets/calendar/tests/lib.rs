@@ -0,0 +1,523 @@
+#[cfg(test)]
+pub mod mock;
+use codec::Decode;
+use frame_support::{assert_noop, assert_ok, dispatch::Dispatchable, traits::Hooks};
+use pallet_bookings::{traits::BlockedDates, CalendarDate};
+use pallet_calendar::{ical, BlockedPeriod, Call, Error};
+use pallet_places::{Coordinates, Error as PlacesError, PlaceAttributes, PlaceType};
+use parking_lot::RwLock;
+use sp_core::{
+	offchain::{
+		testing::{
+			OffchainState, PendingRequest, PoolState, TestOffchainExt, TestTransactionPoolExt,
+		},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	sr25519::Public,
+	H256,
+};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	RuntimeAppPublic,
+};
+use std::sync::Arc;
+
+use crate::mock::*;
+
+const FEED_URL: &[u8] = b"https://calendar.test/place.ics";
+const FEED: &[u8] = b"BEGIN:VCALENDAR\r
+VERSION:2.0\r
+BEGIN:VEVENT\r
+UID:stay-1@other.platform\r
+DTSTART;VALUE=DATE:20250410\r
+DTEND;VALUE=DATE:20250413\r
+SUMMARY:Reserved\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:stay-2@other.pla\r
+ tform\r
+DTSTART:20250501T150000Z\r
+DTEND:20250503T110000Z\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:stay-3@other.platform\r
+DTSTART;VALUE=DATE:20250601\r
+DTEND;VALUE=DATE:20250605\r
+STATUS:CANCELLED\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:stay-4@other.platform\r
+DTSTART;VALUE=DATE:20251231\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+DTSTART;VALUE=DATE:20250701\r
+DTEND;VALUE=DATE:20250705\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+fn account(seed: u8) -> AccountId {
+	Public::from_raw([seed; 32])
+}
+
+fn create_place(owner: AccountId) -> H256 {
+	assert_ok!(Places::create_place(
+		RuntimeOrigin::signed(owner),
+		PlaceType::Apartment,
+		b"Demo Place".to_vec(),
+		b"Demo City".to_vec(),
+		Coordinates { latitude: 40_416_775, longitude: -3_703_790 },
+		BlakeTwo256::hash(b"Demo Description"),
+		10,
+		17,
+		12,
+		0,
+		vec![],
+		None,
+		PlaceAttributes::default(),
+	));
+	Places::get_all_places()[0]
+}
+
+fn blocked_period(
+	uid: &[u8],
+	start_date: CalendarDate,
+	end_date: CalendarDate,
+) -> BlockedPeriod<H256> {
+	BlockedPeriod { uid: BlakeTwo256::hash(uid), start_date, end_date }
+}
+
+fn feed_periods() -> Vec<BlockedPeriod<H256>> {
+	vec![
+		blocked_period(
+			b"stay-1@other.platform",
+			CalendarDate::new(2025, 4, 10),
+			CalendarDate::new(2025, 4, 13),
+		),
+		blocked_period(
+			b"stay-2@other.platform",
+			CalendarDate::new(2025, 5, 1),
+			CalendarDate::new(2025, 5, 3),
+		),
+		blocked_period(
+			b"stay-4@other.platform",
+			CalendarDate::new(2025, 12, 31),
+			CalendarDate::new(2026, 1, 1),
+		),
+	]
+}
+
+// ========================================================
+// iCal Unit Tests
+// ========================================================
+#[test]
+fn test_parse_events_should_work() {
+	let events = ical::parse_events(FEED);
+
+	// Canceled events and events without UID are skipped
+	assert_eq!(
+		events,
+		vec![
+			ical::CalendarEvent {
+				uid: b"stay-1@other.platform".to_vec(),
+				start_date: CalendarDate::new(2025, 4, 10),
+				end_date: CalendarDate::new(2025, 4, 13),
+			},
+			ical::CalendarEvent {
+				uid: b"stay-2@other.platform".to_vec(),
+				start_date: CalendarDate::new(2025, 5, 1),
+				end_date: CalendarDate::new(2025, 5, 3),
+			},
+			// Events without end date last one day
+			ical::CalendarEvent {
+				uid: b"stay-4@other.platform".to_vec(),
+				start_date: CalendarDate::new(2025, 12, 31),
+				end_date: CalendarDate::new(2026, 1, 1),
+			},
+		]
+	);
+
+	assert!(ical::parse_events(b"BEGIN:VEVENT\nUID:1\nDTSTART:20250230\nEND:VEVENT").is_empty());
+	assert!(ical::parse_events(b"not a calendar").is_empty());
+}
+
+#[test]
+fn test_blocked_periods_from_feed_should_skip_past_events() {
+	// 2025-05-02 is 20_210 days after the Unix epoch
+	let periods = Calendar::blocked_periods_from_feed(FEED, 20_210);
+	assert_eq!(periods, feed_periods()[1..].to_vec());
+}
+
+// ========================================================
+// Calendar Feeds Unit Tests
+// ========================================================
+#[test]
+fn test_set_calendar_feed_should_work() {
+	build_with_default_config().execute_with(|| {
+		let place_id = create_place(account(1));
+
+		assert_noop!(
+			Calendar::set_calendar_feed(
+				RuntimeOrigin::signed(account(2)),
+				place_id,
+				FEED_URL.to_vec()
+			),
+			PlacesError::<Test>::MissingPermission
+		);
+		assert_noop!(
+			Calendar::set_calendar_feed(
+				RuntimeOrigin::signed(account(1)),
+				H256::zero(),
+				FEED_URL.to_vec()
+			),
+			PlacesError::<Test>::PlaceNotFound
+		);
+		assert_noop!(
+			Calendar::set_calendar_feed(
+				RuntimeOrigin::signed(account(1)),
+				place_id,
+				b"ftp://calendar.test/place.ics".to_vec()
+			),
+			Error::<Test>::InvalidFeedUrl
+		);
+		assert_noop!(
+			Calendar::set_calendar_feed(
+				RuntimeOrigin::signed(account(1)),
+				place_id,
+				[FEED_URL, &[b'x'; 64]].concat()
+			),
+			Error::<Test>::FeedUrlTooLong
+		);
+
+		assert_ok!(Calendar::set_calendar_feed(
+			RuntimeOrigin::signed(account(1)),
+			place_id,
+			FEED_URL.to_vec()
+		));
+		assert_eq!(Calendar::get_calendar_feed(place_id), Some(FEED_URL.to_vec()));
+		System::assert_last_event(
+			pallet_calendar::Event::CalendarFeedSet { id: place_id, sender: account(1) }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_sync_blocked_periods_should_work() {
+	build_with_default_config().execute_with(|| {
+		let place_id = create_place(account(1));
+		let periods = feed_periods();
+
+		assert_noop!(
+			Calendar::sync_blocked_periods(
+				RuntimeOrigin::signed(account(1)),
+				place_id,
+				periods.clone()
+			),
+			Error::<Test>::CalendarFeedNotSet
+		);
+		assert_ok!(Calendar::set_calendar_feed(
+			RuntimeOrigin::signed(account(1)),
+			place_id,
+			FEED_URL.to_vec()
+		));
+		assert_noop!(
+			Calendar::sync_blocked_periods(
+				RuntimeOrigin::signed(account(2)),
+				place_id,
+				periods.clone()
+			),
+			PlacesError::<Test>::MissingPermission
+		);
+		assert_noop!(
+			Calendar::sync_blocked_periods(
+				RuntimeOrigin::signed(account(1)),
+				place_id,
+				vec![blocked_period(
+					b"switched",
+					CalendarDate::new(2025, 4, 13),
+					CalendarDate::new(2025, 4, 10)
+				)]
+			),
+			Error::<Test>::InvalidBlockedPeriod
+		);
+		assert_noop!(
+			Calendar::sync_blocked_periods(
+				RuntimeOrigin::signed(account(1)),
+				place_id,
+				[periods.clone(), periods.clone()].concat()
+			),
+			Error::<Test>::TooManyBlockedPeriods
+		);
+
+		assert_ok!(Calendar::sync_blocked_periods(
+			RuntimeOrigin::signed(account(1)),
+			place_id,
+			periods.clone()
+		));
+		assert_eq!(Calendar::get_blocked_periods(place_id), periods);
+		assert_eq!(
+			<Calendar as BlockedDates<H256>>::blocked_periods(&place_id)[0],
+			(CalendarDate::new(2025, 4, 10), CalendarDate::new(2025, 4, 13))
+		);
+
+		// Only the changes are notified
+		System::reset_events();
+		assert_ok!(Calendar::sync_blocked_periods(
+			RuntimeOrigin::signed(account(1)),
+			place_id,
+			periods[1..].to_vec()
+		));
+		assert_eq!(Calendar::get_blocked_periods(place_id), periods[1..].to_vec());
+		System::assert_last_event(
+			pallet_calendar::Event::DatesUnblocked {
+				id: place_id,
+				start_date: CalendarDate::new(2025, 4, 10),
+				end_date: CalendarDate::new(2025, 4, 13),
+			}
+			.into(),
+		);
+		assert_eq!(System::events().len(), 1);
+	})
+}
+
+#[test]
+fn test_add_calendar_syncer_should_work() {
+	build_with_default_config().execute_with(|| {
+		assert_noop!(
+			Calendar::add_calendar_syncer(RuntimeOrigin::signed(account(1)), account(9)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Calendar::add_calendar_syncer(RuntimeOrigin::root(), account(9)));
+		assert_eq!(Calendar::get_calendar_syncers(), vec![account(9)]);
+		assert_noop!(
+			Calendar::add_calendar_syncer(RuntimeOrigin::root(), account(9)),
+			Error::<Test>::AlreadyCalendarSyncer
+		);
+
+		assert_ok!(Calendar::remove_calendar_syncer(RuntimeOrigin::root(), account(9)));
+		assert!(Calendar::get_calendar_syncers().is_empty());
+		assert_noop!(
+			Calendar::remove_calendar_syncer(RuntimeOrigin::root(), account(9)),
+			Error::<Test>::NotCalendarSyncer
+		);
+	})
+}
+
+#[test]
+fn test_sync_blocked_periods_by_calendar_syncer_should_work() {
+	build_with_default_config().execute_with(|| {
+		let place_id = create_place(account(1));
+		assert_ok!(Calendar::set_calendar_feed(
+			RuntimeOrigin::signed(account(1)),
+			place_id,
+			FEED_URL.to_vec()
+		));
+		assert_ok!(Calendar::add_calendar_syncer(RuntimeOrigin::root(), account(9)));
+
+		// Calendar syncers can synchronize every place, but not set its feed
+		assert_ok!(Calendar::sync_blocked_periods(
+			RuntimeOrigin::signed(account(9)),
+			place_id,
+			feed_periods()
+		));
+		assert_eq!(Calendar::get_blocked_periods(place_id), feed_periods());
+		assert_noop!(
+			Calendar::set_calendar_feed(
+				RuntimeOrigin::signed(account(9)),
+				place_id,
+				FEED_URL.to_vec()
+			),
+			PlacesError::<Test>::MissingPermission
+		);
+		assert_noop!(
+			Calendar::sync_blocked_periods(
+				RuntimeOrigin::signed(account(9)),
+				H256::repeat_byte(1),
+				feed_periods()
+			),
+			PlacesError::<Test>::PlaceNotFound
+		);
+	})
+}
+
+#[test]
+fn test_remove_calendar_feed_should_work() {
+	build_with_default_config().execute_with(|| {
+		let place_id = create_place(account(1));
+
+		assert_noop!(
+			Calendar::remove_calendar_feed(RuntimeOrigin::signed(account(1)), place_id),
+			Error::<Test>::CalendarFeedNotSet
+		);
+		assert_ok!(Calendar::set_calendar_feed(
+			RuntimeOrigin::signed(account(1)),
+			place_id,
+			FEED_URL.to_vec()
+		));
+		assert_ok!(Calendar::sync_blocked_periods(
+			RuntimeOrigin::signed(account(1)),
+			place_id,
+			feed_periods()
+		));
+		assert_noop!(
+			Calendar::remove_calendar_feed(RuntimeOrigin::signed(account(2)), place_id),
+			PlacesError::<Test>::MissingPermission
+		);
+
+		assert_ok!(Calendar::remove_calendar_feed(RuntimeOrigin::signed(account(1)), place_id));
+		assert_eq!(Calendar::get_calendar_feed(place_id), None);
+		assert!(<Calendar as BlockedDates<H256>>::blocked_periods(&place_id).is_empty());
+	})
+}
+
+// ========================================================
+// Off-chain Worker Unit Tests
+// ========================================================
+fn build_with_offchain(
+	keystore: MemoryKeystore,
+) -> (sp_io::TestExternalities, Arc<RwLock<OffchainState>>, Arc<RwLock<PoolState>>) {
+	let mut ext = build_with_default_config();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(KeystoreExt::new(keystore));
+	(ext, offchain_state, pool_state)
+}
+
+fn expect_feed(state: &Arc<RwLock<OffchainState>>) {
+	state.write().expect_request(PendingRequest {
+		method: "GET".into(),
+		uri: String::from_utf8(FEED_URL.to_vec()).unwrap(),
+		response: Some(FEED.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+}
+
+#[test]
+fn test_offchain_worker_should_sync_calendar() {
+	let keystore = MemoryKeystore::new();
+	let host = keystore
+		.sr25519_generate_new(pallet_calendar::crypto::Public::ID, None)
+		.unwrap();
+	let (mut ext, offchain_state, pool_state) = build_with_offchain(keystore);
+
+	ext.execute_with(|| {
+		let place_id = create_place(host);
+		assert_ok!(Calendar::set_calendar_feed(
+			RuntimeOrigin::signed(host),
+			place_id,
+			FEED_URL.to_vec()
+		));
+
+		// The feeds are only synchronized every `SyncInterval` blocks
+		Calendar::offchain_worker(9);
+		assert!(pool_state.read().transactions.is_empty());
+
+		expect_feed(&offchain_state);
+		Calendar::offchain_worker(10);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, Some((0, ())));
+		assert_eq!(
+			tx.call,
+			RuntimeCall::Calendar(Call::sync_blocked_periods { place_id, periods: feed_periods() })
+		);
+		assert_ok!(tx.call.dispatch(RuntimeOrigin::signed(host)));
+
+		// Nothing is submitted while the feed does not change
+		expect_feed(&offchain_state);
+		Calendar::offchain_worker(20);
+		assert!(pool_state.read().transactions.is_empty());
+	})
+}
+
+#[test]
+fn test_offchain_worker_with_syncer_key_should_sync_calendar() {
+	let keystore = MemoryKeystore::new();
+	let syncer = keystore
+		.sr25519_generate_new(pallet_calendar::crypto::Public::ID, None)
+		.unwrap();
+	let (mut ext, offchain_state, pool_state) = build_with_offchain(keystore);
+
+	ext.execute_with(|| {
+		let place_id = create_place(account(1));
+		assert_ok!(Calendar::set_calendar_feed(
+			RuntimeOrigin::signed(account(1)),
+			place_id,
+			FEED_URL.to_vec()
+		));
+		assert_ok!(Calendar::add_calendar_syncer(RuntimeOrigin::root(), syncer));
+
+		expect_feed(&offchain_state);
+		Calendar::offchain_worker(10);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			RuntimeCall::Calendar(Call::sync_blocked_periods { place_id, periods: feed_periods() })
+		);
+		assert_ok!(tx.call.dispatch(RuntimeOrigin::signed(syncer)));
+		assert_eq!(Calendar::get_blocked_periods(place_id), feed_periods());
+	})
+}
+
+#[test]
+fn test_offchain_worker_should_skip_oversized_feed() {
+	let keystore = MemoryKeystore::new();
+	let host = keystore
+		.sr25519_generate_new(pallet_calendar::crypto::Public::ID, None)
+		.unwrap();
+	let (mut ext, offchain_state, pool_state) = build_with_offchain(keystore);
+
+	ext.execute_with(|| {
+		let place_id = create_place(host);
+		assert_ok!(Calendar::set_calendar_feed(
+			RuntimeOrigin::signed(host),
+			place_id,
+			FEED_URL.to_vec()
+		));
+
+		// The feed is larger than allowed
+		offchain_state.write().expect_request(PendingRequest {
+			method: "GET".into(),
+			uri: String::from_utf8(FEED_URL.to_vec()).unwrap(),
+			response: Some(
+				[FEED, &vec![b' '; pallet_calendar::offchain::MAX_FEED_SIZE][..]].concat(),
+			),
+			sent: true,
+			..Default::default()
+		});
+		Calendar::offchain_worker(10);
+
+		assert!(pool_state.read().transactions.is_empty());
+	})
+}
+
+#[test]
+fn test_offchain_worker_without_host_key_should_skip_place() {
+	let keystore = MemoryKeystore::new();
+	keystore
+		.sr25519_generate_new(pallet_calendar::crypto::Public::ID, None)
+		.unwrap();
+	let (mut ext, _, pool_state) = build_with_offchain(keystore);
+
+	ext.execute_with(|| {
+		let place_id = create_place(account(1));
+		assert_ok!(Calendar::set_calendar_feed(
+			RuntimeOrigin::signed(account(1)),
+			place_id,
+			FEED_URL.to_vec()
+		));
+
+		// The feed is not even fetched
+		Calendar::offchain_worker(10);
+		assert!(pool_state.read().transactions.is_empty());
+	})
+}
@@ -0,0 +1,193 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{
+	interface::LoyaltyInterface, BalanceOf, Config, Error, Event, Pallet, Points, PointsBatch,
+};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	sp_runtime::{
+		traits::{Saturating, Zero},
+		DispatchError,
+	},
+	sp_std::vec::Vec,
+	traits::Get,
+};
+use pallet_bookings::traits::LoyaltyProgram;
+
+impl<T: Config> LoyaltyInterface<T> for Pallet<T> {
+	fn _mint_points(account: &T::AccountId, points: BalanceOf<T>) {
+		if points.is_zero() {
+			return
+		}
+		let expires_at = Self::add_batch(account, points);
+
+		Self::deposit_event(Event::PointsEarned { account: account.clone(), points, expires_at });
+	}
+
+	fn _redeem_points(
+		account: &T::AccountId,
+		points: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let mut batches = Self::active_batches(account);
+		let available = batches
+			.iter()
+			.fold(BalanceOf::<T>::zero(), |acc, batch| acc.saturating_add(batch.points));
+		ensure!(available >= points, Error::<T>::InsufficientPoints);
+
+		// The batches closer to expire are redeemed first
+		let mut remaining = points;
+		for batch in batches.iter_mut() {
+			let redeemed = remaining.min(batch.points);
+			batch.points = batch.points.saturating_sub(redeemed);
+			remaining = remaining.saturating_sub(redeemed);
+			if remaining.is_zero() {
+				break
+			}
+		}
+		batches.retain(|batch| !batch.points.is_zero());
+
+		// Make persistance
+		Self::store_batches(account, batches);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "Account ➡ {:?} has redeemed {:?} points.", account, points);
+
+		Ok(points)
+	}
+}
+
+impl<T: Config> LoyaltyProgram<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn on_booking_completed(guest: &T::AccountId, amount: BalanceOf<T>) {
+		// Only the guest earns points, so two accounts cannot farm them by booking each other
+		Self::_mint_points(guest, T::GuestPointsRate::get().mul_floor(amount));
+	}
+
+	fn redeemable_discount(account: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+		Self::available_points(account).min(T::MaxRedemptionShare::get().mul_floor(amount))
+	}
+
+	fn redeem_points(account: &T::AccountId, discount: BalanceOf<T>) -> DispatchResult {
+		if discount.is_zero() {
+			return Ok(())
+		}
+		let points = Self::_redeem_points(account, discount)?;
+		Self::deposit_event(Event::PointsRedeemed { account: account.clone(), points });
+		Ok(())
+	}
+
+	fn restore_points(account: &T::AccountId, discount: BalanceOf<T>) {
+		if discount.is_zero() {
+			return
+		}
+		// The batches the points were redeemed from may have expired, so they last a new lifetime
+		let expires_at = Self::add_batch(account, discount);
+
+		Self::deposit_event(Event::PointsRestored {
+			account: account.clone(),
+			points: discount,
+			expires_at,
+		});
+	}
+}
+
+/// Auxiliar functions implementation
+impl<T: Config> Pallet<T> {
+	/// Get the points an account can redeem.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account identifier.
+	///
+	/// # Returns
+	///
+	/// Returns the sum of the points of the batches of the account that have not expired yet.
+	pub fn available_points(account: &T::AccountId) -> BalanceOf<T> {
+		let current_block = <frame_system::Pallet<T>>::block_number();
+		Self::get_points_batches(account)
+			.iter()
+			.filter(|batch| batch.expires_at > current_block)
+			.fold(BalanceOf::<T>::zero(), |acc, batch| acc.saturating_add(batch.points))
+	}
+
+	/// Get the batches of an account that have not expired yet.
+	///
+	/// The expired batches are dropped and an event is deposited for them, but the updated
+	/// batches are not persisted.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account identifier.
+	///
+	/// # Returns
+	///
+	/// Returns the batches of the account that can still be redeemed.
+	fn active_batches(account: &T::AccountId) -> Vec<PointsBatch<BalanceOf<T>, T::BlockNumber>> {
+		let current_block = <frame_system::Pallet<T>>::block_number();
+		let (active, expired): (Vec<_>, Vec<_>) = Self::get_points_batches(account)
+			.into_iter()
+			.partition(|batch| batch.expires_at > current_block);
+
+		let expired_points = expired
+			.iter()
+			.fold(BalanceOf::<T>::zero(), |acc, batch| acc.saturating_add(batch.points));
+		if !expired_points.is_zero() {
+			Self::deposit_event(Event::PointsExpired {
+				account: account.clone(),
+				points: expired_points,
+			});
+		}
+
+		active
+	}
+
+	/// Add a new batch of points to an account, expiring once `PointsLifetime` blocks have passed.
+	///
+	/// The points are added to the newest batch if it expires at the same block, or if the account
+	/// already has `MaxPointBatches` batches, in which case the whole batch expires with the new
+	/// points.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account identifier.
+	/// * `points` - The number of points of the batch.
+	///
+	/// # Returns
+	///
+	/// Returns the block at which the new batch expires.
+	fn add_batch(account: &T::AccountId, points: BalanceOf<T>) -> T::BlockNumber {
+		let mut batches = Self::active_batches(account);
+		let expires_at =
+			<frame_system::Pallet<T>>::block_number().saturating_add(T::PointsLifetime::get());
+
+		let full = batches.len() as u32 >= T::MaxPointBatches::get();
+		match batches.last_mut() {
+			Some(newest) if full || newest.expires_at == expires_at => {
+				newest.points = newest.points.saturating_add(points);
+				newest.expires_at = expires_at;
+			},
+			_ => batches.push(PointsBatch { points, expires_at }),
+		}
+
+		// Make persistance
+		<Points<T>>::insert(account, batches);
+
+		expires_at
+	}
+
+	/// Persist the batches of an account, removing the entry once it has no points left.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account identifier.
+	/// * `batches` - The updated batches of the account.
+	fn store_batches(
+		account: &T::AccountId,
+		batches: Vec<PointsBatch<BalanceOf<T>, T::BlockNumber>>,
+	) {
+		if batches.is_empty() {
+			<Points<T>>::remove(account);
+		} else {
+			<Points<T>>::insert(account, batches);
+		}
+	}
+}
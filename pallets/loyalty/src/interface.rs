@@ -0,0 +1,38 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{BalanceOf, Config};
+use frame_support::sp_runtime::DispatchError;
+
+/// Interface for Loyalty pallet
+pub trait LoyaltyInterface<T: Config> {
+	/// Mint points to an account.
+	///
+	/// This function adds a new batch of points to the `account`, which expires once
+	/// `PointsLifetime` blocks have passed. The batches of an account are capped to
+	/// `MaxPointBatches`. Minting zero points does nothing.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account identifier earning the points.
+	/// * `points` - The number of points to mint.
+	fn _mint_points(account: &T::AccountId, points: BalanceOf<T>);
+
+	/// Redeem the points of an account.
+	///
+	/// This function burns `points` from the batches of the `account`, starting from the ones
+	/// closer to expire. The expired batches are removed first.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account identifier redeeming the points.
+	/// * `points` - The number of points to redeem.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the number of points redeemed. Otherwise, it contains a
+	/// `DispatchError` explaining the reason for failure.
+	fn _redeem_points(
+		account: &T::AccountId,
+		points: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError>;
+}
@@ -0,0 +1,89 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub mod functions;
+pub mod interface;
+pub mod structures;
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::{ValueQuery, *},
+		sp_runtime::Perbill,
+		sp_std::prelude::*,
+		traits::Currency,
+	};
+	use frame_system::pallet_prelude::*;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub use structures::*;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info] // This allows us to use unsafe storages, at some point we might need bounded storages
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The Currency of the bookings. Every point is worth one unit of it when redeemed.
+		type Currency: Currency<Self::AccountId>;
+
+		/// The share of the amount paid for a completed booking the guest earns as points.
+		#[pallet::constant]
+		type GuestPointsRate: Get<Perbill>;
+
+		/// The maximum share of the amount of a booking that can be paid with points.
+		#[pallet::constant]
+		type MaxRedemptionShare: Get<Perbill>;
+
+		/// The number of blocks the points can be redeemed for since they are earned.
+		#[pallet::constant]
+		type PointsLifetime: Get<Self::BlockNumber>;
+
+		/// The maximum number of batches of points of an account. Once reached, the points earned
+		/// are added to the newest batch.
+		#[pallet::constant]
+		type MaxPointBatches: Get<u32>;
+	}
+
+	/// Stores the points of every account, grouped by the block at which they expire and ordered
+	/// from the oldest to the newest. There are at most `MaxPointBatches` batches per account.
+	#[pallet::storage]
+	#[pallet::getter(fn get_points_batches)]
+	pub type Points<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Vec<PointsBatch<BalanceOf<T>, T::BlockNumber>>,
+		ValueQuery,
+	>;
+
+	// Pallets use events to inform users when important changes are made.
+	// https://docs.substrate.io/main-docs/build/events-errors/
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account has earned points from a completed booking
+		PointsEarned { account: T::AccountId, points: BalanceOf<T>, expires_at: T::BlockNumber },
+		/// An account has redeemed points as a discount on a booking
+		PointsRedeemed { account: T::AccountId, points: BalanceOf<T> },
+		/// Some points of an account have expired without being redeemed
+		PointsExpired { account: T::AccountId, points: BalanceOf<T> },
+		/// The points redeemed on a canceled booking have been given back to the account
+		PointsRestored { account: T::AccountId, points: BalanceOf<T>, expires_at: T::BlockNumber },
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account does not have enough points to redeem
+		InsufficientPoints,
+	}
+}
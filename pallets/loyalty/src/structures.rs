@@ -0,0 +1,13 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Loyalty points earned by an account at once, and the block at which they expire.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub struct PointsBatch<Balance, BlockNumber> {
+	/// The number of points left in the batch.
+	pub points: Balance,
+	/// The block from which the points can no longer be redeemed.
+	pub expires_at: BlockNumber,
+}
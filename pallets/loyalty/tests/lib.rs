@@ -0,0 +1,189 @@
+#[cfg(test)]
+pub mod mock;
+use frame_support::{assert_err, assert_noop, assert_ok};
+use pallet_bookings::traits::LoyaltyProgram;
+use pallet_loyalty::{Error, PointsBatch};
+
+use crate::mock::*;
+
+const GUEST: u64 = 1;
+const HOST: u64 = 2;
+
+fn complete_booking(amount: u64) {
+	<Loyalty as LoyaltyProgram<u64, u64>>::on_booking_completed(&GUEST, amount);
+}
+
+// ========================================================
+// Points Earning Unit Tests
+// ========================================================
+#[test]
+fn test_complete_booking_mints_points_should_work() {
+	build_with_funded_accounts().execute_with(|| {
+		complete_booking(200);
+
+		assert_eq!(
+			Loyalty::get_points_batches(GUEST),
+			vec![PointsBatch { points: 20, expires_at: 101 }]
+		);
+		// Hosts do not earn points
+		assert!(Loyalty::get_points_batches(HOST).is_empty());
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_loyalty::Event::PointsEarned { account: GUEST, points: 20, expires_at: 101 }
+				.into(),
+		);
+	})
+}
+
+#[test]
+fn test_complete_booking_without_points_should_not_mint() {
+	build_with_funded_accounts().execute_with(|| {
+		complete_booking(5);
+
+		assert!(Loyalty::get_points_batches(GUEST).is_empty());
+	})
+}
+
+#[test]
+fn test_points_batches_should_be_bounded() {
+	build_with_funded_accounts().execute_with(|| {
+		// Points earned in the same block share a batch
+		complete_booking(100);
+		complete_booking(100);
+		assert_eq!(
+			Loyalty::get_points_batches(GUEST),
+			vec![PointsBatch { points: 20, expires_at: 101 }]
+		);
+
+		System::set_block_number(2);
+		complete_booking(100);
+		System::set_block_number(3);
+		complete_booking(100);
+
+		// Once full, the newest batch takes the new points and expires with them
+		System::set_block_number(4);
+		complete_booking(100);
+		assert_eq!(
+			Loyalty::get_points_batches(GUEST),
+			vec![
+				PointsBatch { points: 20, expires_at: 101 },
+				PointsBatch { points: 10, expires_at: 102 },
+				PointsBatch { points: 20, expires_at: 104 },
+			]
+		);
+		assert_eq!(Loyalty::available_points(&GUEST), 50);
+	})
+}
+
+// ========================================================
+// Points Redemption Unit Tests
+// ========================================================
+#[test]
+fn test_redeemable_discount_is_capped() {
+	build_with_funded_accounts().execute_with(|| {
+		complete_booking(200);
+
+		// Only half of the amount can be paid with points
+		assert_eq!(<Loyalty as LoyaltyProgram<u64, u64>>::redeemable_discount(&GUEST, 30), 15);
+		// And never more than the available points
+		assert_eq!(<Loyalty as LoyaltyProgram<u64, u64>>::redeemable_discount(&GUEST, 100), 20);
+		assert_eq!(<Loyalty as LoyaltyProgram<u64, u64>>::redeemable_discount(&3, 100), 0);
+	})
+}
+
+#[test]
+fn test_redeem_points_uses_oldest_first() {
+	build_with_funded_accounts().execute_with(|| {
+		complete_booking(100);
+		System::set_block_number(50);
+		complete_booking(100);
+
+		assert_ok!(<Loyalty as LoyaltyProgram<u64, u64>>::redeem_points(&GUEST, 15));
+
+		assert_eq!(
+			Loyalty::get_points_batches(GUEST),
+			vec![PointsBatch { points: 5, expires_at: 150 }]
+		);
+		assert_eq!(Loyalty::available_points(&GUEST), 5);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_loyalty::Event::PointsRedeemed { account: GUEST, points: 15 }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_redeem_more_points_than_available_should_fail() {
+	build_with_funded_accounts().execute_with(|| {
+		complete_booking(200);
+
+		assert_noop!(
+			<Loyalty as LoyaltyProgram<u64, u64>>::redeem_points(&GUEST, 21),
+			Error::<Test>::InsufficientPoints
+		);
+		assert_eq!(Loyalty::available_points(&GUEST), 20);
+	})
+}
+
+#[test]
+fn test_restore_points_should_work() {
+	build_with_funded_accounts().execute_with(|| {
+		complete_booking(200);
+		assert_ok!(<Loyalty as LoyaltyProgram<u64, u64>>::redeem_points(&GUEST, 15));
+
+		// The redeemed batch has expired by the time the booking is canceled
+		System::set_block_number(101);
+		<Loyalty as LoyaltyProgram<u64, u64>>::restore_points(&GUEST, 15);
+
+		assert_eq!(
+			Loyalty::get_points_batches(GUEST),
+			vec![PointsBatch { points: 15, expires_at: 201 }]
+		);
+		assert_eq!(Loyalty::available_points(&GUEST), 15);
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_loyalty::Event::PointsRestored { account: GUEST, points: 15, expires_at: 201 }
+				.into(),
+		);
+	})
+}
+
+// ========================================================
+// Points Expiration Unit Tests
+// ========================================================
+#[test]
+fn test_expired_points_cannot_be_redeemed() {
+	build_with_funded_accounts().execute_with(|| {
+		complete_booking(200);
+		System::set_block_number(101);
+
+		assert_eq!(Loyalty::available_points(&GUEST), 0);
+		assert_eq!(<Loyalty as LoyaltyProgram<u64, u64>>::redeemable_discount(&GUEST, 100), 0);
+		assert_err!(
+			<Loyalty as LoyaltyProgram<u64, u64>>::redeem_points(&GUEST, 5),
+			Error::<Test>::InsufficientPoints
+		);
+	})
+}
+
+#[test]
+fn test_expired_points_are_dropped_when_minting() {
+	build_with_funded_accounts().execute_with(|| {
+		complete_booking(200);
+		System::set_block_number(120);
+		complete_booking(100);
+
+		assert_eq!(
+			Loyalty::get_points_batches(GUEST),
+			vec![PointsBatch { points: 10, expires_at: 220 }]
+		);
+
+		// Check emitted events
+		System::assert_has_event(
+			pallet_loyalty::Event::PointsExpired { account: GUEST, points: 20 }.into(),
+		);
+	})
+}
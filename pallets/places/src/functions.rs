@@ -1,11 +1,22 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use crate::{
-	interface::PlacesInterface, structures::*, Bytes, Config, Error, Pallet, PlacesData, PlacesIds,
+	geohash::{self, GEOHASH_INDEX_PRECISION, GEOHASH_LOCATION_PRECISION},
+	interface::PlacesInterface,
+	offchain::CONTENT_STATUS_LONGEVITY,
+	structures::*,
+	traits::Verification,
+	Bytes, Config, ContentCheckedAt, ContentCheckers, Error, Event, GuestVerifications,
+	InspectionBalanceOf, InspectionRequests, Inspectors, NoShowPolicies, Pallet, PaymentSchedules,
+	PayoutSplits, PlaceRoles, PlacesByGeohash, PlacesData, PlacesIds,
 };
 use frame_support::{
 	ensure,
-	sp_runtime::traits::Hash,
+	sp_runtime::{
+		traits::{Hash, Saturating},
+		Perbill,
+	},
 	sp_std::{collections::btree_set::BTreeSet, prelude::*},
+	traits::{BalanceStatus, Get, ReservableCurrency},
 };
 
 impl<T: Config> PlacesInterface<T> for Pallet<T> {
@@ -14,28 +25,40 @@ impl<T: Config> PlacesInterface<T> for Pallet<T> {
 	fn _create_place(
 		place_type: PlaceType,
 		name: Bytes,
-		address: Bytes,
+		location: Bytes,
+		coordinates: Coordinates,
 		description: T::Hash,
 		price_per_night: u64,
 		checkin_hour: u32,
 		checkout_hour: u32,
+		utc_offset_minutes: i16,
 		images: Vec<T::Hash>,
 		number_of_floors: Option<u8>,
+		attributes: PlaceAttributes,
 		sender: &T::AccountId,
 	) -> Result<T::Hash, Error<T>> {
 		Self::ensure_checkin_checkout_hours_are_correct(checkin_hour, checkout_hour)?;
+		Self::ensure_utc_offset_is_correct(utc_offset_minutes)?;
+		ensure!(coordinates.is_valid(), Error::<T>::InvalidCoordinates);
+		Self::ensure_attributes_are_correct(&attributes)?;
+
+		// Only the coarse cell of the coordinates is stored
+		let cell = geohash::encode(&coordinates, GEOHASH_LOCATION_PRECISION);
 
 		// Create a new place
 		let place_data: PlaceData<T> = PlaceData::new(
 			place_type,
 			name,
-			address,
+			location,
+			cell.clone(),
 			description,
 			price_per_night,
 			checkin_hour,
 			checkout_hour,
+			utc_offset_minutes,
 			images.into_iter().collect(),
 			number_of_floors,
+			attributes,
 			sender.clone(),
 		);
 
@@ -48,6 +71,7 @@ impl<T: Config> PlacesInterface<T> for Pallet<T> {
 		// Make persistance
 		<PlacesData<T>>::insert(place_id, place_data);
 		<PlacesIds<T>>::append(place_id);
+		Self::add_place_to_geohash_index(place_id, &cell);
 
 		// Logging to the console on debug level
 		log::debug!(target: "did", "A new Place with ID ➡ {:?} has been created.", place_id);
@@ -59,27 +83,46 @@ impl<T: Config> PlacesInterface<T> for Pallet<T> {
 		place_id: &T::Hash,
 		place_type: Option<PlaceType>,
 		name: Option<Bytes>,
-		address: Option<Bytes>,
+		location: Option<Bytes>,
+		coordinates: Option<Coordinates>,
 		description: Option<T::Hash>,
 		price_per_night: Option<u64>,
 		checkin_hour: Option<u32>,
 		checkout_hour: Option<u32>,
+		utc_offset_minutes: Option<i16>,
 		images: Option<Vec<T::Hash>>,
 		number_of_floors: Option<u8>,
+		attributes: Option<PlaceAttributes>,
 		sender: &T::AccountId,
 	) -> Result<T::Hash, Self::Error> {
 		// Retrieve place
 		if let Some(mut place_data) = Self::get_place_by_id(place_id) {
+			ensure!(
+				Self::has_permission(place_id, sender, Permissions::EDIT_LISTING),
+				Error::<T>::MissingPermission
+			);
+
 			if let Some(new_pt) = place_type {
 				place_data.place_type = new_pt;
 			}
 			if let Some(new_name) = name {
 				place_data.name = new_name;
 			}
-			if let Some(new_address) = address {
-				place_data.address = new_address;
+			if let Some(new_location) = location {
+				place_data.location = new_location;
+			}
+			if let Some(new_coordinates) = coordinates {
+				ensure!(new_coordinates.is_valid(), Error::<T>::InvalidCoordinates);
+				let new_cell = geohash::encode(&new_coordinates, GEOHASH_LOCATION_PRECISION);
+				Self::move_place_in_geohash_index(place_id, &place_data.geohash, &new_cell);
+				place_data.geohash = new_cell;
 			}
 			if let Some(new_description) = description {
+				if new_description != place_data.description {
+					// The attestation and the content check no longer match the listing
+					place_data.inspection = None;
+					place_data.content_status = ContentStatus::Unchecked;
+				}
 				place_data.description = new_description;
 			}
 			if let Some(new_ppn) = price_per_night {
@@ -99,9 +142,20 @@ impl<T: Config> PlacesInterface<T> for Pallet<T> {
 				place_data.checkout_hour,
 			)?;
 
+			if let Some(offset) = utc_offset_minutes {
+				Self::ensure_utc_offset_is_correct(offset)?;
+				place_data.utc_offset_minutes = offset;
+			}
+
 			if let Some(new_images) = images {
 				let new_images_set: BTreeSet<T::Hash> = new_images.into_iter().collect();
-				let images_union = new_images_set.union(&place_data.images).cloned().collect();
+				let images_union: BTreeSet<T::Hash> =
+					new_images_set.union(&place_data.images).cloned().collect();
+				if images_union != place_data.images {
+					// The attestation and the content check no longer match the listing
+					place_data.inspection = None;
+					place_data.content_status = ContentStatus::Unchecked;
+				}
 				place_data.images = images_union;
 			}
 
@@ -109,6 +163,11 @@ impl<T: Config> PlacesInterface<T> for Pallet<T> {
 				place_data.number_of_floors = new_nof;
 			}
 
+			if let Some(new_attributes) = attributes {
+				Self::ensure_attributes_are_correct(&new_attributes)?;
+				place_data.attributes = new_attributes;
+			}
+
 			place_data.on_chain_update = Some(AuditTrail::new(sender.clone()));
 
 			// Make persistance
@@ -122,9 +181,20 @@ impl<T: Config> PlacesInterface<T> for Pallet<T> {
 		Err(Error::<T>::UnhandledException)
 	}
 
-	fn _remove_place(place_id: &<T as frame_system::Config>::Hash) -> Result<T::Hash, Self::Error> {
+	fn _remove_place(
+		place_id: &<T as frame_system::Config>::Hash,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error> {
 		// Retrieve place
-		if let Some(_) = Self::get_place_by_id(place_id) {
+		if let Some(place_data) = Self::get_place_by_id(place_id) {
+			// Only the owner can remove the place, before releasing anything
+			ensure!(&place_data.owner == sender, Error::<T>::NotPlaceOwner);
+			// The bond stays locked while the host can still cancel a stay
+			ensure!(
+				!T::ActiveBookings::has_active_bookings(place_id),
+				Error::<T>::PlaceHasActiveBookings
+			);
+
 			// Make persistance
 			<PlacesIds<T>>::mutate(|pids| {
 				if let Some(idx) = pids.iter().position(|x| x == place_id) {
@@ -132,15 +202,366 @@ impl<T: Config> PlacesInterface<T> for Pallet<T> {
 				}
 			});
 			<PlacesData<T>>::remove(place_id);
+			Self::remove_place_from_geohash_index(place_id, &place_data.geohash);
+			let _ = <PlaceRoles<T>>::clear_prefix(place_id, u32::MAX, None);
+			<PayoutSplits<T>>::remove(place_id);
+			<PaymentSchedules<T>>::remove(place_id);
+			<NoShowPolicies<T>>::remove(place_id);
+			<GuestVerifications<T>>::remove(place_id);
+			<ContentCheckedAt<T>>::remove(place_id);
+			if let Some(request) = <InspectionRequests<T>>::take(place_id) {
+				T::InspectionCurrency::unreserve(&request.requester, request.fee);
+			}
 
 			return Ok(*place_id)
 		}
 		Err(Error::<T>::PlaceNotFound)
 	}
+
+	fn _assign_place_role(
+		place_id: &T::Hash,
+		account: T::AccountId,
+		role: PlaceRole,
+		permissions: Option<Permissions>,
+		payout_share: Option<Perbill>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error> {
+		let place_data = Self::get_place_by_id(place_id).ok_or(Error::<T>::PlaceNotFound)?;
+		ensure!(&place_data.owner == sender, Error::<T>::NotPlaceOwner);
+		ensure!(place_data.owner != account, Error::<T>::CannotAssignRoleToOwner);
+
+		let payout_share = payout_share.unwrap_or_default();
+
+		// The shares of the rest of the accounts plus the new one cannot exceed 100%
+		let assigned_parts: u64 = <PlaceRoles<T>>::iter_prefix(place_id)
+			.filter(|(holder, _)| holder != &account)
+			.map(|(_, role_data)| role_data.payout_share.deconstruct() as u64)
+			.sum();
+		ensure!(
+			assigned_parts.saturating_add(payout_share.deconstruct() as u64) <=
+				Perbill::one().deconstruct() as u64,
+			Error::<T>::PayoutSharesExceedTotal
+		);
+
+		let role_data = PlaceRoleData {
+			permissions: permissions.unwrap_or_else(|| role.default_permissions()),
+			role,
+			payout_share,
+		};
+
+		// Make persistance
+		<PlaceRoles<T>>::insert(place_id, &account, role_data);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "A role has been assigned in Place with ID ➡ {:?}.", place_id);
+
+		Ok(*place_id)
+	}
+
+	fn _revoke_place_role(
+		place_id: &T::Hash,
+		account: &T::AccountId,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error> {
+		let place_data = Self::get_place_by_id(place_id).ok_or(Error::<T>::PlaceNotFound)?;
+		ensure!(&place_data.owner == sender, Error::<T>::NotPlaceOwner);
+		ensure!(<PlaceRoles<T>>::contains_key(place_id, account), Error::<T>::RoleNotFound);
+
+		// Make persistance
+		<PlaceRoles<T>>::remove(place_id, account);
+
+		Ok(*place_id)
+	}
+
+	fn _set_payout_split(
+		place_id: &T::Hash,
+		split: Vec<(T::AccountId, Perbill)>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error> {
+		let place_data = Self::get_place_by_id(place_id).ok_or(Error::<T>::PlaceNotFound)?;
+		ensure!(&place_data.owner == sender, Error::<T>::NotPlaceOwner);
+
+		if split.is_empty() {
+			<PayoutSplits<T>>::remove(place_id);
+			return Ok(*place_id)
+		}
+
+		ensure!(
+			split.len() <= T::MaxPayoutBeneficiaries::get() as usize,
+			Error::<T>::TooManyPayoutBeneficiaries
+		);
+
+		let beneficiaries: BTreeSet<&T::AccountId> =
+			split.iter().map(|(account, _)| account).collect();
+		ensure!(beneficiaries.len() == split.len(), Error::<T>::DuplicatedPayoutBeneficiary);
+
+		let total_parts: u64 = split.iter().map(|(_, share)| share.deconstruct() as u64).sum();
+		ensure!(total_parts == Perbill::one().deconstruct() as u64, Error::<T>::InvalidPayoutSplit);
+
+		// Make persistance
+		<PayoutSplits<T>>::insert(place_id, split);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "The payout split of Place with ID ➡ {:?} has been updated.", place_id);
+
+		Ok(*place_id)
+	}
+
+	fn _set_payment_schedule(
+		place_id: &T::Hash,
+		schedule: Option<PaymentSchedule>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error> {
+		let place_data = Self::get_place_by_id(place_id).ok_or(Error::<T>::PlaceNotFound)?;
+		ensure!(&place_data.owner == sender, Error::<T>::NotPlaceOwner);
+
+		// Make persistance
+		match schedule {
+			Some(schedule) => {
+				ensure!(schedule.deposit < Perbill::one(), Error::<T>::InvalidPaymentSchedule);
+				<PaymentSchedules<T>>::insert(place_id, schedule);
+			},
+			None => <PaymentSchedules<T>>::remove(place_id),
+		}
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "The payment schedule of Place with ID ➡ {:?} has been updated.", place_id);
+
+		Ok(*place_id)
+	}
+
+	fn _set_no_show_policy(
+		place_id: &T::Hash,
+		policy: Option<NoShowPolicy>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error> {
+		let place_data = Self::get_place_by_id(place_id).ok_or(Error::<T>::PlaceNotFound)?;
+		ensure!(&place_data.owner == sender, Error::<T>::NotPlaceOwner);
+
+		// Make persistance
+		match policy {
+			Some(policy) => <NoShowPolicies<T>>::insert(place_id, policy),
+			None => <NoShowPolicies<T>>::remove(place_id),
+		}
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "The no-show policy of Place with ID ➡ {:?} has been updated.", place_id);
+
+		Ok(*place_id)
+	}
+
+	fn _set_guest_verification(
+		place_id: &T::Hash,
+		level: Option<VerificationLevel>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error> {
+		let place_data = Self::get_place_by_id(place_id).ok_or(Error::<T>::PlaceNotFound)?;
+		ensure!(&place_data.owner == sender, Error::<T>::NotPlaceOwner);
+
+		// Make persistance
+		match level {
+			Some(level) if level != VerificationLevel::Unverified =>
+				<GuestVerifications<T>>::insert(place_id, level),
+			_ => <GuestVerifications<T>>::remove(place_id),
+		}
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "The guest verification of Place with ID ➡ {:?} has been updated.", place_id);
+
+		Ok(*place_id)
+	}
+
+	fn _add_inspector(inspector: &T::AccountId) -> Result<(), Self::Error> {
+		<Inspectors<T>>::try_mutate(|inspectors| {
+			ensure!(!inspectors.contains(inspector), Error::<T>::AlreadyInspector);
+			inspectors.push(inspector.clone());
+			Ok(())
+		})
+	}
+
+	fn _remove_inspector(inspector: &T::AccountId) -> Result<(), Self::Error> {
+		<Inspectors<T>>::try_mutate(|inspectors| {
+			let index = inspectors
+				.iter()
+				.position(|account| account == inspector)
+				.ok_or(Error::<T>::NotInspector)?;
+			inspectors.swap_remove(index);
+			Ok(())
+		})
+	}
+
+	fn _add_content_checker(checker: &T::Public) -> Result<(), Self::Error> {
+		<ContentCheckers<T>>::try_mutate(|checkers| {
+			ensure!(!checkers.contains(checker), Error::<T>::AlreadyContentChecker);
+			checkers.push(checker.clone());
+			Ok(())
+		})
+	}
+
+	fn _remove_content_checker(checker: &T::Public) -> Result<(), Self::Error> {
+		<ContentCheckers<T>>::try_mutate(|checkers| {
+			let index = checkers
+				.iter()
+				.position(|key| key == checker)
+				.ok_or(Error::<T>::NotContentChecker)?;
+			checkers.swap_remove(index);
+			Ok(())
+		})
+	}
+
+	fn _request_inspection(
+		place_id: &T::Hash,
+		inspector: &T::AccountId,
+		fee: InspectionBalanceOf<T>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error> {
+		let place_data = Self::get_place_by_id(place_id).ok_or(Error::<T>::PlaceNotFound)?;
+		ensure!(&place_data.owner == sender, Error::<T>::NotPlaceOwner);
+		ensure!(Self::get_inspectors().contains(inspector), Error::<T>::NotInspector);
+		ensure!(inspector != sender, Error::<T>::CannotInspectOwnPlace);
+		ensure!(
+			!<InspectionRequests<T>>::contains_key(place_id),
+			Error::<T>::InspectionAlreadyRequested
+		);
+
+		// Make persistance
+		T::InspectionCurrency::reserve(sender, fee)
+			.map_err(|_| Error::<T>::InsufficientBalanceForInspection)?;
+		<InspectionRequests<T>>::insert(
+			place_id,
+			InspectionRequest { requester: sender.clone(), inspector: inspector.clone(), fee },
+		);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "The inspection of Place with ID ➡ {:?} has been requested.", place_id);
+
+		Ok(*place_id)
+	}
+
+	fn _cancel_inspection_request(
+		place_id: &T::Hash,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error> {
+		let request =
+			Self::get_inspection_request(place_id).ok_or(Error::<T>::InspectionNotRequested)?;
+		ensure!(&request.requester == sender, Error::<T>::NotPlaceOwner);
+
+		// Make persistance
+		T::InspectionCurrency::unreserve(&request.requester, request.fee);
+		<InspectionRequests<T>>::remove(place_id);
+
+		Ok(*place_id)
+	}
+
+	fn _attest_place(
+		place_id: &T::Hash,
+		description: T::Hash,
+		images: Vec<T::Hash>,
+		sender: &T::AccountId,
+	) -> Result<T::Moment, Self::Error> {
+		ensure!(Self::get_inspectors().contains(sender), Error::<T>::NotInspector);
+		let mut place_data = Self::get_place_by_id(place_id).ok_or(Error::<T>::PlaceNotFound)?;
+		ensure!(&place_data.owner != sender, Error::<T>::CannotInspectOwnPlace);
+		let images: BTreeSet<T::Hash> = images.into_iter().collect();
+		ensure!(
+			place_data.description == description && place_data.images == images,
+			Error::<T>::InspectionMismatch
+		);
+
+		let attested_at = <pallet_timestamp::Pallet<T>>::now();
+		let expires_at = attested_at.saturating_add(T::InspectionValidity::get());
+
+		// Make persistance
+		if let Some(request) =
+			Self::get_inspection_request(place_id).filter(|request| &request.inspector == sender)
+		{
+			// The fee in escrow only goes to the inspector chosen by the owner
+			<InspectionRequests<T>>::remove(place_id);
+			T::InspectionCurrency::repatriate_reserved(
+				&request.requester,
+				sender,
+				request.fee,
+				BalanceStatus::Free,
+			)
+			.map_err(|_| Error::<T>::UnhandledException)?;
+			Self::deposit_event(Event::InspectionFeeClaimed {
+				id: *place_id,
+				inspector: sender.clone(),
+				fee: request.fee,
+			});
+		}
+		place_data.inspection =
+			Some(PlaceInspection { inspector: sender.clone(), attested_at, expires_at });
+		<PlacesData<T>>::insert(place_id, place_data);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "The Place with ID ➡ {:?} has been attested.", place_id);
+
+		Ok(expires_at)
+	}
+
+	fn _submit_content_status(
+		place_id: &T::Hash,
+		content: T::Hash,
+		status: ContentStatus,
+		block_number: T::BlockNumber,
+	) -> Result<T::Hash, Self::Error> {
+		let mut place_data = Self::get_place_by_id(place_id).ok_or(Error::<T>::PlaceNotFound)?;
+		ensure!(Self::content_hash(&place_data) == content, Error::<T>::ContentChanged);
+		ensure!(
+			Self::is_fresh_content_status(place_id, block_number),
+			Error::<T>::StaleContentStatus
+		);
+
+		// Make persistance
+		place_data.content_status = status;
+		<PlacesData<T>>::insert(place_id, place_data);
+		<ContentCheckedAt<T>>::insert(place_id, block_number);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "The content of Place with ID ➡ {:?} has been checked: {:?}", place_id, status);
+
+		Ok(*place_id)
+	}
 }
 
 /// Auxiliar functions implementation
 impl<T: Config> Pallet<T> {
+	/// Check whether a content status checked at a block can still be accepted for a place.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the place.
+	/// * `block_number` - The block at which the content was checked.
+	///
+	/// # Returns
+	///
+	/// Returns `true` if the block is not in the future, is at most `CONTENT_STATUS_LONGEVITY`
+	/// blocks old and is newer than the last content status accepted for the place.
+	pub(crate) fn is_fresh_content_status(
+		place_id: &T::Hash,
+		block_number: T::BlockNumber,
+	) -> bool {
+		let now = frame_system::Pallet::<T>::block_number();
+		block_number <= now &&
+			now <= block_number.saturating_add(CONTENT_STATUS_LONGEVITY.into()) &&
+			Self::get_content_checked_at(place_id)
+				.map_or(true, |checked_at| checked_at < block_number)
+	}
+
+	/// Get the hash identifying the content referenced by a place, its description and images.
+	///
+	/// # Arguments
+	///
+	/// * `place_data` - The data of the place.
+	///
+	/// # Returns
+	///
+	/// Returns the hash of the description and images references.
+	pub fn content_hash(place_data: &PlaceData<T>) -> T::Hash {
+		T::Hashing::hash_of(&(&place_data.description, &place_data.images))
+	}
+
 	fn ensure_checkin_checkout_hours_are_correct(
 		checkin_hour: u32,
 		checkout_hour: u32,
@@ -154,4 +575,283 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// UTC offsets range from UTC-12:00 to UTC+14:00, in steps of 15 minutes.
+	fn ensure_utc_offset_is_correct(utc_offset_minutes: i16) -> Result<(), Error<T>> {
+		ensure!(
+			(-12 * 60..=14 * 60).contains(&utc_offset_minutes) && utc_offset_minutes % 15 == 0,
+			Error::<T>::InvalidUtcOffset
+		);
+
+		Ok(())
+	}
+
+	fn ensure_attributes_are_correct(attributes: &PlaceAttributes) -> Result<(), Error<T>> {
+		ensure!(
+			attributes.house_rules.len() <= T::MaxHouseRules::get() as usize,
+			Error::<T>::TooManyHouseRules
+		);
+		let max_length = T::MaxHouseRuleLength::get() as usize;
+		ensure!(
+			attributes
+				.house_rules
+				.iter()
+				.all(|(key, value)| key.len() <= max_length && value.len() <= max_length),
+			Error::<T>::HouseRuleTooLong
+		);
+
+		Ok(())
+	}
+
+	/// Checks whether an account holds a permission over a place.
+	///
+	/// The owner of the place holds every permission, the rest of the accounts hold the
+	/// permissions of the role they have been assigned, if any.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the place.
+	/// * `account` - The account to check.
+	/// * `permission` - The permission required.
+	///
+	/// # Returns
+	///
+	/// Returns `true` if the account holds the permission, `false` otherwise.
+	pub fn has_permission(
+		place_id: &T::Hash,
+		account: &T::AccountId,
+		permission: Permissions,
+	) -> bool {
+		match Self::get_place_by_id(place_id) {
+			Some(place_data) if &place_data.owner == account => true,
+			Some(_) => Self::get_place_role(place_id, account)
+				.map_or(false, |role_data| role_data.permissions.contains(permission)),
+			None => false,
+		}
+	}
+
+	/// Get the beneficiaries of the payouts of a place.
+	///
+	/// If the place has a payout split, its beneficiaries are returned as they were configured.
+	/// Otherwise, every account with a role and a payout share receives its share, and the owner
+	/// keeps the remainder, being the first entry of the list. In both cases the first beneficiary
+	/// is the one that should receive any rounding dust.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the place.
+	/// * `owner` - The owner of the place.
+	///
+	/// # Returns
+	///
+	/// Returns a vector of `(account, share)` tuples whose shares add up to 100%.
+	pub fn get_payout_shares(
+		place_id: &T::Hash,
+		owner: &T::AccountId,
+	) -> Vec<(T::AccountId, Perbill)> {
+		let split = Self::get_payout_split(place_id);
+		if !split.is_empty() {
+			return split
+		}
+
+		let mut shares: Vec<(T::AccountId, Perbill)> = <PlaceRoles<T>>::iter_prefix(place_id)
+			.filter(|(_, role_data)| role_data.payout_share.deconstruct() > 0)
+			.map(|(account, role_data)| (account, role_data.payout_share))
+			.collect();
+
+		let assigned_parts: u32 =
+			shares.iter().map(|(_, share)| share.deconstruct()).fold(0, u32::saturating_add);
+		let owner_share =
+			Perbill::from_parts(Perbill::one().deconstruct().saturating_sub(assigned_parts));
+		shares.insert(0, (owner.clone(), owner_share));
+
+		shares
+	}
+
+	/// Returns the cell of the geohash index containing a location cell, if the location is set.
+	fn index_cell(location_cell: &[u8]) -> Option<Bytes> {
+		location_cell
+			.get(..GEOHASH_INDEX_PRECISION as usize)
+			.map(|prefix| prefix.to_vec())
+	}
+
+	/// Adds a place to the index cell containing its location cell.
+	fn add_place_to_geohash_index(place_id: T::Hash, location_cell: &[u8]) {
+		if let Some(cell) = Self::index_cell(location_cell) {
+			<PlacesByGeohash<T>>::append(cell, place_id);
+		}
+	}
+
+	/// Removes a place from the index cell containing its location cell.
+	fn remove_place_from_geohash_index(place_id: &T::Hash, location_cell: &[u8]) {
+		let Some(cell) = Self::index_cell(location_cell) else { return };
+		<PlacesByGeohash<T>>::mutate_exists(cell, |maybe_places| {
+			if let Some(places) = maybe_places {
+				places.retain(|id| id != place_id);
+				if places.is_empty() {
+					*maybe_places = None;
+				}
+			}
+		});
+	}
+
+	/// Moves a place to the index cell of its new location cell, if it changes.
+	fn move_place_in_geohash_index(place_id: &T::Hash, old_cell: &[u8], new_cell: &[u8]) {
+		if Self::index_cell(old_cell) != Self::index_cell(new_cell) {
+			Self::remove_place_from_geohash_index(place_id, old_cell);
+			Self::add_place_to_geohash_index(*place_id, new_cell);
+		}
+	}
+
+	/// Checks whether a place matches the criteria of a search filter.
+	fn matches_filter<D, F>(
+		place_id: &T::Hash,
+		place_data: &PlaceData<T>,
+		filter: &PlaceFilter<D>,
+		is_available: &F,
+	) -> bool
+	where
+		F: Fn(&T::Hash, &D, &D) -> bool,
+	{
+		place_data.active &&
+			filter.place_type.as_ref().map_or(true, |pt| pt == &place_data.place_type) &&
+			filter.min_price.map_or(true, |min| place_data.price_per_night >= min) &&
+			filter.max_price.map_or(true, |max| place_data.price_per_night <= max) &&
+			place_data.attributes.amenities.contains(filter.required_amenities) &&
+			filter
+				.house_rules
+				.iter()
+				.all(|(key, value)| place_data.attributes.house_rules.get(key) == Some(value)) &&
+			(!filter.verified_host || Self::is_verified_host(&place_data.owner)) &&
+			filter
+				.available_between
+				.as_ref()
+				.map_or(true, |(start, end)| is_available(place_id, start, end))
+	}
+
+	/// Checks whether an account is shown as a verified host in its listings.
+	///
+	/// # Arguments
+	///
+	/// * `host` - The account identifier of the host.
+	///
+	/// # Returns
+	///
+	/// Returns `true` if the host holds the verification level of verified hosts.
+	pub fn is_verified_host(host: &T::AccountId) -> bool {
+		T::Verification::verification_level(host) >= VerificationLevel::VERIFIED_HOST
+	}
+
+	/// Checks whether a place holds a valid attestation of an inspector.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the place.
+	///
+	/// # Returns
+	///
+	/// Returns `true` if the place has been attested and the attestation has not expired yet.
+	pub fn is_place_inspected(place_id: &T::Hash) -> bool {
+		let current_moment = <pallet_timestamp::Pallet<T>>::now();
+		Self::get_place_by_id(place_id)
+			.and_then(|place_data| place_data.inspection)
+			.map_or(false, |inspection| inspection.expires_at > current_moment)
+	}
+
+	/// Checks whether an account is verified enough to book a place.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the place.
+	/// * `guest` - The account identifier of the guest.
+	///
+	/// # Returns
+	///
+	/// Returns `true` if the place has no verification requirement or the guest meets it.
+	pub fn meets_guest_verification(place_id: &T::Hash, guest: &T::AccountId) -> bool {
+		Self::get_guest_verification(place_id)
+			.map_or(true, |level| T::Verification::verification_level(guest) >= level)
+	}
+
+	/// Get the identifiers of the places that may lie inside a bounding box.
+	///
+	/// The candidates are read from the geohash cells covering the area.
+	///
+	/// # Returns
+	///
+	/// Returns the identifiers of the candidates, or an error if the area needs more than
+	/// `MAX_SEARCH_CELLS` cells to be covered.
+	fn places_in_area(bounding_box: &BoundingBox) -> Result<Vec<T::Hash>, Error<T>> {
+		let cells = geohash::covering_cells(bounding_box, GEOHASH_INDEX_PRECISION)
+			.ok_or(Error::<T>::SearchAreaTooLarge)?;
+		Ok(cells.into_iter().flat_map(|cell| Self::get_places_by_geohash(cell)).collect())
+	}
+
+	/// Search the active places located inside a bounding box. A place is located where the
+	/// center of its location cell is.
+	///
+	/// # Arguments
+	///
+	/// * `bounding_box` - The area to search in.
+	/// * `filter` - The criteria the places must match.
+	/// * `is_available` - Checks whether a place is available between two dates. The availability
+	///   is kept by the pallets handling the bookings.
+	///
+	/// # Returns
+	///
+	/// Returns the identifiers of the places found, or an error if the area is too large.
+	pub fn search_places_in_bounding_box<D, F>(
+		bounding_box: &BoundingBox,
+		filter: &PlaceFilter<D>,
+		is_available: F,
+	) -> Result<Vec<T::Hash>, Error<T>>
+	where
+		F: Fn(&T::Hash, &D, &D) -> bool,
+	{
+		Ok(Self::places_in_area(bounding_box)?
+			.into_iter()
+			.filter(|place_id| {
+				Self::get_place_by_id(place_id).map_or(false, |place_data| {
+					geohash::decode_center(&place_data.geohash)
+						.map_or(false, |location| bounding_box.contains(&location)) &&
+						Self::matches_filter(place_id, &place_data, filter, &is_available)
+				})
+			})
+			.collect())
+	}
+
+	/// Search the active places located within a radius of a point. A place is located where the
+	/// center of its location cell is.
+	///
+	/// # Arguments
+	///
+	/// * `center` - The center of the area to search in.
+	/// * `radius` - The radius of the area, in meters.
+	/// * `filter` - The criteria the places must match.
+	/// * `is_available` - Checks whether a place is available between two dates. The availability
+	///   is kept by the pallets handling the bookings.
+	///
+	/// # Returns
+	///
+	/// Returns the identifiers of the places found, or an error if the area is too large.
+	pub fn search_places_within_radius<D, F>(
+		center: &Coordinates,
+		radius: u32,
+		filter: &PlaceFilter<D>,
+		is_available: F,
+	) -> Result<Vec<T::Hash>, Error<T>>
+	where
+		F: Fn(&T::Hash, &D, &D) -> bool,
+	{
+		Ok(Self::places_in_area(&geohash::bounding_box_around(center, radius))?
+			.into_iter()
+			.filter(|place_id| {
+				Self::get_place_by_id(place_id).map_or(false, |place_data| {
+					geohash::decode_center(&place_data.geohash).map_or(false, |location| {
+						geohash::is_within_radius(center, &location, radius)
+					}) && Self::matches_filter(place_id, &place_data, filter, &is_available)
+				})
+			})
+			.collect())
+	}
 }
@@ -0,0 +1,238 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{BoundingBox, Coordinates};
+use frame_support::sp_std::prelude::*;
+
+/// Number of characters of the geohash used to index the places. A 4-character cell covers
+/// roughly 39km x 19.5km at the equator.
+pub const GEOHASH_INDEX_PRECISION: u8 = 4;
+
+/// Number of characters of the geohash stored as the location of the places. A 6-character cell
+/// covers roughly 1.2km x 0.6km at the equator, the exact coordinates are never stored.
+pub const GEOHASH_LOCATION_PRECISION: u8 = 6;
+
+/// Maximum number of geohash cells visited by a search. Bigger areas cannot be searched.
+pub const MAX_SEARCH_CELLS: u64 = 1024;
+
+/// Approximate length in meters of a degree of latitude.
+pub const METERS_PER_DEGREE: u64 = 111_320;
+
+const MICRO_DEGREES: i64 = 1_000_000;
+const LATITUDE_RANGE: i64 = 180 * MICRO_DEGREES;
+const LONGITUDE_RANGE: i64 = 360 * MICRO_DEGREES;
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Cosine of every integer degree between 0 and 90, scaled by `COS_SCALE`.
+const COS_SCALE: i128 = 10_000;
+const COS_TABLE: [u16; 91] = [
+	10000, 9998, 9994, 9986, 9976, 9962, 9945, 9925, 9903, 9877, 9848, 9816, 9781, 9744, 9703,
+	9659, 9613, 9563, 9511, 9455, 9397, 9336, 9272, 9205, 9135, 9063, 8988, 8910, 8829, 8746, 8660,
+	8572, 8480, 8387, 8290, 8192, 8090, 7986, 7880, 7771, 7660, 7547, 7431, 7314, 7193, 7071, 6947,
+	6820, 6691, 6561, 6428, 6293, 6157, 6018, 5878, 5736, 5592, 5446, 5299, 5150, 5000, 4848, 4695,
+	4540, 4384, 4226, 4067, 3907, 3746, 3584, 3420, 3256, 3090, 2924, 2756, 2588, 2419, 2250, 2079,
+	1908, 1736, 1564, 1392, 1219, 1045, 872, 698, 523, 349, 175, 0,
+];
+
+/// Returns the number of bits used for the longitude and the latitude at a given precision.
+fn precision_bits(precision: u8) -> (u32, u32) {
+	let total_bits = 5 * precision.clamp(1, 12) as u32;
+	((total_bits + 1) / 2, total_bits / 2)
+}
+
+/// Returns the index of the cell containing `value` when `range` is split in `2^bits` cells.
+fn cell_index(value: i32, offset: i64, range: i64, bits: u32) -> u64 {
+	let shifted = (value as i64 + offset).clamp(0, range) as u128;
+	let index = shifted * (1u128 << bits) / range as u128;
+	index.min((1u128 << bits) - 1) as u64
+}
+
+/// Returns the `(longitude, latitude)` indexes of the cell containing the coordinates.
+fn cell_of(coordinates: &Coordinates, precision: u8) -> (u64, u64) {
+	let (longitude_bits, latitude_bits) = precision_bits(precision);
+	(
+		cell_index(coordinates.longitude, LONGITUDE_RANGE / 2, LONGITUDE_RANGE, longitude_bits),
+		cell_index(coordinates.latitude, LATITUDE_RANGE / 2, LATITUDE_RANGE, latitude_bits),
+	)
+}
+
+/// Returns the coordinate of the center of the cell `index` when `range` is split in `2^bits`
+/// cells.
+fn cell_center(index: u64, offset: i64, range: i64, bits: u32) -> i32 {
+	((2 * index as i128 + 1) * range as i128 / (2i128 << bits) - offset as i128) as i32
+}
+
+/// Encodes the cell identified by its `(longitude, latitude)` indexes as a geohash.
+fn encode_cell(longitude_index: u64, latitude_index: u64, precision: u8) -> Vec<u8> {
+	let precision = precision.clamp(1, 12);
+	let (longitude_bits, latitude_bits) = precision_bits(precision);
+
+	// Interleave the bits, starting with the longitude
+	let mut code: u64 = 0;
+	for position in 0..5 * precision as u32 {
+		let bit = if position % 2 == 0 {
+			(longitude_index >> (longitude_bits - 1 - position / 2)) & 1
+		} else {
+			(latitude_index >> (latitude_bits - 1 - position / 2)) & 1
+		};
+		code = (code << 1) | bit;
+	}
+
+	(0..precision as u32)
+		.map(|character| {
+			let shift = 5 * (precision as u32 - 1 - character);
+			BASE32[((code >> shift) & 31) as usize]
+		})
+		.collect()
+}
+
+/// Encodes the coordinates as a geohash of the given precision.
+///
+/// # Arguments
+///
+/// * `coordinates` - The coordinates to encode.
+/// * `precision` - The number of characters of the geohash, between 1 and 12.
+///
+/// # Returns
+///
+/// Returns the geohash as a vector of base32 characters.
+pub fn encode(coordinates: &Coordinates, precision: u8) -> Vec<u8> {
+	let (longitude_index, latitude_index) = cell_of(coordinates, precision);
+	encode_cell(longitude_index, latitude_index, precision)
+}
+
+/// Decodes a geohash into the coordinates of the center of its cell.
+///
+/// # Arguments
+///
+/// * `geohash` - The geohash to decode, between 1 and 12 characters.
+///
+/// # Returns
+///
+/// Returns the coordinates of the center of the cell, or `None` if the geohash is not valid.
+pub fn decode_center(geohash: &[u8]) -> Option<Coordinates> {
+	let precision = u8::try_from(geohash.len())
+		.ok()
+		.filter(|precision| (1..=12).contains(precision))?;
+	let (longitude_bits, latitude_bits) = precision_bits(precision);
+
+	// Split the interleaved bits, starting with the longitude
+	let (mut longitude_index, mut latitude_index) = (0u64, 0u64);
+	for (character_index, character) in geohash.iter().enumerate() {
+		let value = BASE32.iter().position(|base| base == character)? as u64;
+		for bit_index in 0..5u32 {
+			let bit = (value >> (4 - bit_index)) & 1;
+			if (5 * character_index as u32 + bit_index) % 2 == 0 {
+				longitude_index = (longitude_index << 1) | bit;
+			} else {
+				latitude_index = (latitude_index << 1) | bit;
+			}
+		}
+	}
+
+	Some(Coordinates {
+		latitude: cell_center(latitude_index, LATITUDE_RANGE / 2, LATITUDE_RANGE, latitude_bits),
+		longitude: cell_center(
+			longitude_index,
+			LONGITUDE_RANGE / 2,
+			LONGITUDE_RANGE,
+			longitude_bits,
+		),
+	})
+}
+
+/// Get the geohash cells that cover a bounding box.
+///
+/// # Arguments
+///
+/// * `bounding_box` - The area to cover.
+/// * `precision` - The number of characters of the geohashes.
+///
+/// # Returns
+///
+/// Returns the list of geohashes covering the area, or `None` if the area needs more than
+/// `MAX_SEARCH_CELLS` cells.
+pub fn covering_cells(bounding_box: &BoundingBox, precision: u8) -> Option<Vec<Vec<u8>>> {
+	let (min_longitude, min_latitude) = cell_of(&bounding_box.south_west, precision);
+	let (max_longitude, max_latitude) = cell_of(&bounding_box.north_east, precision);
+
+	let cells = (max_longitude.checked_sub(min_longitude)? + 1)
+		.saturating_mul(max_latitude.checked_sub(min_latitude)? + 1);
+	if cells > MAX_SEARCH_CELLS {
+		return None
+	}
+
+	let mut geohashes = Vec::with_capacity(cells as usize);
+	for longitude_index in min_longitude..=max_longitude {
+		for latitude_index in min_latitude..=max_latitude {
+			geohashes.push(encode_cell(longitude_index, latitude_index, precision));
+		}
+	}
+	Some(geohashes)
+}
+
+/// Returns the cosine of the latitude, scaled by `COS_SCALE`.
+fn latitude_cosine(latitude: i32) -> i128 {
+	let degrees = (latitude.unsigned_abs() / MICRO_DEGREES as u32).min(90);
+	COS_TABLE[degrees as usize] as i128
+}
+
+/// Converts a distance in meters to millionths of a degree of latitude.
+fn meters_to_micro_degrees(meters: u32) -> i128 {
+	meters as i128 * MICRO_DEGREES as i128 / METERS_PER_DEGREE as i128
+}
+
+/// Get the bounding box that contains the circle of a given radius.
+///
+/// The longitude span is widened by the cosine of the latitude of the center. Circles crossing
+/// the antimeridian are clamped to it.
+///
+/// # Arguments
+///
+/// * `center` - The center of the circle.
+/// * `radius` - The radius of the circle, in meters.
+///
+/// # Returns
+///
+/// Returns the `BoundingBox` containing the circle.
+pub fn bounding_box_around(center: &Coordinates, radius: u32) -> BoundingBox {
+	let latitude_delta = meters_to_micro_degrees(radius);
+	let cosine = latitude_cosine(center.latitude);
+	let longitude_delta =
+		if cosine == 0 { LONGITUDE_RANGE as i128 } else { latitude_delta * COS_SCALE / cosine };
+
+	let clamp = |value: i128, limit: i64| value.clamp(-(limit as i128), limit as i128) as i32;
+	BoundingBox {
+		south_west: Coordinates {
+			latitude: clamp(center.latitude as i128 - latitude_delta, LATITUDE_RANGE / 2),
+			longitude: clamp(center.longitude as i128 - longitude_delta, LONGITUDE_RANGE / 2),
+		},
+		north_east: Coordinates {
+			latitude: clamp(center.latitude as i128 + latitude_delta, LATITUDE_RANGE / 2),
+			longitude: clamp(center.longitude as i128 + longitude_delta, LONGITUDE_RANGE / 2),
+		},
+	}
+}
+
+/// Checks whether a point lies within a given radius of a center.
+///
+/// The distance is approximated with an equirectangular projection, which is accurate enough
+/// for the distances used when searching places.
+///
+/// # Arguments
+///
+/// * `center` - The center of the circle.
+/// * `point` - The point to check.
+/// * `radius` - The radius of the circle, in meters.
+///
+/// # Returns
+///
+/// Returns `true` if the point lies within the circle, `false` otherwise.
+pub fn is_within_radius(center: &Coordinates, point: &Coordinates, radius: u32) -> bool {
+	let latitude_distance = point.latitude as i128 - center.latitude as i128;
+	let longitude_distance = (point.longitude as i128 - center.longitude as i128) *
+		latitude_cosine(center.latitude) /
+		COS_SCALE;
+	let radius = meters_to_micro_degrees(radius);
+
+	latitude_distance * latitude_distance + longitude_distance * longitude_distance <=
+		radius * radius
+}
@@ -1,5 +1,8 @@
-use crate::{Bytes, Config, PlaceType};
-use frame_support::sp_std::prelude::*;
+use crate::{
+	Bytes, Config, ContentStatus, Coordinates, InspectionBalanceOf, NoShowPolicy, PaymentSchedule,
+	Permissions, PlaceAttributes, PlaceRole, PlaceType, VerificationLevel,
+};
+use frame_support::{sp_runtime::Perbill, sp_std::prelude::*};
 
 /// Interface for Places pallet
 pub trait PlacesInterface<T: Config> {
@@ -15,13 +18,18 @@ pub trait PlacesInterface<T: Config> {
 	///
 	/// * `place_type` - The type of the Place, e.g., Hotel, Apartment, etc.
 	/// * `name` - The name of the Place.
-	/// * `address` - The address of the Place.
+	/// * `location` - The coarse public location of the Place, such as a city or a geohash prefix.
+	/// * `coordinates` - The latitude and longitude of the Place, in millionths of a degree. Only
+	///   its geohash cell is stored.
 	/// * `description` - A hash of the description of the Place (stored separately).
 	/// * `price_per_night` - The price per night for booking the Place.
 	/// * `checkin_hour` - The hour when guests can check-in (in 24-hour format).
 	/// * `checkout_hour` - The hour when guests must check-out (in 24-hour format).
+	/// * `utc_offset_minutes` - The offset of the local time of the Place from UTC, in minutes. The
+	///   checkin and checkout hours are expressed in this local time.
 	/// * `images` - A list of hashes representing images of the Place (stored separately).
 	/// * `number_of_floors` - An optional field indicating the number of floors in the Place.
+	/// * `attributes` - The amenities and house rules of the Place.
 	/// * `sender` - The account identifier of the sender creating the Place.
 	///
 	/// # Returns
@@ -32,22 +40,26 @@ pub trait PlacesInterface<T: Config> {
 	fn _create_place(
 		place_type: PlaceType,
 		name: Bytes,
-		address: Bytes,
+		location: Bytes,
+		coordinates: Coordinates,
 		description: T::Hash,
 		price_per_night: u64,
 		checkin_hour: u32,
 		checkout_hour: u32,
+		utc_offset_minutes: i16,
 		images: Vec<T::Hash>,
 		number_of_floors: Option<u8>,
+		attributes: PlaceAttributes,
 		sender: &T::AccountId,
 	) -> Result<T::Hash, Self::Error>;
 
 	/// Update an existing Place's information.
 	///
 	/// This function updates the information of an existing Place with the specified `place_id`.
-	/// Any of the provided optional fields (`place_type`, `name`, `address`, `description`,
-	/// `price_per_night`, `checkin_hour`, `checkout_hour`, `images`, `number_of_floors`) can be set
-	/// to `None` to indicate that the corresponding attribute should remain unchanged.
+	/// Any of the provided optional fields (`place_type`, `name`, `location`, `coordinates`,
+	/// `description`, `price_per_night`, `checkin_hour`, `checkout_hour`, `utc_offset_minutes`,
+	/// `images`, `number_of_floors`, `attributes`) can be set to `None` to indicate that the
+	/// corresponding attribute should remain unchanged.
 	/// The `sender` account identifier must have the necessary permissions to update the Place.
 	/// The function returns a unique identifier (`Hash`) for the updated Place.
 	///
@@ -56,16 +68,21 @@ pub trait PlacesInterface<T: Config> {
 	/// * `place_id` - The identifier of the Place to update.
 	/// * `place_type` - An optional new type of the Place (if provided).
 	/// * `name` - An optional new name of the Place (if provided).
-	/// * `address` - An optional new address of the Place (if provided).
+	/// * `location` - An optional new coarse location of the Place (if provided).
+	/// * `coordinates` - An optional new latitude and longitude of the Place (if provided).
 	/// * `description` - An optional new hash of the description of the Place (if provided).
 	/// * `price_per_night` - An optional new price per night for booking the Place (if provided).
 	/// * `checkin_hour` - An optional new hour when guests can check-in (in 24-hour format, if
 	///   provided).
 	/// * `checkout_hour` - An optional new hour when guests must check-out (in 24-hour format, if
 	///   provided).
+	/// * `utc_offset_minutes` - An optional new offset of the local time of the Place from UTC, in
+	///   minutes (if provided).
 	/// * `images` - An optional new list of hashes representing images of the Place (if provided).
 	/// * `number_of_floors` - An optional new field indicating the number of floors in the Place
 	///   (if provided).
+	/// * `attributes` - An optional new set of amenities and house rules, replacing the current
+	///   ones (if provided).
 	/// * `sender` - The account identifier of the sender updating the Place.
 	///
 	/// # Returns
@@ -77,13 +94,16 @@ pub trait PlacesInterface<T: Config> {
 		place_id: &T::Hash,
 		place_type: Option<PlaceType>,
 		name: Option<Bytes>,
-		address: Option<Bytes>,
+		location: Option<Bytes>,
+		coordinates: Option<Coordinates>,
 		description: Option<T::Hash>,
 		price_per_night: Option<u64>,
 		checkin_hour: Option<u32>,
 		checkout_hour: Option<u32>,
+		utc_offset_minutes: Option<i16>,
 		images: Option<Vec<T::Hash>>,
 		number_of_floors: Option<u8>,
+		attributes: Option<PlaceAttributes>,
 		sender: &T::AccountId,
 	) -> Result<T::Hash, Self::Error>;
 
@@ -96,11 +116,300 @@ pub trait PlacesInterface<T: Config> {
 	/// # Arguments
 	///
 	/// * `place_id` - The identifier of the Place to delete.
+	/// * `sender` - The account removing the Place, which must be its owner.
 	///
 	/// # Returns
 	///
 	/// Returns a `Result` indicating the success or failure of the Place deletion operation. If the
 	/// operation is successful, the `Result` contains the unique identifier (`Hash`) for the
 	/// deleted Place. Otherwise, it contains an error indicating the reason for failure.
-	fn _remove_place(place_id: &T::Hash) -> Result<T::Hash, Self::Error>;
+	fn _remove_place(place_id: &T::Hash, sender: &T::AccountId) -> Result<T::Hash, Self::Error>;
+
+	/// Assign a role to an account in a Place.
+	///
+	/// This function grants `role` to `account` over the Place identified by `place_id`. Only the
+	/// owner of the Place can assign roles. If `permissions` is not provided, the defaults of the
+	/// role are used. The optional `payout_share` defines the percentage of every payout that the
+	/// account receives, the owner keeps the remainder.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the Place.
+	/// * `account` - The account receiving the role.
+	/// * `role` - The role to assign.
+	/// * `permissions` - Optional custom permissions for the account.
+	/// * `payout_share` - Optional share of the payouts for the account.
+	/// * `sender` - The account identifier of the sender assigning the role.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the identifier (`Hash`) of the Place. Otherwise, it
+	/// contains an error indicating the reason for failure.
+	fn _assign_place_role(
+		place_id: &T::Hash,
+		account: T::AccountId,
+		role: PlaceRole,
+		permissions: Option<Permissions>,
+		payout_share: Option<Perbill>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error>;
+
+	/// Revoke the role of an account in a Place.
+	///
+	/// This function removes any role held by `account` over the Place identified by `place_id`.
+	/// Only the owner of the Place can revoke roles.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the Place.
+	/// * `account` - The account losing its role.
+	/// * `sender` - The account identifier of the sender revoking the role.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the identifier (`Hash`) of the Place. Otherwise, it
+	/// contains an error indicating the reason for failure.
+	fn _revoke_place_role(
+		place_id: &T::Hash,
+		account: &T::AccountId,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error>;
+
+	/// Set the payout split of a Place.
+	///
+	/// This function defines the list of beneficiaries of the payouts of the Place identified by
+	/// `place_id`, each one with its share. The shares must add up to 100% and an account cannot
+	/// appear twice. An empty `split` removes the configuration, falling back to the payout shares
+	/// of the roles. Only the owner of the Place can set it.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the Place.
+	/// * `split` - The list of `(account, share)` beneficiaries.
+	/// * `sender` - The account identifier of the sender setting the split.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the identifier (`Hash`) of the Place. Otherwise, it
+	/// contains an error indicating the reason for failure.
+	fn _set_payout_split(
+		place_id: &T::Hash,
+		split: Vec<(T::AccountId, Perbill)>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error>;
+
+	/// Set the payment schedule of a Place.
+	///
+	/// This function defines how the amount of the bookings of the Place identified by `place_id`
+	/// is collected: a share reserved when the booking is created and the remaining balance
+	/// collected a number of days before the checkin. The deposit must be lower than 100%. A
+	/// `None` schedule removes the configuration, so the whole amount is reserved upfront. Only
+	/// the owner of the Place can set it.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the Place.
+	/// * `schedule` - The payment schedule, if any.
+	/// * `sender` - The account identifier of the sender setting the schedule.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the identifier (`Hash`) of the Place. Otherwise, it
+	/// contains an error indicating the reason for failure.
+	fn _set_payment_schedule(
+		place_id: &T::Hash,
+		schedule: Option<PaymentSchedule>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error>;
+
+	/// Set the no-show policy of a Place.
+	///
+	/// This function defines the share of the amount paid that the host keeps when the guest of a
+	/// booking of the Place identified by `place_id` never arrives, and the grace period after the
+	/// checkin hour before the booking can be marked as a no-show. A `None` policy removes the
+	/// configuration, so the default policy applies. Only the owner of the Place can set it.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the Place.
+	/// * `policy` - The no-show policy, if any.
+	/// * `sender` - The account identifier of the sender setting the policy.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the identifier (`Hash`) of the Place. Otherwise, it
+	/// contains an error indicating the reason for failure.
+	fn _set_no_show_policy(
+		place_id: &T::Hash,
+		policy: Option<NoShowPolicy>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error>;
+
+	/// Set the minimum verification level required from the guests of a Place.
+	///
+	/// This function defines the verification level an account must hold to book the Place
+	/// identified by `place_id`. A `None` level, or `Unverified`, removes the requirement. Only the
+	/// owner of the Place can set it.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the Place.
+	/// * `level` - The minimum verification level, if any.
+	/// * `sender` - The account identifier of the sender setting the requirement.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the identifier (`Hash`) of the Place. Otherwise, it
+	/// contains an error indicating the reason for failure.
+	fn _set_guest_verification(
+		place_id: &T::Hash,
+		level: Option<VerificationLevel>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error>;
+
+	/// Approve an inspector.
+	///
+	/// # Arguments
+	///
+	/// * `inspector` - The account identifier of the new inspector.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. It fails if the
+	/// account is already an inspector.
+	fn _add_inspector(inspector: &T::AccountId) -> Result<(), Self::Error>;
+
+	/// Remove an inspector.
+	///
+	/// # Arguments
+	///
+	/// * `inspector` - The account identifier of the inspector.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. It fails if the
+	/// account is not an inspector.
+	fn _remove_inspector(inspector: &T::AccountId) -> Result<(), Self::Error>;
+
+	/// Allow a key to sign the results of the content checks.
+	///
+	/// # Arguments
+	///
+	/// * `checker` - The key of the new content checker.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. It fails if the
+	/// key is already a content checker.
+	fn _add_content_checker(checker: &T::Public) -> Result<(), Self::Error>;
+
+	/// Remove a content checker.
+	///
+	/// # Arguments
+	///
+	/// * `checker` - The key of the content checker.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. It fails if the
+	/// key is not a content checker.
+	fn _remove_content_checker(checker: &T::Public) -> Result<(), Self::Error>;
+
+	/// Request the inspection of a Place.
+	///
+	/// This function reserves the `fee` from the owner of the Place identified by `place_id`.
+	/// The fee stays in escrow until an inspector attests the Place or the request is canceled.
+	/// A Place can only have one pending request.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the Place.
+	/// * `inspector` - The account identifier of the inspector chosen by the owner.
+	/// * `fee` - The fee paid to the inspector.
+	/// * `sender` - The account identifier of the owner requesting the inspection.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the identifier (`Hash`) of the Place. Otherwise, it
+	/// contains an error indicating the reason for failure.
+	fn _request_inspection(
+		place_id: &T::Hash,
+		inspector: &T::AccountId,
+		fee: InspectionBalanceOf<T>,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error>;
+
+	/// Cancel the pending inspection request of a Place, releasing its fee.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the Place.
+	/// * `sender` - The account identifier of the owner canceling the request.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the identifier (`Hash`) of the Place. Otherwise, it
+	/// contains an error indicating the reason for failure.
+	fn _cancel_inspection_request(
+		place_id: &T::Hash,
+		sender: &T::AccountId,
+	) -> Result<T::Hash, Self::Error>;
+
+	/// Attest that a Place exists and matches its listing.
+	///
+	/// This function checks that the `description` and `images` found by the inspector match the
+	/// ones of the Place, and stores the attestation until it expires after the
+	/// `InspectionValidity`. If the Place has a pending inspection request for this inspector, it
+	/// claims its fee. Requests for other inspectors are left pending.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the Place.
+	/// * `description` - The description hash found by the inspector.
+	/// * `images` - The image hashes found by the inspector.
+	/// * `sender` - The account identifier of the inspector.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the moment the attestation expires. Otherwise, it
+	/// contains an error indicating the reason for failure.
+	fn _attest_place(
+		place_id: &T::Hash,
+		description: T::Hash,
+		images: Vec<T::Hash>,
+		sender: &T::AccountId,
+	) -> Result<T::Moment, Self::Error>;
+
+	/// Store the result of the off-chain check of the content referenced by a Place.
+	///
+	/// This function checks that the `content` checked by the off-chain worker is still the
+	/// current description and images of the Place, and stores its status.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The identifier of the Place.
+	/// * `content` - The hash of the description and images checked.
+	/// * `status` - The result of the check.
+	/// * `block_number` - The block at which the content was checked.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains the identifier (`Hash`) of the Place. Otherwise, it
+	/// contains an error indicating the reason for failure.
+	fn _submit_content_status(
+		place_id: &T::Hash,
+		content: T::Hash,
+		status: ContentStatus,
+		block_number: T::BlockNumber,
+	) -> Result<T::Hash, Self::Error>;
 }
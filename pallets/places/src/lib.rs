@@ -3,31 +3,138 @@
 pub use pallet::*;
 
 pub mod functions;
+pub mod geohash;
 pub mod interface;
+pub mod migrations;
+pub mod offchain;
+pub mod runtime_api;
 pub mod structures;
+pub mod traits;
 
 pub use pallet_timestamp;
 
+use sp_core::crypto::KeyTypeId;
+
+/// Key type of the accounts used by the off-chain worker to sign the results of the content
+/// checks.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"plcs");
+
+/// Crypto of the keys used by the off-chain worker. Only the results signed by the keys of the
+/// content checkers are accepted.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::Signature as Sr25519Signature;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	pub struct ContentAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for ContentAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+
+	impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+		for ContentAuthId
+	{
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
-	use crate::{interface::PlacesInterface, structures::PlaceData};
+	use crate::{
+		interface::PlacesInterface,
+		structures::PlaceData,
+		traits::{ActiveBookings, ListingBond, Verification},
+	};
 
 	use super::*;
-	use frame_support::{pallet_prelude::*, sp_std::prelude::*};
-	use frame_system::pallet_prelude::*;
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::Perbill,
+		sp_std::prelude::*,
+		traits::{Currency, ReservableCurrency},
+	};
+	use frame_system::{
+		offchain::{AppCrypto, SendTransactionTypes, SignedPayload, SigningTypes},
+		pallet_prelude::*,
+	};
+
+	pub type InspectionBalanceOf<T> = <<T as Config>::InspectionCurrency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
 	pub type Bytes = Vec<u8>;
 
 	pub use structures::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info] // This allows us to use unsafe storages, at some point we might need bounded storages
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config + pallet_timestamp::Config {
+	pub trait Config:
+		frame_system::Config
+		+ pallet_timestamp::Config
+		+ SendTransactionTypes<Call<Self>>
+		+ SigningTypes
+	{
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The maximum number of beneficiaries in the payout split of a place.
+		#[pallet::constant]
+		type MaxPayoutBeneficiaries: Get<u32>;
+
+		/// The maximum number of house rules of a place.
+		#[pallet::constant]
+		type MaxHouseRules: Get<u32>;
+
+		/// The maximum length of the key and of the value of a house rule.
+		#[pallet::constant]
+		type MaxHouseRuleLength: Get<u32>;
+
+		/// The bond locked by the hosts for every listing they create.
+		type ListingBond: ListingBond<Self::AccountId>;
+
+		/// The verification judgements of hosts and guests.
+		type Verification: Verification<Self::AccountId>;
+
+		/// The bookings of the places, which must be finished before removing them.
+		type ActiveBookings: ActiveBookings<Self::Hash>;
+
+		/// The currency in which the owners pay the inspection of their places.
+		type InspectionCurrency: ReservableCurrency<Self::AccountId>;
+
+		/// The origin allowed to approve and remove inspectors and content checkers.
+		type InspectorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The time, in milliseconds, the attestation of an inspector remains valid.
+		#[pallet::constant]
+		type InspectionValidity: Get<Self::Moment>;
+
+		/// The keys used by the off-chain worker to sign the results of the content checks.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// The priority of the unsigned transactions submitted by the off-chain worker.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// The maximum number of places whose content the off-chain worker checks per block.
+		#[pallet::constant]
+		type MaxContentChecks: Get<u32>;
 	}
 
 	// The pallet's runtime storage items.
@@ -40,6 +147,70 @@ pub mod pallet {
 	#[pallet::getter(fn get_place_by_id)]
 	pub type PlacesData<T: Config> = StorageMap<_, Twox64Concat, T::Hash, PlaceData<T>>;
 
+	/// Stores the places located in every geohash cell, using `GEOHASH_INDEX_PRECISION`
+	/// characters.
+	#[pallet::storage]
+	#[pallet::getter(fn get_places_by_geohash)]
+	pub type PlacesByGeohash<T: Config> =
+		StorageMap<_, Twox64Concat, Bytes, Vec<T::Hash>, ValueQuery>;
+
+	/// Stores the roles that accounts other than the owner hold in a place.
+	#[pallet::storage]
+	#[pallet::getter(fn get_place_role)]
+	pub type PlaceRoles<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, T::AccountId, PlaceRoleData>;
+
+	/// Stores the payout split of a place. When set, it takes precedence over the payout shares
+	/// of the roles. The first beneficiary receives any rounding dust.
+	#[pallet::storage]
+	#[pallet::getter(fn get_payout_split)]
+	pub type PayoutSplits<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, Vec<(T::AccountId, Perbill)>, ValueQuery>;
+
+	/// Stores the payment schedule of a place. Bookings of places without one reserve the whole
+	/// amount upfront.
+	#[pallet::storage]
+	#[pallet::getter(fn get_payment_schedule)]
+	pub type PaymentSchedules<T: Config> = StorageMap<_, Twox64Concat, T::Hash, PaymentSchedule>;
+
+	/// Stores the no-show policy of a place. Places without one apply the default policy.
+	#[pallet::storage]
+	#[pallet::getter(fn get_no_show_policy)]
+	pub type NoShowPolicies<T: Config> = StorageMap<_, Twox64Concat, T::Hash, NoShowPolicy>;
+
+	/// Stores the minimum verification level a place requires from its guests. Places without
+	/// requirement accept unverified guests.
+	#[pallet::storage]
+	#[pallet::getter(fn get_guest_verification)]
+	pub type GuestVerifications<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, VerificationLevel>;
+
+	/// Stores the accounts approved to inspect places.
+	#[pallet::storage]
+	#[pallet::getter(fn get_inspectors)]
+	pub type Inspectors<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+	/// Stores the pending inspection request of every place.
+	#[pallet::storage]
+	#[pallet::getter(fn get_inspection_request)]
+	pub type InspectionRequests<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::Hash,
+		InspectionRequest<T::AccountId, InspectionBalanceOf<T>>,
+	>;
+
+	/// Stores the keys allowed to sign the results of the content checks.
+	#[pallet::storage]
+	#[pallet::getter(fn get_content_checkers)]
+	pub type ContentCheckers<T: Config> = StorageValue<_, Vec<T::Public>, ValueQuery>;
+
+	/// Stores the block of the last content status accepted for a place. Only newer results are
+	/// accepted.
+	#[pallet::storage]
+	#[pallet::getter(fn get_content_checked_at)]
+	pub type ContentCheckedAt<T: Config> = StorageMap<_, Twox64Concat, T::Hash, T::BlockNumber>;
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
@@ -58,6 +229,82 @@ pub mod pallet {
 			id: T::Hash,
 			sender: T::AccountId,
 		},
+		/// A role has been assigned to an account in a Place
+		PlaceRoleAssigned {
+			id: T::Hash,
+			account: T::AccountId,
+			role: PlaceRole,
+		},
+		/// A role has been revoked from an account in a Place
+		PlaceRoleRevoked {
+			id: T::Hash,
+			account: T::AccountId,
+		},
+		/// The payout split of a Place has been updated
+		PayoutSplitUpdated {
+			id: T::Hash,
+			sender: T::AccountId,
+		},
+		/// The payment schedule of a Place has been updated
+		PaymentScheduleUpdated {
+			id: T::Hash,
+			sender: T::AccountId,
+		},
+		/// The no-show policy of a Place has been updated
+		NoShowPolicyUpdated {
+			id: T::Hash,
+			sender: T::AccountId,
+		},
+		/// The verification level required from the guests of a Place has been updated
+		GuestVerificationUpdated {
+			id: T::Hash,
+			sender: T::AccountId,
+		},
+		/// An account has been approved to inspect places
+		InspectorAdded {
+			inspector: T::AccountId,
+		},
+		/// An inspector has been removed
+		InspectorRemoved {
+			inspector: T::AccountId,
+		},
+		/// The owner of a Place has requested its inspection, reserving the fee
+		InspectionRequested {
+			id: T::Hash,
+			sender: T::AccountId,
+			inspector: T::AccountId,
+			fee: InspectionBalanceOf<T>,
+		},
+		/// The owner of a Place has canceled its inspection request, and the fee is released
+		InspectionRequestCanceled {
+			id: T::Hash,
+			sender: T::AccountId,
+		},
+		/// An inspector has attested that a Place exists and matches its listing
+		PlaceAttested {
+			id: T::Hash,
+			inspector: T::AccountId,
+			expires_at: T::Moment,
+		},
+		/// The inspector of a Place has claimed the fee of its inspection request
+		InspectionFeeClaimed {
+			id: T::Hash,
+			inspector: T::AccountId,
+			fee: InspectionBalanceOf<T>,
+		},
+		/// The content referenced by a Place has been checked by the off-chain worker
+		ContentChecked {
+			id: T::Hash,
+			status: ContentStatus,
+		},
+		/// A key has been allowed to sign the results of the content checks
+		ContentCheckerAdded {
+			checker: T::Public,
+		},
+		/// A key is no longer allowed to sign the results of the content checks
+		ContentCheckerRemoved {
+			checker: T::Public,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -73,6 +320,69 @@ pub mod pallet {
 		BadHoursProvided,
 		/// Checkout hour cannot be greater than checkin hour
 		CheckoutHourCannotBeGreaterThanCheckinHour,
+		/// The UTC offset is out of range or not a multiple of 15 minutes
+		InvalidUtcOffset,
+		/// Latitude or longitude out of range
+		InvalidCoordinates,
+		/// The place has more house rules than allowed
+		TooManyHouseRules,
+		/// The key or the value of a house rule is longer than allowed
+		HouseRuleTooLong,
+		/// Not Place Owner
+		NotPlaceOwner,
+		/// The place cannot be removed while it has active bookings
+		PlaceHasActiveBookings,
+		/// The account lacks the permission required for the action
+		MissingPermission,
+		/// The owner cannot be assigned a role in its own place
+		CannotAssignRoleToOwner,
+		/// Role not found for the account
+		RoleNotFound,
+		/// The sum of the payout shares cannot exceed 100%
+		PayoutSharesExceedTotal,
+		/// The shares of a payout split must add up to 100%
+		InvalidPayoutSplit,
+		/// An account appears more than once in the payout split
+		DuplicatedPayoutBeneficiary,
+		/// The payout split exceeds the maximum number of beneficiaries
+		TooManyPayoutBeneficiaries,
+		/// The deposit of a payment schedule must be lower than 100%
+		InvalidPaymentSchedule,
+		/// The account is already an inspector
+		AlreadyInspector,
+		/// The account is not an inspector
+		NotInspector,
+		/// Inspectors cannot attest their own places
+		CannotInspectOwnPlace,
+		/// The description or the images attested do not match the listing
+		InspectionMismatch,
+		/// The place already has a pending inspection request
+		InspectionAlreadyRequested,
+		/// The place has no pending inspection request
+		InspectionNotRequested,
+		/// The owner cannot afford the inspection fee
+		InsufficientBalanceForInspection,
+		/// The description or the images changed since the content was checked
+		ContentChanged,
+		/// The content status is older than the last one accepted or no longer valid
+		StaleContentStatus,
+		/// The key is already a content checker
+		AlreadyContentChecker,
+		/// The key is not a content checker
+		NotContentChecker,
+		/// The search area needs too many geohash cells to be covered
+		SearchAreaTooLarge,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+		}
+
+		fn offchain_worker(block_number: T::BlockNumber) {
+			Self::check_places_content(block_number);
+		}
 	}
 
 	#[pallet::call]
@@ -81,37 +391,50 @@ pub mod pallet {
 		///
 		/// * `place_type` - The PlaceType
 		/// * `name` - Name for the Place, initially `Bytes`
-		/// * `address` - Location of the Place
+		/// * `location` - Coarse public location of the Place, such as a city or a geohash prefix
+		/// * `coordinates` - Latitude and longitude of the Place, in millionths of a degree. Only
+		///   its geohash cell is stored
 		/// * `description` - Hash reference of the Place description
 		/// * `price_per_night` - Price per night of the Place
+		/// * `utc_offset_minutes` - Offset of the local time of the Place from UTC, in minutes
 		/// * `images` - List of images from the place, hash references
 		/// * `number_of_floors` - Number of floors, in case the Place has more than one
+		/// * `attributes` - Amenities and house rules of the Place
 		#[pallet::call_index(1)]
 		pub fn create_place(
 			origin: OriginFor<T>,
 			place_type: PlaceType,
 			name: Bytes,
-			address: Bytes,
+			location: Bytes,
+			coordinates: Coordinates,
 			description: T::Hash,
 			price_per_night: u64,
 			checkin_hour: u32,
 			checkout_hour: u32,
+			utc_offset_minutes: i16,
 			images: Vec<T::Hash>,
 			number_of_floors: Option<u8>,
+			attributes: PlaceAttributes,
 		) -> DispatchResult {
 			// Check sender
 			let sender = ensure_signed(origin)?;
 
+			// The host must afford the bond of one more listing
+			T::ListingBond::lock_listing_bond(&sender)?;
+
 			let place_id = Self::_create_place(
 				place_type,
 				name,
-				address,
+				location,
+				coordinates,
 				description,
 				price_per_night,
 				checkin_hour,
 				checkout_hour,
+				utc_offset_minutes,
 				images,
 				number_of_floors,
+				attributes,
 				&sender,
 			)?;
 
@@ -125,24 +448,31 @@ pub mod pallet {
 		/// * `place_id` - The Place identifier
 		/// * `place_type` - The PlaceType
 		/// * `name` - Name for the Place, initially `Bytes`
-		/// * `address` - Location of the Place
+		/// * `location` - Coarse public location of the Place, such as a city or a geohash prefix
+		/// * `coordinates` - Latitude and longitude of the Place, in millionths of a degree. Only
+		///   its geohash cell is stored
 		/// * `description` - Hash reference of the Place description
 		/// * `price_per_night` - Price per night of the Place
+		/// * `utc_offset_minutes` - Offset of the local time of the Place from UTC, in minutes
 		/// * `images` - List of images from the place, hash references
 		/// * `number_of_floors` - Number of floors, in case the Place has more than one
+		/// * `attributes` - Amenities and house rules of the Place, replacing the current ones
 		#[pallet::call_index(2)]
 		pub fn update_place(
 			origin: OriginFor<T>,
 			place_id: T::Hash,
 			place_type: Option<PlaceType>,
 			name: Option<Bytes>,
-			address: Option<Bytes>,
+			location: Option<Bytes>,
+			coordinates: Option<Coordinates>,
 			description: Option<T::Hash>,
 			price_per_night: Option<u64>,
 			checkin_hour: Option<u32>,
 			checkout_hour: Option<u32>,
+			utc_offset_minutes: Option<i16>,
 			images: Option<Vec<T::Hash>>,
 			number_of_floors: Option<u8>,
+			attributes: Option<PlaceAttributes>,
 		) -> DispatchResult {
 			// Check sender
 			let sender = ensure_signed(origin)?;
@@ -151,13 +481,16 @@ pub mod pallet {
 				&place_id,
 				place_type,
 				name,
-				address,
+				location,
+				coordinates,
 				description,
 				price_per_night,
 				checkin_hour,
 				checkout_hour,
+				utc_offset_minutes,
 				images,
 				number_of_floors,
+				attributes,
 				&sender,
 			)?;
 
@@ -166,7 +499,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Extrinsic to remove a Place.
+		/// Extrinsic to remove a Place. It fails while the Place has active bookings.
 		///
 		/// * `place_id` - The Place identifier
 		#[pallet::call_index(3)]
@@ -174,11 +507,346 @@ pub mod pallet {
 			// Check sender
 			let sender = ensure_signed(origin)?;
 
-			Self::_remove_place(&place_id)?;
+			Self::_remove_place(&place_id, &sender)?;
+			T::ListingBond::release_listing_bond(&sender);
 
 			// Deposit our "Removed" event.
 			Self::deposit_event(Event::PlaceRemoved { id: place_id, sender });
 			Ok(())
 		}
+
+		/// Extrinsic to assign a role to an account in a Place. Only the owner can call it.
+		///
+		/// * `place_id` - The Place identifier
+		/// * `account` - The account receiving the role
+		/// * `role` - The PlaceRole to assign
+		/// * `permissions` - Custom permissions, the role defaults are used if not provided
+		/// * `payout_share` - Share of the payouts that goes to the account, none by default
+		#[pallet::call_index(4)]
+		pub fn assign_place_role(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+			account: T::AccountId,
+			role: PlaceRole,
+			permissions: Option<Permissions>,
+			payout_share: Option<Perbill>,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_assign_place_role(
+				&place_id,
+				account.clone(),
+				role.clone(),
+				permissions,
+				payout_share,
+				&sender,
+			)?;
+
+			// Deposit our "RoleAssigned" event.
+			Self::deposit_event(Event::PlaceRoleAssigned { id: place_id, account, role });
+			Ok(())
+		}
+
+		/// Extrinsic to revoke the role of an account in a Place. Only the owner can call it.
+		///
+		/// * `place_id` - The Place identifier
+		/// * `account` - The account losing its role
+		#[pallet::call_index(5)]
+		pub fn revoke_place_role(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+			account: T::AccountId,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_revoke_place_role(&place_id, &account, &sender)?;
+
+			// Deposit our "RoleRevoked" event.
+			Self::deposit_event(Event::PlaceRoleRevoked { id: place_id, account });
+			Ok(())
+		}
+
+		/// Extrinsic to set how the payouts of a Place are split. Only the owner can call it.
+		///
+		/// * `place_id` - The Place identifier
+		/// * `split` - List of beneficiaries and their shares, which must add up to 100%. An empty
+		///   list removes the split
+		#[pallet::call_index(6)]
+		pub fn set_payout_split(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+			split: Vec<(T::AccountId, Perbill)>,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_set_payout_split(&place_id, split, &sender)?;
+
+			// Deposit our "PayoutSplitUpdated" event.
+			Self::deposit_event(Event::PayoutSplitUpdated { id: place_id, sender });
+			Ok(())
+		}
+
+		/// Extrinsic to set the payment schedule of the bookings of a Place. Only the owner can
+		/// call it.
+		///
+		/// * `place_id` - The Place identifier
+		/// * `schedule` - The share reserved as a deposit and the number of days before the checkin
+		///   at which the remaining balance is collected. `None` removes the schedule
+		#[pallet::call_index(7)]
+		pub fn set_payment_schedule(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+			schedule: Option<PaymentSchedule>,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_set_payment_schedule(&place_id, schedule, &sender)?;
+
+			// Deposit our "PaymentScheduleUpdated" event.
+			Self::deposit_event(Event::PaymentScheduleUpdated { id: place_id, sender });
+			Ok(())
+		}
+
+		/// Extrinsic to set the policy applied to the bookings of a Place whose guests never
+		/// arrive. Only the owner can call it.
+		///
+		/// * `place_id` - The Place identifier
+		/// * `policy` - The share of the amount kept by the host and the grace period after the
+		///   checkin hour. `None` restores the default policy
+		#[pallet::call_index(8)]
+		pub fn set_no_show_policy(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+			policy: Option<NoShowPolicy>,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_set_no_show_policy(&place_id, policy, &sender)?;
+
+			// Deposit our "NoShowPolicyUpdated" event.
+			Self::deposit_event(Event::NoShowPolicyUpdated { id: place_id, sender });
+			Ok(())
+		}
+
+		/// Extrinsic to set the minimum verification level required from the guests of a Place.
+		/// Only the owner can call it.
+		///
+		/// * `place_id` - The Place identifier
+		/// * `level` - The minimum verification level. `None` accepts unverified guests
+		#[pallet::call_index(9)]
+		pub fn set_guest_verification(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+			level: Option<VerificationLevel>,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_set_guest_verification(&place_id, level, &sender)?;
+
+			// Deposit our "GuestVerificationUpdated" event.
+			Self::deposit_event(Event::GuestVerificationUpdated { id: place_id, sender });
+			Ok(())
+		}
+
+		/// Extrinsic to approve an inspector. Only the `InspectorOrigin` can call it.
+		///
+		/// * `inspector` - The account approved to inspect places
+		#[pallet::call_index(10)]
+		pub fn add_inspector(origin: OriginFor<T>, inspector: T::AccountId) -> DispatchResult {
+			// Check origin
+			T::InspectorOrigin::ensure_origin(origin)?;
+
+			Self::_add_inspector(&inspector)?;
+
+			// Deposit our "InspectorAdded" event.
+			Self::deposit_event(Event::InspectorAdded { inspector });
+			Ok(())
+		}
+
+		/// Extrinsic to remove an inspector. Only the `InspectorOrigin` can call it. The places
+		/// it attested keep their attestation until it expires.
+		///
+		/// * `inspector` - The inspector to remove
+		#[pallet::call_index(11)]
+		pub fn remove_inspector(origin: OriginFor<T>, inspector: T::AccountId) -> DispatchResult {
+			// Check origin
+			T::InspectorOrigin::ensure_origin(origin)?;
+
+			Self::_remove_inspector(&inspector)?;
+
+			// Deposit our "InspectorRemoved" event.
+			Self::deposit_event(Event::InspectorRemoved { inspector });
+			Ok(())
+		}
+
+		/// Extrinsic to request the inspection of a Place. Only the owner can call it. The fee is
+		/// reserved in escrow until the chosen inspector attests the Place.
+		///
+		/// * `place_id` - The Place identifier
+		/// * `inspector` - The approved inspector that can claim the fee
+		/// * `fee` - The fee paid to the inspector
+		#[pallet::call_index(12)]
+		pub fn request_inspection(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+			inspector: T::AccountId,
+			fee: InspectionBalanceOf<T>,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_request_inspection(&place_id, &inspector, fee, &sender)?;
+
+			// Deposit our "InspectionRequested" event.
+			Self::deposit_event(Event::InspectionRequested {
+				id: place_id,
+				sender,
+				inspector,
+				fee,
+			});
+			Ok(())
+		}
+
+		/// Extrinsic to cancel the pending inspection request of a Place, releasing the fee. Only
+		/// the owner can call it.
+		///
+		/// * `place_id` - The Place identifier
+		#[pallet::call_index(13)]
+		pub fn cancel_inspection_request(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			Self::_cancel_inspection_request(&place_id, &sender)?;
+
+			// Deposit our "InspectionRequestCanceled" event.
+			Self::deposit_event(Event::InspectionRequestCanceled { id: place_id, sender });
+			Ok(())
+		}
+
+		/// Extrinsic to attest that a Place exists and matches its description and images. Only
+		/// inspectors can call it, and they claim the fee of the pending inspection request, if
+		/// the owner chose them.
+		///
+		/// * `place_id` - The Place identifier
+		/// * `description` - Hash reference of the description found by the inspector
+		/// * `images` - Hash references of the images found by the inspector
+		#[pallet::call_index(14)]
+		pub fn attest_place(
+			origin: OriginFor<T>,
+			place_id: T::Hash,
+			description: T::Hash,
+			images: Vec<T::Hash>,
+		) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+
+			let expires_at = Self::_attest_place(&place_id, description, images, &sender)?;
+
+			// Deposit our "PlaceAttested" event.
+			Self::deposit_event(Event::PlaceAttested {
+				id: place_id,
+				inspector: sender,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Unsigned extrinsic used by the off-chain worker to store the result of checking the
+		/// content referenced by a Place. The result must be signed by a content checker, which
+		/// is verified when validating the transaction. Results older than the last one accepted
+		/// for the Place, or than `CONTENT_STATUS_LONGEVITY` blocks, are rejected.
+		///
+		/// * `payload` - The result of the check and the key of the content checker
+		/// * `_signature` - The signature of the payload
+		#[pallet::call_index(15)]
+		pub fn submit_content_status(
+			origin: OriginFor<T>,
+			payload: ContentStatusPayload<T::Public, T::Hash, T::BlockNumber>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			// Only the off-chain worker submits the content status
+			ensure_none(origin)?;
+
+			let ContentStatusPayload { place_id, content, status, block_number, .. } = payload;
+			Self::_submit_content_status(&place_id, content, status, block_number)?;
+
+			// Deposit our "ContentChecked" event.
+			Self::deposit_event(Event::ContentChecked { id: place_id, status });
+			Ok(())
+		}
+
+		/// Extrinsic to allow a key to sign the results of the content checks. Only the
+		/// `InspectorOrigin` can call it.
+		///
+		/// * `checker` - The key of the content checker
+		#[pallet::call_index(16)]
+		pub fn add_content_checker(origin: OriginFor<T>, checker: T::Public) -> DispatchResult {
+			// Check origin
+			T::InspectorOrigin::ensure_origin(origin)?;
+
+			Self::_add_content_checker(&checker)?;
+
+			// Deposit our "ContentCheckerAdded" event.
+			Self::deposit_event(Event::ContentCheckerAdded { checker });
+			Ok(())
+		}
+
+		/// Extrinsic to remove a content checker. Only the `InspectorOrigin` can call it.
+		///
+		/// * `checker` - The key of the content checker
+		#[pallet::call_index(17)]
+		pub fn remove_content_checker(origin: OriginFor<T>, checker: T::Public) -> DispatchResult {
+			// Check origin
+			T::InspectorOrigin::ensure_origin(origin)?;
+
+			Self::_remove_content_checker(&checker)?;
+
+			// Deposit our "ContentCheckerRemoved" event.
+			Self::deposit_event(Event::ContentCheckerRemoved { checker });
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::submit_content_status { payload, signature } = call else {
+				return InvalidTransaction::Call.into()
+			};
+
+			// Only accept the results signed by a content checker
+			if !Self::get_content_checkers().contains(&payload.public) {
+				return InvalidTransaction::BadSigner.into()
+			}
+			if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+				return InvalidTransaction::BadProof.into()
+			}
+
+			let place_data =
+				Self::get_place_by_id(payload.place_id).ok_or(InvalidTransaction::Stale)?;
+			if Self::content_hash(&place_data) != payload.content ||
+				!Self::is_fresh_content_status(&payload.place_id, payload.block_number)
+			{
+				return InvalidTransaction::Stale.into()
+			}
+
+			ValidTransaction::with_tag_prefix("PlacesContent")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((payload.place_id, payload.content, payload.block_number))
+				.longevity(crate::offchain::CONTENT_STATUS_LONGEVITY.into())
+				.propagate(true)
+				.build()
+		}
 	}
 }
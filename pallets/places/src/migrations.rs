@@ -0,0 +1,80 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{
+	AuditTrail, Bytes, Config, ContentStatus, Pallet, PlaceAttributes, PlaceData, PlaceType,
+	PlacesData,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	sp_std::{collections::btree_set::BTreeSet, prelude::*},
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Migration from the first layout of the places, which stored their plaintext address.
+pub mod v1 {
+	use super::*;
+
+	/// The data of a place before the storage version 1.
+	#[derive(Encode, Decode)]
+	pub struct OldPlaceData<T: Config> {
+		pub place_type: PlaceType,
+		pub name: Bytes,
+		pub address: Bytes,
+		pub description: T::Hash,
+		pub price_per_night: u64,
+		pub checkin_hour: u32,
+		pub checkout_hour: u32,
+		pub active: bool,
+		pub images: BTreeSet<T::Hash>,
+		pub number_of_floors: u8,
+		pub owner: T::AccountId,
+		pub on_chain_creation: AuditTrail<T>,
+		pub on_chain_update: Option<AuditTrail<T>>,
+	}
+
+	/// Translate the places to the storage version 1.
+	///
+	/// The plaintext address cannot be coarsened on-chain, so the location of every place is
+	/// cleared and the owners have to set it again. The places are left out of the geohash index
+	/// until their coordinates are set, and the rest of the new fields take their defaults.
+	///
+	/// # Returns
+	///
+	/// Returns the weight consumed by the migration.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated: u64 = 0;
+		<PlacesData<T>>::translate::<OldPlaceData<T>, _>(|_, old| {
+			translated += 1;
+			Some(PlaceData {
+				place_type: old.place_type,
+				name: old.name,
+				location: Bytes::new(),
+				geohash: Bytes::new(),
+				description: old.description,
+				price_per_night: old.price_per_night,
+				checkin_hour: old.checkin_hour,
+				checkout_hour: old.checkout_hour,
+				utc_offset_minutes: 0,
+				active: old.active,
+				images: old.images,
+				number_of_floors: old.number_of_floors,
+				attributes: PlaceAttributes::default(),
+				inspection: None,
+				content_status: ContentStatus::Unchecked,
+				owner: old.owner,
+				on_chain_creation: old.on_chain_creation,
+				on_chain_update: old.on_chain_update,
+			})
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "{} places have been migrated to the storage version 1.", translated);
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
@@ -0,0 +1,164 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{Call, Config, ContentStatus, ContentStatusPayload, Pallet, PlaceData};
+use frame_support::{
+	sp_io::offchain,
+	sp_runtime::{
+		offchain::{http, storage::StorageValueRef, Duration, StorageKind},
+		traits::{Hash, Saturating},
+	},
+	sp_std::{iter, prelude::*, str},
+	traits::Get,
+};
+use frame_system::offchain::{SendUnsignedTransaction, Signer};
+
+/// Key of the offchain local storage holding the URL of the content gateway, such as
+/// `https://ipfs.io/ipfs`. The content referenced by a hash is fetched from `<gateway>/<hash>`.
+/// The content is not checked until the node operator sets it.
+pub const CONTENT_GATEWAY_KEY: &[u8] = b"drenting::places::content-gateway";
+
+/// Prefix of the offchain local storage keys holding the last block at which the content of a
+/// place was checked.
+const CONTENT_CHECK_PREFIX: &[u8] = b"drenting::places::content-check::";
+
+/// Number of blocks to wait before checking the content of the same place again, while the
+/// previous result is not included in a block.
+const CONTENT_CHECK_RETRY_BLOCKS: u32 = 10;
+
+/// Time to wait for the content gateway to answer a request.
+const HTTP_TIMEOUT_MILLIS: u64 = 3_000;
+
+/// Maximum size, in bytes, of a referenced object. Larger objects are not read, and the content
+/// of the place is reported as missing.
+pub const MAX_CONTENT_SIZE: usize = 2 * 1024 * 1024;
+
+/// Number of blocks during which a signed content status can be submitted. Older results are
+/// rejected, so they cannot be replayed.
+pub const CONTENT_STATUS_LONGEVITY: u32 = 5;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+impl<T: Config> Pallet<T> {
+	/// Check the content referenced by the places whose content has not been checked yet.
+	///
+	/// For every place, each referenced object is fetched from the content gateway and its hash
+	/// is compared with the reference. The result is submitted as an unsigned transaction, with
+	/// a payload signed by a local key of a content checker. Nodes without such a key do not
+	/// check the content. At most `MaxContentChecks` places are checked per block.
+	///
+	/// # Arguments
+	///
+	/// * `block_number` - The block at which the off-chain worker runs.
+	pub(crate) fn check_places_content(block_number: T::BlockNumber) {
+		let gateway =
+			match offchain::local_storage_get(StorageKind::PERSISTENT, CONTENT_GATEWAY_KEY) {
+				Some(gateway) => gateway,
+				None => return,
+			};
+		let signer =
+			Signer::<T, T::AuthorityId>::any_account().with_filter(Self::get_content_checkers());
+		if !signer.can_sign() {
+			return
+		}
+
+		let places = Self::get_all_places()
+			.into_iter()
+			.filter_map(|place_id| Self::get_place_by_id(place_id).map(|data| (place_id, data)))
+			.filter(|(_, place_data)| place_data.content_status == ContentStatus::Unchecked)
+			.filter(|(place_id, _)| Self::acquire_content_check(place_id, block_number))
+			.take(T::MaxContentChecks::get() as usize);
+
+		for (place_id, place_data) in places {
+			let status = match Self::check_content(&gateway, &place_data) {
+				Ok(status) => status,
+				Err(error) => {
+					// The gateway could not be reached, the place is checked again later
+					log::debug!(target: "did", "The content of Place with ID ➡ {:?} could not be fetched: {:?}", place_id, error);
+					continue
+				},
+			};
+
+			let content = Self::content_hash(&place_data);
+			let result = signer.send_unsigned_transaction(
+				|account| ContentStatusPayload {
+					place_id,
+					content,
+					status,
+					block_number,
+					public: account.public.clone(),
+				},
+				|payload, signature| Call::submit_content_status { payload, signature },
+			);
+			if !matches!(result, Some((_, Ok(())))) {
+				log::debug!(target: "did", "The content status of Place with ID ➡ {:?} could not be submitted.", place_id);
+			}
+		}
+	}
+
+	/// Check whether every object referenced by a place can be fetched and matches its hash.
+	///
+	/// # Returns
+	///
+	/// Returns the content status of the place, or an error if the gateway could not be reached.
+	fn check_content(
+		gateway: &[u8],
+		place_data: &PlaceData<T>,
+	) -> Result<ContentStatus, http::Error> {
+		for reference in iter::once(&place_data.description).chain(place_data.images.iter()) {
+			match Self::fetch_content(gateway, reference)? {
+				Some(body) if T::Hashing::hash(&body) == *reference => continue,
+				_ => return Ok(ContentStatus::Missing),
+			}
+		}
+		Ok(ContentStatus::Verified)
+	}
+
+	/// Fetch the object referenced by a hash from the content gateway.
+	///
+	/// # Returns
+	///
+	/// Returns the body of the object, `None` if the gateway does not serve it or it exceeds the
+	/// `MAX_CONTENT_SIZE`, or an error if the gateway could not be reached.
+	fn fetch_content(gateway: &[u8], reference: &T::Hash) -> Result<Option<Vec<u8>>, http::Error> {
+		let mut url = gateway.to_vec();
+		url.push(b'/');
+		for byte in reference.as_ref() {
+			url.push(HEX_DIGITS[(byte >> 4) as usize]);
+			url.push(HEX_DIGITS[(byte & 0x0f) as usize]);
+		}
+		let url = str::from_utf8(&url).map_err(|_| http::Error::Unknown)?;
+
+		let deadline = offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MILLIS));
+		let pending = http::Request::get(url)
+			.deadline(deadline)
+			.send()
+			.map_err(|_| http::Error::IoError)?;
+		let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+
+		if response.code != 200 {
+			return Ok(None)
+		}
+		// Read one more byte than allowed to detect the objects that are too large
+		let body: Vec<u8> = response.body().take(MAX_CONTENT_SIZE + 1).collect();
+		if body.len() > MAX_CONTENT_SIZE {
+			return Ok(None)
+		}
+		Ok(Some(body))
+	}
+
+	/// Record that the content of a place is being checked, unless it was checked recently.
+	///
+	/// # Returns
+	///
+	/// Returns `true` if the content of the place can be checked at this block.
+	fn acquire_content_check(place_id: &T::Hash, block_number: T::BlockNumber) -> bool {
+		let key = [CONTENT_CHECK_PREFIX, place_id.as_ref()].concat();
+		let last_check = StorageValueRef::persistent(&key);
+		let retry_blocks = T::BlockNumber::from(CONTENT_CHECK_RETRY_BLOCKS);
+
+		let result = last_check.mutate(|last: Result<Option<T::BlockNumber>, _>| match last {
+			Ok(Some(last)) if block_number < last.saturating_add(retry_blocks) => Err(()),
+			_ => Ok(block_number),
+		});
+		result.is_ok()
+	}
+}
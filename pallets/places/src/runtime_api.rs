@@ -0,0 +1,31 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{BoundingBox, Coordinates, PlaceFilter};
+use codec::Codec;
+use frame_support::{sp_runtime::DispatchError, sp_std::prelude::*};
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to search the places registered in the chain.
+	pub trait PlacesApi<Hash, Date>
+	where
+		Hash: Codec,
+		Date: Codec,
+	{
+		/// Get the places located inside a bounding box that match the filter. Fails if the area
+		/// is too large.
+		fn places_in_bounding_box(
+			bounding_box: BoundingBox,
+			filter: PlaceFilter<Date>,
+		) -> Result<Vec<Hash>, DispatchError>;
+
+		/// Get the places located within `radius` meters of `center` that match the filter. Fails
+		/// if the area is too large.
+		fn places_within_radius(
+			center: Coordinates,
+			radius: u32,
+			filter: PlaceFilter<Date>,
+		) -> Result<Vec<Hash>, DispatchError>;
+
+		/// Check whether the owner of a place is a verified host.
+		fn is_verified_host(place_id: Hash) -> bool;
+	}
+}
@@ -2,8 +2,18 @@
 
 use super::{Bytes, Config};
 use codec::{Decode, Encode};
-use frame_support::sp_std::{collections::btree_set::BTreeSet, prelude::*};
+use frame_support::{
+	sp_runtime::Perbill,
+	sp_std::{
+		collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+		ops::BitOr,
+		prelude::*,
+	},
+};
+use frame_system::offchain::{SignedPayload, SigningTypes};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 // Struct to keep track of chain interactions
 #[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
@@ -25,6 +35,7 @@ impl<T: Config> AuditTrail<T> {
 }
 
 #[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PlaceType {
 	Apartment,
 	House,
@@ -32,6 +43,275 @@ pub enum PlaceType {
 	Boat,
 }
 
+/// Geographic coordinates, expressed in millionths of a degree.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Coordinates {
+	/// Latitude between -90_000_000 and 90_000_000
+	pub latitude: i32,
+	/// Longitude between -180_000_000 and 180_000_000
+	pub longitude: i32,
+}
+
+impl Coordinates {
+	/// Returns `true` if the latitude and the longitude are within their ranges.
+	pub fn is_valid(&self) -> bool {
+		(-90_000_000..=90_000_000).contains(&self.latitude) &&
+			(-180_000_000..=180_000_000).contains(&self.longitude)
+	}
+}
+
+/// Rectangular area delimited by its south-west and north-east corners.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BoundingBox {
+	pub south_west: Coordinates,
+	pub north_east: Coordinates,
+}
+
+impl BoundingBox {
+	/// Returns `true` if the coordinates lie within the area.
+	pub fn contains(&self, coordinates: &Coordinates) -> bool {
+		(self.south_west.latitude..=self.north_east.latitude).contains(&coordinates.latitude) &&
+			(self.south_west.longitude..=self.north_east.longitude)
+				.contains(&coordinates.longitude)
+	}
+}
+
+/// Bitset of the amenities a place offers.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Amenities(pub u32);
+
+impl Amenities {
+	pub const NONE: Self = Self(0);
+	pub const WIFI: Self = Self(1 << 0);
+	pub const PARKING: Self = Self(1 << 1);
+	pub const PETS_ALLOWED: Self = Self(1 << 2);
+	pub const KITCHEN: Self = Self(1 << 3);
+	pub const AIR_CONDITIONING: Self = Self(1 << 4);
+	pub const HEATING: Self = Self(1 << 5);
+	pub const WASHING_MACHINE: Self = Self(1 << 6);
+	pub const POOL: Self = Self(1 << 7);
+	/// Step-free access to the place
+	pub const STEP_FREE_ACCESS: Self = Self(1 << 8);
+	/// Elevator or ground floor access
+	pub const ELEVATOR: Self = Self(1 << 9);
+	/// Bathroom adapted for wheelchairs
+	pub const ACCESSIBLE_BATHROOM: Self = Self(1 << 10);
+
+	/// Returns `true` if all the amenities in `other` are offered.
+	pub fn contains(&self, other: Amenities) -> bool {
+		self.0 & other.0 == other.0
+	}
+}
+
+impl BitOr for Amenities {
+	type Output = Self;
+
+	fn bitor(self, rhs: Self) -> Self {
+		Self(self.0 | rhs.0)
+	}
+}
+
+/// Structured attributes of a listing.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug, Default)]
+pub struct PlaceAttributes {
+	/// The amenities offered by the place
+	pub amenities: Amenities,
+	/// The house rules, such as `no_smoking` ➡ `true` or `quiet_hours` ➡ `22-08`
+	pub house_rules: BTreeMap<Bytes, Bytes>,
+}
+
+/// Optional criteria the places returned by a search must match.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PlaceFilter<Date> {
+	/// Only return places of this type
+	pub place_type: Option<PlaceType>,
+	/// Minimum price per night
+	pub min_price: Option<u64>,
+	/// Maximum price per night
+	pub max_price: Option<u64>,
+	/// Only return places available between both dates, expressed in the date type used by the
+	/// bookings
+	pub available_between: Option<(Date, Date)>,
+	/// Only return places offering all these amenities
+	pub required_amenities: Amenities,
+	/// Only return places with these house rules set to these values
+	pub house_rules: Vec<(Bytes, Bytes)>,
+	/// Only return places whose owner is a verified host
+	pub verified_host: bool,
+}
+
+/// Verification level of an account, from the least to the most trusted.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Encode, Decode, TypeInfo, Clone, Copy, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum VerificationLevel {
+	Unverified,
+	EmailVerified,
+	PhoneVerified,
+	/// The identity document of the account has been verified
+	IdVerified,
+}
+
+impl VerificationLevel {
+	/// The level a host needs to be shown as verified in its listings.
+	pub const VERIFIED_HOST: Self = Self::IdVerified;
+}
+
+/// Roles an account can hold in a place on behalf of its owner.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub enum PlaceRole {
+	Owner,
+	CoHost,
+	Cleaner,
+	Viewer,
+}
+
+impl PlaceRole {
+	/// Permissions granted to the role when none are explicitly provided.
+	pub fn default_permissions(&self) -> Permissions {
+		match self {
+			PlaceRole::Owner => Permissions::ALL,
+			PlaceRole::CoHost =>
+				Permissions::EDIT_LISTING |
+					Permissions::MANAGE_BOOKINGS |
+					Permissions::MANAGE_CALENDAR,
+			PlaceRole::Cleaner => Permissions::MANAGE_CALENDAR,
+			PlaceRole::Viewer => Permissions::NONE,
+		}
+	}
+}
+
+/// Bitset of the actions an account is allowed to perform over a place.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Copy, Debug, Default)]
+pub struct Permissions(pub u8);
+
+impl Permissions {
+	pub const NONE: Self = Self(0);
+	/// Update the listing information
+	pub const EDIT_LISTING: Self = Self(1 << 0);
+	/// Confirm or reject booking requests
+	pub const MANAGE_BOOKINGS: Self = Self(1 << 1);
+	/// Withdraw the payout of completed bookings
+	pub const WITHDRAW_PAYOUT: Self = Self(1 << 2);
+	/// Manage the availability calendar
+	pub const MANAGE_CALENDAR: Self = Self(1 << 3);
+	pub const ALL: Self = Self(
+		Self::EDIT_LISTING.0 |
+			Self::MANAGE_BOOKINGS.0 |
+			Self::WITHDRAW_PAYOUT.0 |
+			Self::MANAGE_CALENDAR.0,
+	);
+
+	/// Returns `true` if all the permissions in `other` are granted.
+	pub fn contains(&self, other: Permissions) -> bool {
+		self.0 & other.0 == other.0
+	}
+}
+
+impl BitOr for Permissions {
+	type Output = Self;
+
+	fn bitor(self, rhs: Self) -> Self {
+		Self(self.0 | rhs.0)
+	}
+}
+
+/// Role assigned to an account in a specific place.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub struct PlaceRoleData {
+	/// The role held by the account.
+	pub role: PlaceRole,
+	/// The permissions granted to the account.
+	pub permissions: Permissions,
+	/// Share of every payout that goes to the account. The owner keeps the remainder.
+	pub payout_share: Perbill,
+}
+
+/// Schedule followed to collect the amount of the bookings of a place.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub struct PaymentSchedule {
+	/// Share of the amount reserved when the booking is created.
+	pub deposit: Perbill,
+	/// Number of days before the checkin at which the remaining balance is collected.
+	pub balance_due_days: u32,
+}
+
+/// Policy applied to the bookings of a place whose guests never arrive.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub struct NoShowPolicy {
+	/// Share of the amount paid by the guest that the host keeps.
+	pub host_share: Perbill,
+	/// Number of hours after the checkin hour before a booking can be marked as a no-show.
+	pub grace_period_hours: u32,
+}
+
+impl Default for NoShowPolicy {
+	/// By default, the host keeps the whole amount after a day of grace.
+	fn default() -> Self {
+		NoShowPolicy { host_share: Perbill::one(), grace_period_hours: 24 }
+	}
+}
+
+/// Attestation of an inspector that a place exists and matches its listing.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+#[scale_info(skip_type_params(T))]
+pub struct PlaceInspection<T: Config> {
+	/// The inspector that attested the place.
+	pub inspector: T::AccountId,
+	/// The moment of the attestation.
+	pub attested_at: T::Moment,
+	/// The moment from which the attestation is no longer valid.
+	pub expires_at: T::Moment,
+}
+
+/// Inspection requested by the owner of a place, whose fee is held in escrow.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub struct InspectionRequest<AccountId, Balance> {
+	/// The owner that requested the inspection.
+	pub requester: AccountId,
+	/// The inspector chosen by the owner, the only one that can claim the fee.
+	pub inspector: AccountId,
+	/// The fee reserved from the owner, claimed by the inspector on attestation.
+	pub fee: Balance,
+}
+
+/// Result of the off-chain check of the content referenced by a place.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Copy, Debug)]
+pub enum ContentStatus {
+	/// The content has not been checked since it last changed.
+	Unchecked,
+	/// Every referenced object was found and matches its hash.
+	Verified,
+	/// Some referenced object could not be found or does not match its hash.
+	Missing,
+}
+
+/// Result of the off-chain check of the content of a place, signed by a content checker.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
+pub struct ContentStatusPayload<Public, Hash, BlockNumber> {
+	/// The place whose content was checked.
+	pub place_id: Hash,
+	/// The hash of the description and images checked.
+	pub content: Hash,
+	/// The result of the check.
+	pub status: ContentStatus,
+	/// The block at which the content was checked. Older results are rejected.
+	pub block_number: BlockNumber,
+	/// The key of the content checker signing the payload.
+	pub public: Public,
+}
+
+impl<T: SigningTypes> SignedPayload<T>
+	for ContentStatusPayload<T::Public, T::Hash, T::BlockNumber>
+{
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
+
 #[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Clone, Debug)]
 #[scale_info(skip_type_params(T))]
 
@@ -40,8 +320,12 @@ pub struct PlaceData<T: Config> {
 	pub place_type: PlaceType,
 	/// The name of the place.
 	pub name: Bytes,
-	/// The address of the place.
-	pub address: Bytes,
+	/// The coarse public location of the place, such as a city or a geohash prefix. The exact
+	/// address is only revealed, encrypted, to the guests of confirmed bookings.
+	pub location: Bytes,
+	/// The geohash cell of the place, with `GEOHASH_LOCATION_PRECISION` characters. Its prefix
+	/// indexes the place, the exact coordinates are not stored.
+	pub geohash: Bytes,
 	/// The description of the place. Just a reference to an external description, as this might be
 	/// too big for the chain.
 	pub description: T::Hash,
@@ -51,12 +335,23 @@ pub struct PlaceData<T: Config> {
 	pub checkin_hour: u32,
 	/// The hour picked by the owner for the checkout 0-23
 	pub checkout_hour: u32,
+	/// The offset of the local time of the place from UTC, in minutes. The checkin and checkout
+	/// hours are expressed in this local time.
+	pub utc_offset_minutes: i16,
 	/// Whether the place is available for rent. Controls if the place can receive bookings or not.
 	pub active: bool,
 	/// The images of the place. References to external images.
 	pub images: BTreeSet<T::Hash>,
 	/// The number of floors of the house, in case it has more than
 	pub number_of_floors: u8,
+	/// The amenities and house rules of the place.
+	pub attributes: PlaceAttributes,
+	/// The last attestation of an inspector. It is cleared when the description or the images
+	/// of the place change.
+	pub inspection: Option<PlaceInspection<T>>,
+	/// The result of the off-chain check of the description and images. It is reset when they
+	/// change.
+	pub content_status: ContentStatus,
 	/// The owner of the place
 	pub owner: T::AccountId,
 	/// Audit Trailing
@@ -68,26 +363,34 @@ impl<T: Config> PlaceData<T> {
 	pub fn new(
 		place_type: PlaceType,
 		name: Bytes,
-		address: Bytes,
+		location: Bytes,
+		geohash: Bytes,
 		description: T::Hash,
 		price_per_night: u64,
 		checkin_hour: u32,
 		checkout_hour: u32,
+		utc_offset_minutes: i16,
 		images: BTreeSet<T::Hash>,
 		number_of_floors: Option<u8>,
+		attributes: PlaceAttributes,
 		created_by: T::AccountId,
 	) -> Self {
 		PlaceData {
 			place_type,
 			name,
-			address,
+			location,
+			geohash,
 			description,
 			price_per_night,
 			checkin_hour,
 			checkout_hour,
+			utc_offset_minutes,
 			active: true,
 			images,
 			number_of_floors: number_of_floors.unwrap_or(1),
+			attributes,
+			inspection: None,
+			content_status: ContentStatus::Unchecked,
 			owner: created_by.clone(),
 			on_chain_creation: AuditTrail::<T>::new(created_by),
 			on_chain_update: None,
@@ -100,7 +403,7 @@ impl<T: Config> PlaceData<T> {
 pub struct PlaceHashingData<T: Config> {
 	pub place_type: PlaceType,
 	pub name: Bytes,
-	pub address: Bytes,
+	pub location: Bytes,
 	pub description: T::Hash,
 	pub images: BTreeSet<T::Hash>,
 	pub number_of_floors: u8,
@@ -108,9 +411,9 @@ pub struct PlaceHashingData<T: Config> {
 
 impl<T: Config> From<PlaceData<T>> for PlaceHashingData<T> {
 	fn from(from: PlaceData<T>) -> Self {
-		let PlaceData { place_type, name, address, description, images, number_of_floors, .. } =
+		let PlaceData { place_type, name, location, description, images, number_of_floors, .. } =
 			from;
 
-		Self { place_type, name, address, description, images, number_of_floors }
+		Self { place_type, name, location, description, images, number_of_floors }
 	}
 }
@@ -0,0 +1,78 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::structures::VerificationLevel;
+use frame_support::dispatch::DispatchResult;
+
+/// Bond locked by the hosts for every active listing.
+///
+/// It is consulted by `pallet_places` whenever a place is created or removed, so the bond of the
+/// host can be adjusted to the number of listings it publishes.
+pub trait ListingBond<AccountId> {
+	/// Lock the bond required for a new listing of the host.
+	///
+	/// # Arguments
+	///
+	/// * `host` - The owner of the new place.
+	///
+	/// # Returns
+	///
+	/// Returns a `DispatchResult` with an error if the host cannot afford the bond, in which case
+	/// the place must not be created.
+	fn lock_listing_bond(host: &AccountId) -> DispatchResult;
+
+	/// Release the part of the bond that is no longer required once a listing of the host is
+	/// removed.
+	///
+	/// # Arguments
+	///
+	/// * `host` - The owner of the removed place.
+	fn release_listing_bond(host: &AccountId);
+}
+
+/// No bond is required to publish listings.
+impl<AccountId> ListingBond<AccountId> for () {
+	fn lock_listing_bond(_host: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn release_listing_bond(_host: &AccountId) {}
+}
+
+/// Bookings that are still to be honored by the host of a place.
+///
+/// It is consulted by `pallet_places` before removing a place, so its bond is not released while
+/// the host can still cancel its stays.
+pub trait ActiveBookings<Hash> {
+	/// Check whether a place has bookings requested, confirmed or in progress.
+	///
+	/// # Arguments
+	///
+	/// * `place_id` - The place to be checked.
+	fn has_active_bookings(place_id: &Hash) -> bool;
+}
+
+/// There are no bookings, so places can always be removed.
+impl<Hash> ActiveBookings<Hash> for () {
+	fn has_active_bookings(_place_id: &Hash) -> bool {
+		false
+	}
+}
+
+/// Verification judgements held by hosts and guests.
+///
+/// It is consulted by `pallet_places` to flag verified hosts, and by the pallets handling the
+/// bookings to enforce the verification level required by a place from its guests.
+pub trait Verification<AccountId> {
+	/// Get the verification level of an account.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The host or guest.
+	fn verification_level(account: &AccountId) -> VerificationLevel;
+}
+
+/// There are no judgements, so every account is unverified.
+impl<AccountId> Verification<AccountId> for () {
+	fn verification_level(_account: &AccountId) -> VerificationLevel {
+		VerificationLevel::Unverified
+	}
+}
@@ -1,12 +1,35 @@
 #[cfg(test)]
 pub mod mock;
-use frame_support::{assert_noop, assert_ok};
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::Dispatchable,
+	traits::{Currency, GetStorageVersion, Hooks, ReservableCurrency, StorageVersion},
+};
 
 use crate::mock::*;
 use pallet_places::*;
-use sp_core::H256;
+use parking_lot::RwLock;
+use sp_core::{
+	offchain::{
+		testing::{
+			OffchainState, PendingRequest, PoolState, TestOffchainExt, TestTransactionPoolExt,
+		},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	H256,
+};
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::{BlakeTwo256, Hash, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	Perbill,
+};
+use std::sync::Arc;
 use structures::PlaceData;
 
+const DEMO_COORDINATES: Coordinates = Coordinates { latitude: 40_416_775, longitude: -3_703_790 };
+
 fn create_hash(data: &str) -> H256 {
 	let bytes = data.as_bytes();
 	let mut array = [0; 32];
@@ -19,13 +42,16 @@ fn create_default_place() {
 		RuntimeOrigin::signed(1),
 		PlaceType::Apartment,
 		b"Demo Place".to_vec(),
-		b"Demo Address".to_vec(),
+		b"Demo City".to_vec(),
+		DEMO_COORDINATES,
 		create_hash("Demo Description"),
 		10,
 		17,
 		12,
+		0,
 		vec![create_hash("image_1"), create_hash("image_2")],
 		None,
+		PlaceAttributes::default(),
 	);
 }
 
@@ -42,13 +68,16 @@ fn test_create_place_should_work() {
 			RuntimeOrigin::signed(1),
 			PlaceType::Apartment,
 			b"Demo Place".to_vec(),
-			b"Demo Address".to_vec(),
+			b"Demo City".to_vec(),
+			DEMO_COORDINATES,
 			create_hash("Demo Description"),
 			10,
 			17,
 			12,
+			0,
 			vec![create_hash("image_1"), create_hash("image_2")],
 			None,
+			PlaceAttributes::default(),
 		));
 
 		let place_id = Places::get_all_places()[0];
@@ -60,14 +89,19 @@ fn test_create_place_should_work() {
 			Some(PlaceData {
 				place_type: PlaceType::Apartment,
 				name: b"Demo Place".to_vec(),
-				address: b"Demo Address".to_vec(),
+				location: b"Demo City".to_vec(),
+				geohash: b"ezjmgt".to_vec(),
 				description: create_hash("Demo Description"),
 				price_per_night: 10,
 				checkin_hour: 17,
 				checkout_hour: 12,
+				utc_offset_minutes: 0,
 				active: true,
 				images: vec![create_hash("image_1"), create_hash("image_2")].into_iter().collect(),
 				number_of_floors: 1,
+				attributes: PlaceAttributes::default(),
+				inspection: None,
+				content_status: ContentStatus::Unchecked,
 				owner: 1,
 				on_chain_creation: AuditTrail { account: 1, block: 0, time: 0 },
 				on_chain_update: None
@@ -83,26 +117,35 @@ fn test_update_place_should_work() {
 
 		let new_place_type = Some(PlaceType::House);
 		let new_name = Some(b"Demo Place 2".to_vec());
-		let new_address = Some(b"Demo Address 2".to_vec());
+		let new_location = Some(b"Demo City 2".to_vec());
+		let new_coordinates = Some(Coordinates { latitude: 41_387_917, longitude: 2_169_919 });
 		let new_description = Some(create_hash("Demo Description 2"));
 		let new_price_per_night = Some(20);
 		let new_checkin_hour = Some(18);
 		let new_checkout_hour = Some(11);
+		let new_utc_offset_minutes = Some(60);
 		let new_images = Some(vec![create_hash("image_3"), create_hash("image_2")]);
 		let new_number_of_floors = Some(2);
+		let new_attributes = Some(PlaceAttributes {
+			amenities: Amenities::WIFI | Amenities::KITCHEN,
+			house_rules: vec![(b"no_smoking".to_vec(), b"true".to_vec())].into_iter().collect(),
+		});
 
 		assert_ok!(Places::update_place(
 			RuntimeOrigin::signed(1),
 			place_id,
 			new_place_type,
 			new_name,
-			new_address,
+			new_location,
+			new_coordinates,
 			new_description,
 			new_price_per_night,
 			new_checkin_hour,
 			new_checkout_hour,
+			new_utc_offset_minutes,
 			new_images,
 			new_number_of_floors,
+			new_attributes,
 		));
 
 		let place_data = Places::get_place_by_id(place_id);
@@ -113,11 +156,13 @@ fn test_update_place_should_work() {
 			Some(PlaceData {
 				place_type: PlaceType::House,
 				name: b"Demo Place 2".to_vec(),
-				address: b"Demo Address 2".to_vec(),
+				location: b"Demo City 2".to_vec(),
+				geohash: b"sp3e3q".to_vec(),
 				description: create_hash("Demo Description 2"),
 				price_per_night: 20,
 				checkin_hour: 18,
 				checkout_hour: 11,
+				utc_offset_minutes: 60,
 				active: true,
 				images: vec![
 					create_hash("image_1"),
@@ -127,6 +172,14 @@ fn test_update_place_should_work() {
 				.into_iter()
 				.collect(),
 				number_of_floors: 2,
+				attributes: PlaceAttributes {
+					amenities: Amenities::WIFI | Amenities::KITCHEN,
+					house_rules: vec![(b"no_smoking".to_vec(), b"true".to_vec())]
+						.into_iter()
+						.collect(),
+				},
+				inspection: None,
+				content_status: ContentStatus::Unchecked,
 				owner: 1,
 				on_chain_creation: AuditTrail { account: 1, block: 0, time: 0 },
 				on_chain_update: Some(AuditTrail { account: 1, block: 0, time: 0 }),
@@ -147,3 +200,1377 @@ fn test_remove_place_should_work() {
 		assert_eq!(places, None);
 	})
 }
+
+#[test]
+fn test_remove_place_by_non_owner_should_fail() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_noop!(
+			Places::remove_place(RuntimeOrigin::signed(2), place_id),
+			Error::<Test>::NotPlaceOwner
+		);
+		assert_eq!(Places::get_all_places().len(), 1);
+	})
+}
+
+#[test]
+fn test_assign_place_role_should_work() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_ok!(Places::assign_place_role(
+			RuntimeOrigin::signed(1),
+			place_id,
+			2,
+			PlaceRole::CoHost,
+			None,
+			Some(Perbill::from_percent(20)),
+		));
+
+		assert_eq!(
+			Places::get_place_role(place_id, 2),
+			Some(PlaceRoleData {
+				role: PlaceRole::CoHost,
+				permissions: Permissions::EDIT_LISTING |
+					Permissions::MANAGE_BOOKINGS |
+					Permissions::MANAGE_CALENDAR,
+				payout_share: Perbill::from_percent(20),
+			})
+		);
+		assert_eq!(
+			Places::get_payout_shares(&place_id, &1),
+			vec![(1, Perbill::from_percent(80)), (2, Perbill::from_percent(20))]
+		);
+
+		// A co-host can edit the listing
+		assert_ok!(Places::update_place(
+			RuntimeOrigin::signed(2),
+			place_id,
+			None,
+			Some(b"Managed Place".to_vec()),
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+		assert_eq!(Places::get_place_by_id(place_id).unwrap().name, b"Managed Place".to_vec());
+	})
+}
+
+#[test]
+fn test_update_place_without_permission_should_fail() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_noop!(
+			Places::update_place(
+				RuntimeOrigin::signed(2),
+				place_id,
+				None,
+				Some(b"Stolen Place".to_vec()),
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::MissingPermission
+		);
+
+		// A viewer cannot edit the listing either
+		assert_ok!(Places::assign_place_role(
+			RuntimeOrigin::signed(1),
+			place_id,
+			2,
+			PlaceRole::Viewer,
+			None,
+			None,
+		));
+		assert_noop!(
+			Places::update_place(
+				RuntimeOrigin::signed(2),
+				place_id,
+				None,
+				Some(b"Stolen Place".to_vec()),
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::MissingPermission
+		);
+	})
+}
+
+#[test]
+fn test_assign_place_role_not_owner_should_fail() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_noop!(
+			Places::assign_place_role(
+				RuntimeOrigin::signed(2),
+				place_id,
+				2,
+				PlaceRole::Owner,
+				None,
+				None,
+			),
+			Error::<Test>::NotPlaceOwner
+		);
+	})
+}
+
+#[test]
+fn test_assign_place_role_exceeding_payout_shares_should_fail() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_ok!(Places::assign_place_role(
+			RuntimeOrigin::signed(1),
+			place_id,
+			2,
+			PlaceRole::CoHost,
+			None,
+			Some(Perbill::from_percent(60)),
+		));
+
+		assert_noop!(
+			Places::assign_place_role(
+				RuntimeOrigin::signed(1),
+				place_id,
+				3,
+				PlaceRole::CoHost,
+				None,
+				Some(Perbill::from_percent(50)),
+			),
+			Error::<Test>::PayoutSharesExceedTotal
+		);
+	})
+}
+
+#[test]
+fn test_revoke_place_role_should_work() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_ok!(Places::assign_place_role(
+			RuntimeOrigin::signed(1),
+			place_id,
+			2,
+			PlaceRole::Cleaner,
+			None,
+			None,
+		));
+		assert_ok!(Places::revoke_place_role(RuntimeOrigin::signed(1), place_id, 2));
+
+		assert_eq!(Places::get_place_role(place_id, 2), None);
+		assert!(!Places::has_permission(&place_id, &2, Permissions::MANAGE_CALENDAR));
+	})
+}
+
+#[test]
+fn test_set_payout_split_should_work() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let split = vec![(2, Perbill::from_percent(70)), (1, Perbill::from_percent(30))];
+
+		assert_ok!(Places::set_payout_split(RuntimeOrigin::signed(1), place_id, split.clone()));
+
+		assert_eq!(Places::get_payout_split(place_id), split);
+		// The split takes precedence over the role shares
+		assert_eq!(Places::get_payout_shares(&place_id, &1), split);
+
+		// An empty split removes it
+		assert_ok!(Places::set_payout_split(RuntimeOrigin::signed(1), place_id, vec![]));
+		assert_eq!(Places::get_payout_shares(&place_id, &1), vec![(1, Perbill::one())]);
+	})
+}
+
+#[test]
+fn test_set_invalid_payout_split_should_fail() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_noop!(
+			Places::set_payout_split(
+				RuntimeOrigin::signed(1),
+				place_id,
+				vec![(2, Perbill::from_percent(70)), (1, Perbill::from_percent(20))],
+			),
+			Error::<Test>::InvalidPayoutSplit
+		);
+		assert_noop!(
+			Places::set_payout_split(
+				RuntimeOrigin::signed(1),
+				place_id,
+				vec![(2, Perbill::from_percent(50)), (2, Perbill::from_percent(50))],
+			),
+			Error::<Test>::DuplicatedPayoutBeneficiary
+		);
+		assert_noop!(
+			Places::set_payout_split(
+				RuntimeOrigin::signed(2),
+				place_id,
+				vec![(2, Perbill::from_percent(100))],
+			),
+			Error::<Test>::NotPlaceOwner
+		);
+	})
+}
+
+#[test]
+fn test_set_payment_schedule_should_work() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let schedule = PaymentSchedule { deposit: Perbill::from_percent(30), balance_due_days: 14 };
+
+		assert_ok!(Places::set_payment_schedule(
+			RuntimeOrigin::signed(1),
+			place_id,
+			Some(schedule.clone())
+		));
+		assert_eq!(Places::get_payment_schedule(place_id), Some(schedule));
+
+		// A `None` schedule removes it
+		assert_ok!(Places::set_payment_schedule(RuntimeOrigin::signed(1), place_id, None));
+		assert_eq!(Places::get_payment_schedule(place_id), None);
+	})
+}
+
+#[test]
+fn test_set_invalid_payment_schedule_should_fail() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_noop!(
+			Places::set_payment_schedule(
+				RuntimeOrigin::signed(1),
+				place_id,
+				Some(PaymentSchedule { deposit: Perbill::one(), balance_due_days: 14 }),
+			),
+			Error::<Test>::InvalidPaymentSchedule
+		);
+		assert_noop!(
+			Places::set_payment_schedule(
+				RuntimeOrigin::signed(2),
+				place_id,
+				Some(PaymentSchedule { deposit: Perbill::from_percent(30), balance_due_days: 14 }),
+			),
+			Error::<Test>::NotPlaceOwner
+		);
+	})
+}
+
+#[test]
+fn test_set_no_show_policy_should_work() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let policy = NoShowPolicy { host_share: Perbill::from_percent(50), grace_period_hours: 6 };
+
+		assert_noop!(
+			Places::set_no_show_policy(RuntimeOrigin::signed(2), place_id, Some(policy.clone())),
+			Error::<Test>::NotPlaceOwner
+		);
+		assert_ok!(Places::set_no_show_policy(
+			RuntimeOrigin::signed(1),
+			place_id,
+			Some(policy.clone())
+		));
+		assert_eq!(Places::get_no_show_policy(place_id), Some(policy));
+
+		// A `None` policy restores the default one
+		assert_ok!(Places::set_no_show_policy(RuntimeOrigin::signed(1), place_id, None));
+		assert_eq!(Places::get_no_show_policy(place_id), None);
+	})
+}
+
+#[test]
+fn test_set_guest_verification_should_work() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_noop!(
+			Places::set_guest_verification(
+				RuntimeOrigin::signed(2),
+				place_id,
+				Some(VerificationLevel::PhoneVerified)
+			),
+			Error::<Test>::NotPlaceOwner
+		);
+		assert_ok!(Places::set_guest_verification(
+			RuntimeOrigin::signed(1),
+			place_id,
+			Some(VerificationLevel::PhoneVerified)
+		));
+		assert_eq!(
+			Places::get_guest_verification(place_id),
+			Some(VerificationLevel::PhoneVerified)
+		);
+
+		// Only guests with a phone or identity verification meet the requirement
+		VerifiedAccounts::set(vec![
+			(2, VerificationLevel::EmailVerified),
+			(3, VerificationLevel::IdVerified),
+		]);
+		assert!(!Places::meets_guest_verification(&place_id, &2));
+		assert!(Places::meets_guest_verification(&place_id, &3));
+		assert!(!Places::meets_guest_verification(&place_id, &4));
+
+		// A `None` level accepts unverified guests again
+		assert_ok!(Places::set_guest_verification(RuntimeOrigin::signed(1), place_id, None));
+		assert_eq!(Places::get_guest_verification(place_id), None);
+		assert!(Places::meets_guest_verification(&place_id, &4));
+	})
+}
+
+const INSPECTOR: u64 = 3;
+
+fn demo_images() -> Vec<H256> {
+	vec![create_hash("image_1"), create_hash("image_2")]
+}
+
+#[test]
+fn test_add_and_remove_inspector_should_work() {
+	build_with_default_config().execute_with(|| {
+		assert_noop!(
+			Places::add_inspector(RuntimeOrigin::signed(1), INSPECTOR),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Places::add_inspector(RuntimeOrigin::root(), INSPECTOR));
+		assert_eq!(Places::get_inspectors(), vec![INSPECTOR]);
+		assert_noop!(
+			Places::add_inspector(RuntimeOrigin::root(), INSPECTOR),
+			Error::<Test>::AlreadyInspector
+		);
+
+		assert_ok!(Places::remove_inspector(RuntimeOrigin::root(), INSPECTOR));
+		assert_eq!(Places::get_inspectors(), vec![]);
+		assert_noop!(
+			Places::remove_inspector(RuntimeOrigin::root(), INSPECTOR),
+			Error::<Test>::NotInspector
+		);
+	})
+}
+
+#[test]
+fn test_request_inspection_should_work() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_noop!(
+			Places::request_inspection(RuntimeOrigin::signed(1), place_id, INSPECTOR, 20),
+			Error::<Test>::NotInspector
+		);
+		assert_ok!(Places::add_inspector(RuntimeOrigin::root(), INSPECTOR));
+		assert_ok!(Places::add_inspector(RuntimeOrigin::root(), 1));
+		assert_noop!(
+			Places::request_inspection(RuntimeOrigin::signed(2), place_id, INSPECTOR, 20),
+			Error::<Test>::NotPlaceOwner
+		);
+		assert_noop!(
+			Places::request_inspection(RuntimeOrigin::signed(1), place_id, 1, 20),
+			Error::<Test>::CannotInspectOwnPlace
+		);
+		assert_noop!(
+			Places::request_inspection(
+				RuntimeOrigin::signed(1),
+				place_id,
+				INSPECTOR,
+				BASE_TOKEN_AMOUNT + 1
+			),
+			Error::<Test>::InsufficientBalanceForInspection
+		);
+		assert_ok!(Places::request_inspection(RuntimeOrigin::signed(1), place_id, INSPECTOR, 20));
+		assert_eq!(
+			Places::get_inspection_request(place_id),
+			Some(InspectionRequest { requester: 1, inspector: INSPECTOR, fee: 20 })
+		);
+		assert_eq!(Balances::reserved_balance(1), 20);
+		assert_noop!(
+			Places::request_inspection(RuntimeOrigin::signed(1), place_id, INSPECTOR, 20),
+			Error::<Test>::InspectionAlreadyRequested
+		);
+
+		// Canceling the request releases the fee
+		assert_ok!(Places::cancel_inspection_request(RuntimeOrigin::signed(1), place_id));
+		assert_eq!(Places::get_inspection_request(place_id), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_noop!(
+			Places::cancel_inspection_request(RuntimeOrigin::signed(1), place_id),
+			Error::<Test>::InspectionNotRequested
+		);
+	})
+}
+
+#[test]
+fn test_attest_place_should_work() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		assert_ok!(Places::add_inspector(RuntimeOrigin::root(), INSPECTOR));
+		assert_ok!(Places::request_inspection(RuntimeOrigin::signed(1), place_id, INSPECTOR, 20));
+
+		assert_ok!(Places::attest_place(
+			RuntimeOrigin::signed(INSPECTOR),
+			place_id,
+			create_hash("Demo Description"),
+			demo_images()
+		));
+
+		let inspection = Places::get_place_by_id(place_id).unwrap().inspection.unwrap();
+		assert_eq!(inspection.inspector, INSPECTOR);
+		assert_eq!(inspection.expires_at, InspectionValidity::get());
+		assert!(Places::is_place_inspected(&place_id));
+
+		// The inspector claims the fee in escrow
+		assert_eq!(Places::get_inspection_request(place_id), None);
+		assert_eq!(Balances::total_balance(&1), BASE_TOKEN_AMOUNT - 20);
+		assert_eq!(Balances::free_balance(INSPECTOR), BASE_TOKEN_AMOUNT + 20);
+
+		// The attestation expires
+		<pallet_places::pallet_timestamp::Pallet<Test>>::set_timestamp(InspectionValidity::get());
+		assert!(!Places::is_place_inspected(&place_id));
+	})
+}
+
+#[test]
+fn test_attest_place_by_other_inspector_should_keep_the_fee_in_escrow() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		assert_ok!(Places::add_inspector(RuntimeOrigin::root(), INSPECTOR));
+		assert_ok!(Places::add_inspector(RuntimeOrigin::root(), 2));
+		assert_ok!(Places::request_inspection(RuntimeOrigin::signed(1), place_id, INSPECTOR, 20));
+
+		assert_ok!(Places::attest_place(
+			RuntimeOrigin::signed(2),
+			place_id,
+			create_hash("Demo Description"),
+			demo_images()
+		));
+
+		// The attestation is stored but the fee stays reserved for the chosen inspector
+		assert_eq!(Places::get_place_by_id(place_id).unwrap().inspection.unwrap().inspector, 2);
+		assert_eq!(
+			Places::get_inspection_request(place_id),
+			Some(InspectionRequest { requester: 1, inspector: INSPECTOR, fee: 20 })
+		);
+		assert_eq!(Balances::reserved_balance(1), 20);
+		assert_eq!(Balances::free_balance(2), BASE_TOKEN_AMOUNT);
+
+		// The chosen inspector can still claim it
+		assert_ok!(Places::attest_place(
+			RuntimeOrigin::signed(INSPECTOR),
+			place_id,
+			create_hash("Demo Description"),
+			demo_images()
+		));
+		assert_eq!(Places::get_inspection_request(place_id), None);
+		assert_eq!(Balances::free_balance(INSPECTOR), BASE_TOKEN_AMOUNT + 20);
+	})
+}
+
+#[test]
+fn test_attest_place_with_wrong_data_should_fail() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		assert_noop!(
+			Places::attest_place(
+				RuntimeOrigin::signed(INSPECTOR),
+				place_id,
+				create_hash("Demo Description"),
+				demo_images()
+			),
+			Error::<Test>::NotInspector
+		);
+
+		assert_ok!(Places::add_inspector(RuntimeOrigin::root(), INSPECTOR));
+		assert_ok!(Places::add_inspector(RuntimeOrigin::root(), 1));
+		assert_noop!(
+			Places::attest_place(
+				RuntimeOrigin::signed(1),
+				place_id,
+				create_hash("Demo Description"),
+				demo_images()
+			),
+			Error::<Test>::CannotInspectOwnPlace
+		);
+		assert_noop!(
+			Places::attest_place(
+				RuntimeOrigin::signed(INSPECTOR),
+				place_id,
+				create_hash("Other Description"),
+				demo_images()
+			),
+			Error::<Test>::InspectionMismatch
+		);
+		assert_noop!(
+			Places::attest_place(
+				RuntimeOrigin::signed(INSPECTOR),
+				place_id,
+				create_hash("Demo Description"),
+				vec![create_hash("image_1")]
+			),
+			Error::<Test>::InspectionMismatch
+		);
+	})
+}
+
+#[test]
+fn test_update_place_description_clears_inspection() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		assert_ok!(Places::add_inspector(RuntimeOrigin::root(), INSPECTOR));
+		assert_ok!(Places::attest_place(
+			RuntimeOrigin::signed(INSPECTOR),
+			place_id,
+			create_hash("Demo Description"),
+			demo_images()
+		));
+
+		assert_ok!(Places::update_place(
+			RuntimeOrigin::signed(1),
+			place_id,
+			None,
+			None,
+			None,
+			None,
+			Some(create_hash("New Description")),
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+
+		assert_eq!(Places::get_place_by_id(place_id).unwrap().inspection, None);
+		assert!(!Places::is_place_inspected(&place_id));
+	})
+}
+
+#[test]
+fn test_geohash_encode_should_work() {
+	assert_eq!(geohash::encode(&DEMO_COORDINATES, 4), b"ezjm".to_vec());
+	assert_eq!(
+		geohash::encode(&Coordinates { latitude: 57_649_110, longitude: 10_407_440 }, 11),
+		b"u4pruydqqvj".to_vec()
+	);
+
+	// The center of a cell lies in the same cell
+	let center = geohash::decode_center(b"ezjmgt").unwrap();
+	assert_eq!(geohash::encode(&center, 6), b"ezjmgt".to_vec());
+	assert_eq!(center, Coordinates { latitude: 40_415_954, longitude: -3_707_886 });
+	assert_eq!(geohash::decode_center(b"ezjmga"), None);
+	assert_eq!(geohash::decode_center(b""), None);
+}
+
+#[test]
+fn test_create_place_with_invalid_coordinates_should_fail() {
+	build_with_default_config().execute_with(|| {
+		assert_noop!(
+			Places::create_place(
+				RuntimeOrigin::signed(1),
+				PlaceType::Apartment,
+				b"Demo Place".to_vec(),
+				b"Demo City".to_vec(),
+				Coordinates { latitude: 91_000_000, longitude: 0 },
+				create_hash("Demo Description"),
+				10,
+				17,
+				12,
+				0,
+				vec![],
+				None,
+				PlaceAttributes::default(),
+			),
+			Error::<Test>::InvalidCoordinates
+		);
+	})
+}
+
+#[test]
+fn test_geohash_index_should_follow_the_place() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		assert_eq!(Places::get_place_by_id(place_id).unwrap().geohash, b"ezjmgt".to_vec());
+		assert_eq!(Places::get_places_by_geohash(b"ezjm".to_vec()), vec![place_id]);
+
+		// Moving the place moves it to the new cell
+		assert_ok!(Places::update_place(
+			RuntimeOrigin::signed(1),
+			place_id,
+			None,
+			None,
+			None,
+			Some(Coordinates { latitude: 41_387_917, longitude: 2_169_919 }),
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+		assert!(!PlacesByGeohash::<Test>::contains_key(b"ezjm".to_vec()));
+		assert_eq!(Places::get_places_by_geohash(b"sp3e".to_vec()), vec![place_id]);
+
+		// Removing the place removes it from the index
+		assert_ok!(Places::remove_place(RuntimeOrigin::signed(1), place_id));
+		assert!(!PlacesByGeohash::<Test>::contains_key(b"sp3e".to_vec()));
+	})
+}
+
+#[test]
+fn test_search_places_should_work() {
+	build_with_demo_place().execute_with(|| {
+		let demo_place_id = Places::get_all_places()[0];
+		// A house about 4.2km north of the demo place, in a different cell
+		assert_ok!(Places::create_place(
+			RuntimeOrigin::signed(2),
+			PlaceType::House,
+			b"Stadium House".to_vec(),
+			b"Demo City".to_vec(),
+			Coordinates { latitude: 40_453_054, longitude: -3_688_344 },
+			create_hash("Stadium Description"),
+			50,
+			17,
+			12,
+			0,
+			vec![],
+			None,
+			PlaceAttributes::default(),
+		));
+		let house_id = Places::get_all_places()[1];
+		let always_available = |_: &H256, _: &u64, _: &u64| true;
+		let any_place = PlaceFilter::default();
+
+		let mut found = Places::search_places_within_radius(
+			&DEMO_COORDINATES,
+			5_000,
+			&any_place,
+			always_available,
+		)
+		.unwrap();
+		found.sort();
+		let mut expected = vec![demo_place_id, house_id];
+		expected.sort();
+		assert_eq!(found, expected);
+		assert_eq!(
+			Places::search_places_within_radius(
+				&DEMO_COORDINATES,
+				3_000,
+				&any_place,
+				always_available
+			)
+			.unwrap(),
+			vec![demo_place_id]
+		);
+
+		let bounding_box = BoundingBox {
+			south_west: Coordinates { latitude: 40_440_000, longitude: -3_700_000 },
+			north_east: Coordinates { latitude: 40_460_000, longitude: -3_680_000 },
+		};
+		assert_eq!(
+			Places::search_places_in_bounding_box(&bounding_box, &any_place, always_available)
+				.unwrap(),
+			vec![house_id]
+		);
+
+		// Areas needing too many cells cannot be searched
+		let whole_world = BoundingBox {
+			south_west: Coordinates { latitude: -90_000_000, longitude: -180_000_000 },
+			north_east: Coordinates { latitude: 90_000_000, longitude: 180_000_000 },
+		};
+		assert!(matches!(
+			Places::search_places_in_bounding_box(&whole_world, &any_place, always_available),
+			Err(Error::<Test>::SearchAreaTooLarge)
+		));
+		assert!(matches!(
+			Places::search_places_within_radius(
+				&DEMO_COORDINATES,
+				1_000_000,
+				&any_place,
+				always_available
+			),
+			Err(Error::<Test>::SearchAreaTooLarge)
+		));
+
+		// Filters
+		let apartments =
+			PlaceFilter { place_type: Some(PlaceType::Apartment), ..Default::default() };
+		assert_eq!(
+			Places::search_places_within_radius(
+				&DEMO_COORDINATES,
+				5_000,
+				&apartments,
+				always_available
+			)
+			.unwrap(),
+			vec![demo_place_id]
+		);
+		let expensive =
+			PlaceFilter { min_price: Some(20), max_price: Some(60), ..Default::default() };
+		assert_eq!(
+			Places::search_places_within_radius(
+				&DEMO_COORDINATES,
+				5_000,
+				&expensive,
+				always_available
+			)
+			.unwrap(),
+			vec![house_id]
+		);
+		let available = PlaceFilter { available_between: Some((1, 2)), ..Default::default() };
+		assert_eq!(
+			Places::search_places_within_radius(
+				&DEMO_COORDINATES,
+				5_000,
+				&available,
+				|place_id: &H256, _, _| place_id == &demo_place_id
+			)
+			.unwrap(),
+			vec![demo_place_id]
+		);
+
+		// Only the house belongs to a verified host
+		VerifiedAccounts::set(vec![
+			(1, VerificationLevel::PhoneVerified),
+			(2, VerificationLevel::IdVerified),
+		]);
+		assert!(!Places::is_verified_host(&1));
+		assert!(Places::is_verified_host(&2));
+		let verified = PlaceFilter { verified_host: true, ..Default::default() };
+		assert_eq!(
+			Places::search_places_within_radius(
+				&DEMO_COORDINATES,
+				5_000,
+				&verified,
+				always_available
+			)
+			.unwrap(),
+			vec![house_id]
+		);
+	})
+}
+
+#[test]
+fn test_create_place_with_too_many_house_rules_should_fail() {
+	build_with_default_config().execute_with(|| {
+		let attributes = PlaceAttributes {
+			amenities: Amenities::NONE,
+			house_rules: (0..21u8).map(|rule| (vec![rule], b"true".to_vec())).collect(),
+		};
+
+		assert_noop!(
+			Places::create_place(
+				RuntimeOrigin::signed(1),
+				PlaceType::Apartment,
+				b"Demo Place".to_vec(),
+				b"Demo City".to_vec(),
+				DEMO_COORDINATES,
+				create_hash("Demo Description"),
+				10,
+				17,
+				12,
+				0,
+				vec![],
+				None,
+				attributes,
+			),
+			Error::<Test>::TooManyHouseRules
+		);
+	})
+}
+
+#[test]
+fn test_update_place_with_too_long_house_rule_should_fail() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let long_key = PlaceAttributes {
+			amenities: Amenities::NONE,
+			house_rules: vec![(vec![b'k'; 33], b"true".to_vec())].into_iter().collect(),
+		};
+		let long_value = PlaceAttributes {
+			amenities: Amenities::NONE,
+			house_rules: vec![(b"quiet_hours".to_vec(), vec![b'v'; 33])].into_iter().collect(),
+		};
+
+		for attributes in [long_key, long_value] {
+			assert_noop!(
+				Places::update_place(
+					RuntimeOrigin::signed(1),
+					place_id,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					Some(attributes),
+				),
+				Error::<Test>::HouseRuleTooLong
+			);
+		}
+	})
+}
+
+#[test]
+fn test_search_places_by_attributes_should_work() {
+	build_with_demo_place().execute_with(|| {
+		let demo_place_id = Places::get_all_places()[0];
+		assert_ok!(Places::create_place(
+			RuntimeOrigin::signed(2),
+			PlaceType::House,
+			b"Pet Friendly House".to_vec(),
+			b"Demo City".to_vec(),
+			DEMO_COORDINATES,
+			create_hash("Pet Friendly Description"),
+			50,
+			17,
+			12,
+			0,
+			vec![],
+			None,
+			PlaceAttributes {
+				amenities: Amenities::WIFI | Amenities::PETS_ALLOWED | Amenities::PARKING,
+				house_rules: vec![(b"quiet_hours".to_vec(), b"22-08".to_vec())]
+					.into_iter()
+					.collect(),
+			},
+		));
+		let house_id = Places::get_all_places()[1];
+		let always_available = |_: &H256, _: &u64, _: &u64| true;
+
+		let with_pets = PlaceFilter {
+			required_amenities: Amenities::PETS_ALLOWED | Amenities::WIFI,
+			..Default::default()
+		};
+		assert_eq!(
+			Places::search_places_within_radius(
+				&DEMO_COORDINATES,
+				1_000,
+				&with_pets,
+				always_available
+			)
+			.unwrap(),
+			vec![house_id]
+		);
+
+		let with_pool = PlaceFilter { required_amenities: Amenities::POOL, ..Default::default() };
+		assert_eq!(
+			Places::search_places_within_radius(
+				&DEMO_COORDINATES,
+				1_000,
+				&with_pool,
+				always_available
+			)
+			.unwrap(),
+			vec![]
+		);
+
+		let quiet = PlaceFilter {
+			house_rules: vec![(b"quiet_hours".to_vec(), b"22-08".to_vec())],
+			..Default::default()
+		};
+		assert_eq!(
+			Places::search_places_within_radius(&DEMO_COORDINATES, 1_000, &quiet, always_available)
+				.unwrap(),
+			vec![house_id]
+		);
+
+		// Updating the attributes of the demo place makes it match too
+		assert_ok!(Places::update_place(
+			RuntimeOrigin::signed(1),
+			demo_place_id,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(PlaceAttributes {
+				amenities: Amenities::WIFI | Amenities::PETS_ALLOWED,
+				house_rules: Default::default(),
+			}),
+		));
+		let mut found = Places::search_places_within_radius(
+			&DEMO_COORDINATES,
+			1_000,
+			&with_pets,
+			always_available,
+		)
+		.unwrap();
+		found.sort();
+		let mut expected = vec![demo_place_id, house_id];
+		expected.sort();
+		assert_eq!(found, expected);
+	})
+}
+
+#[test]
+fn test_update_place_with_invalid_utc_offset_should_fail() {
+	build_with_demo_place().execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		for invalid_offset in [15 * 60, -13 * 60, 50] {
+			assert_noop!(
+				Places::update_place(
+					RuntimeOrigin::signed(1),
+					place_id,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					None,
+					Some(invalid_offset),
+					None,
+					None,
+					None,
+				),
+				Error::<Test>::InvalidUtcOffset
+			);
+		}
+
+		// Nepal is 5 hours and 45 minutes ahead of UTC
+		assert_ok!(Places::update_place(
+			RuntimeOrigin::signed(1),
+			place_id,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(5 * 60 + 45),
+			None,
+			None,
+			None,
+		));
+		assert_eq!(Places::get_place_by_id(place_id).unwrap().utc_offset_minutes, 345);
+	})
+}
+
+const GATEWAY: &str = "https://gateway.test/ipfs";
+const CHECKER: u64 = 7;
+
+fn content_url(content: &[u8]) -> String {
+	let hash = BlakeTwo256::hash(content);
+	format!(
+		"{}/{}",
+		GATEWAY,
+		hash.as_ref().iter().map(|b| format!("{:02x}", b)).collect::<String>()
+	)
+}
+
+/// Expect a request of the off-chain worker for `content`, to which the gateway answers with
+/// `response`.
+fn expect_content(state: &mut OffchainState, content: &[u8], response: &[u8]) {
+	state.expect_request(PendingRequest {
+		method: "GET".into(),
+		uri: content_url(content),
+		response: Some(response.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+}
+
+/// Build a place whose content is checked by the off-chain worker, whose node holds the key of
+/// the `CHECKER`.
+fn build_with_offchain(
+) -> (sp_io::TestExternalities, Arc<RwLock<OffchainState>>, Arc<RwLock<PoolState>>) {
+	let mut ext = build_with_default_config();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	offchain.0.write().persistent_storage.set(
+		b"",
+		pallet_places::offchain::CONTENT_GATEWAY_KEY,
+		GATEWAY.as_bytes(),
+	);
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	UintAuthorityId::set_all_keys(vec![CHECKER]);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Places::add_content_checker(RuntimeOrigin::root(), UintAuthorityId(CHECKER)));
+		assert_ok!(Places::create_place(
+			RuntimeOrigin::signed(1),
+			PlaceType::Apartment,
+			b"Demo Place".to_vec(),
+			b"Demo City".to_vec(),
+			DEMO_COORDINATES,
+			BlakeTwo256::hash(b"Demo Description"),
+			10,
+			17,
+			12,
+			0,
+			vec![BlakeTwo256::hash(b"image_1")],
+			None,
+			PlaceAttributes::default(),
+		));
+	});
+	(ext, offchain_state, pool_state)
+}
+
+fn submitted_call(pool_state: &Arc<RwLock<PoolState>>) -> RuntimeCall {
+	let tx = pool_state.write().transactions.pop().unwrap();
+	assert!(pool_state.read().transactions.is_empty());
+	UncheckedExtrinsic::decode(&mut &*tx).unwrap().function
+}
+
+/// Build the call submitting the content status of a place checked at `block_number`, signed by
+/// `checker`.
+fn content_status_call(
+	place_id: H256,
+	content: H256,
+	status: ContentStatus,
+	block_number: u64,
+	checker: u64,
+) -> Call<Test> {
+	let payload = ContentStatusPayload {
+		place_id,
+		content,
+		status,
+		block_number,
+		public: UintAuthorityId(checker),
+	};
+	let signature = TestSignature(checker, payload.encode());
+	Call::submit_content_status { payload, signature }
+}
+
+#[test]
+fn test_add_content_checker_should_work() {
+	build_with_default_config().execute_with(|| {
+		assert_noop!(
+			Places::add_content_checker(RuntimeOrigin::signed(1), UintAuthorityId(CHECKER)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Places::add_content_checker(RuntimeOrigin::root(), UintAuthorityId(CHECKER)));
+		assert_eq!(Places::get_content_checkers(), vec![UintAuthorityId(CHECKER)]);
+		assert_noop!(
+			Places::add_content_checker(RuntimeOrigin::root(), UintAuthorityId(CHECKER)),
+			Error::<Test>::AlreadyContentChecker
+		);
+
+		assert_ok!(Places::remove_content_checker(RuntimeOrigin::root(), UintAuthorityId(CHECKER)));
+		assert_eq!(Places::get_content_checkers(), vec![]);
+		assert_noop!(
+			Places::remove_content_checker(RuntimeOrigin::root(), UintAuthorityId(CHECKER)),
+			Error::<Test>::NotContentChecker
+		);
+	})
+}
+
+#[test]
+fn test_offchain_worker_should_verify_content() {
+	let (mut ext, offchain_state, pool_state) = build_with_offchain();
+	expect_content(&mut offchain_state.write(), b"Demo Description", b"Demo Description");
+	expect_content(&mut offchain_state.write(), b"image_1", b"image_1");
+
+	ext.execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let place_data = Places::get_place_by_id(place_id).unwrap();
+		assert_eq!(place_data.content_status, ContentStatus::Unchecked);
+
+		Places::offchain_worker(1);
+
+		let call = submitted_call(&pool_state);
+		let expected = content_status_call(
+			place_id,
+			Places::content_hash(&place_data),
+			ContentStatus::Verified,
+			1,
+			CHECKER,
+		);
+		assert_eq!(call, RuntimeCall::Places(expected.clone()));
+		assert!(Places::validate_unsigned(TransactionSource::External, &expected).is_ok());
+		assert_ok!(call.dispatch(RuntimeOrigin::none()));
+		assert_eq!(
+			Places::get_place_by_id(place_id).unwrap().content_status,
+			ContentStatus::Verified
+		);
+
+		// Verified places are not checked again
+		Places::offchain_worker(2);
+		assert!(pool_state.read().transactions.is_empty());
+	})
+}
+
+#[test]
+fn test_offchain_worker_should_report_missing_content() {
+	let (mut ext, offchain_state, pool_state) = build_with_offchain();
+	// The gateway serves content that does not match the reference
+	expect_content(&mut offchain_state.write(), b"Demo Description", b"Other Description");
+
+	ext.execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+
+		Places::offchain_worker(1);
+
+		let call = submitted_call(&pool_state);
+		assert!(matches!(
+			call,
+			RuntimeCall::Places(Call::submit_content_status {
+				payload: ContentStatusPayload { status: ContentStatus::Missing, .. },
+				..
+			})
+		));
+		assert_ok!(call.dispatch(RuntimeOrigin::none()));
+		assert_eq!(
+			Places::get_place_by_id(place_id).unwrap().content_status,
+			ContentStatus::Missing
+		);
+	})
+}
+
+#[test]
+fn test_offchain_worker_should_not_read_oversized_content() {
+	let (mut ext, offchain_state, pool_state) = build_with_offchain();
+	// The gateway serves an object larger than allowed
+	expect_content(
+		&mut offchain_state.write(),
+		b"Demo Description",
+		&vec![0; pallet_places::offchain::MAX_CONTENT_SIZE + 1],
+	);
+
+	ext.execute_with(|| {
+		Places::offchain_worker(1);
+
+		assert!(matches!(
+			submitted_call(&pool_state),
+			RuntimeCall::Places(Call::submit_content_status {
+				payload: ContentStatusPayload { status: ContentStatus::Missing, .. },
+				..
+			})
+		));
+	})
+}
+
+#[test]
+fn test_offchain_worker_without_checker_key_should_skip_places() {
+	let (mut ext, _, pool_state) = build_with_offchain();
+
+	ext.execute_with(|| {
+		// The key of the node is no longer allowed to check the content
+		assert_ok!(Places::remove_content_checker(RuntimeOrigin::root(), UintAuthorityId(CHECKER)));
+
+		Places::offchain_worker(1);
+
+		assert!(pool_state.read().transactions.is_empty());
+	})
+}
+
+#[test]
+fn test_submit_content_status_with_wrong_signature_should_fail() {
+	let (mut ext, _, _) = build_with_offchain();
+
+	ext.execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let content = Places::content_hash(&Places::get_place_by_id(place_id).unwrap());
+
+		// The key is not a content checker
+		let call = content_status_call(place_id, content, ContentStatus::Verified, 1, 8);
+		assert_eq!(
+			Places::validate_unsigned(TransactionSource::InBlock, &call),
+			Err(InvalidTransaction::BadSigner.into())
+		);
+
+		// The payload is not signed by the key of the content checker
+		let payload = ContentStatusPayload {
+			place_id,
+			content,
+			status: ContentStatus::Verified,
+			block_number: 1,
+			public: UintAuthorityId(CHECKER),
+		};
+		let signature = TestSignature(8, payload.encode());
+		let call = Call::submit_content_status { payload, signature };
+		assert_eq!(
+			Places::validate_unsigned(TransactionSource::InBlock, &call),
+			Err(InvalidTransaction::BadProof.into())
+		);
+	})
+}
+
+#[test]
+fn test_submit_content_status_for_changed_content_should_fail() {
+	let (mut ext, _, _) = build_with_offchain();
+
+	ext.execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let content = Places::content_hash(&Places::get_place_by_id(place_id).unwrap());
+		let call = content_status_call(place_id, content, ContentStatus::Verified, 1, CHECKER);
+
+		assert_noop!(
+			RuntimeCall::Places(call.clone()).dispatch(RuntimeOrigin::signed(1)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert!(Places::validate_unsigned(TransactionSource::External, &call).is_ok());
+
+		assert_ok!(RuntimeCall::Places(call.clone()).dispatch(RuntimeOrigin::none()));
+		assert_ok!(Places::update_place(
+			RuntimeOrigin::signed(1),
+			place_id,
+			None,
+			None,
+			None,
+			None,
+			Some(create_hash("New Description")),
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+
+		// The content changed, so it has to be checked again
+		assert_eq!(
+			Places::get_place_by_id(place_id).unwrap().content_status,
+			ContentStatus::Unchecked
+		);
+		assert_eq!(
+			Places::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::Stale.into())
+		);
+		assert_noop!(
+			RuntimeCall::Places(call).dispatch(RuntimeOrigin::none()),
+			Error::<Test>::ContentChanged
+		);
+	})
+}
+
+#[test]
+fn test_submit_stale_content_status_should_fail() {
+	let (mut ext, _, _) = build_with_offchain();
+
+	ext.execute_with(|| {
+		let place_id = Places::get_all_places()[0];
+		let content = Places::content_hash(&Places::get_place_by_id(place_id).unwrap());
+		let missing = content_status_call(place_id, content, ContentStatus::Missing, 1, CHECKER);
+		assert_ok!(RuntimeCall::Places(missing.clone()).dispatch(RuntimeOrigin::none()));
+		assert_eq!(Places::get_content_checked_at(place_id), Some(1));
+
+		// A newer check replaces the result
+		System::set_block_number(3);
+		let verified = content_status_call(place_id, content, ContentStatus::Verified, 2, CHECKER);
+		assert!(Places::validate_unsigned(TransactionSource::External, &verified).is_ok());
+		assert_ok!(RuntimeCall::Places(verified).dispatch(RuntimeOrigin::none()));
+
+		// The older result cannot be replayed
+		assert_eq!(
+			Places::validate_unsigned(TransactionSource::External, &missing),
+			Err(InvalidTransaction::Stale.into())
+		);
+		assert_noop!(
+			RuntimeCall::Places(missing).dispatch(RuntimeOrigin::none()),
+			Error::<Test>::StaleContentStatus
+		);
+		assert_eq!(
+			Places::get_place_by_id(place_id).unwrap().content_status,
+			ContentStatus::Verified
+		);
+
+		// Results from the future or older than their longevity are rejected too
+		let future = content_status_call(place_id, content, ContentStatus::Missing, 4, CHECKER);
+		assert_eq!(
+			Places::validate_unsigned(TransactionSource::External, &future),
+			Err(InvalidTransaction::Stale.into())
+		);
+		System::set_block_number(4 + pallet_places::offchain::CONTENT_STATUS_LONGEVITY as u64 + 1);
+		assert_noop!(
+			RuntimeCall::Places(future).dispatch(RuntimeOrigin::none()),
+			Error::<Test>::StaleContentStatus
+		);
+	})
+}
+
+/// Store a place with the layout previous to the storage version 1.
+fn insert_old_place(place_id: H256, address: &[u8]) {
+	let old_place = migrations::v1::OldPlaceData::<Test> {
+		place_type: PlaceType::Apartment,
+		name: b"Demo Place".to_vec(),
+		address: address.to_vec(),
+		description: create_hash("Demo Description"),
+		price_per_night: 10,
+		checkin_hour: 17,
+		checkout_hour: 12,
+		active: true,
+		images: demo_images().into_iter().collect(),
+		number_of_floors: 1,
+		owner: 1,
+		on_chain_creation: AuditTrail::new(1),
+		on_chain_update: None,
+	};
+	frame_support::storage::unhashed::put(
+		&PlacesData::<Test>::hashed_key_for(place_id),
+		&old_place,
+	);
+	PlacesIds::<Test>::append(place_id);
+}
+
+#[test]
+fn test_migration_to_v1_should_clear_addresses() {
+	build_with_default_config().execute_with(|| {
+		let place_id = create_hash("Old Place");
+		StorageVersion::new(0).put::<Places>();
+		insert_old_place(place_id, b"Calle Mayor 1, 3B, Madrid");
+
+		Places::on_runtime_upgrade();
+
+		let place_data = Places::get_place_by_id(place_id).unwrap();
+		assert!(place_data.location.is_empty());
+		assert_eq!(place_data.owner, 1);
+		assert_eq!(place_data.price_per_night, 10);
+		assert_eq!(place_data.images.len(), 2);
+		assert_eq!(place_data.inspection, None);
+		assert_eq!(place_data.content_status, ContentStatus::Unchecked);
+		assert_eq!(Places::on_chain_storage_version(), StorageVersion::new(1));
+		assert!(Places::get_places_by_geohash(geohash::encode(
+			&Coordinates::default(),
+			geohash::GEOHASH_INDEX_PRECISION
+		))
+		.is_empty());
+
+		// The migration only runs once
+		Places::on_runtime_upgrade();
+		assert_eq!(Places::get_place_by_id(place_id), Some(place_data));
+	})
+}
@@ -1,15 +1,16 @@
 #![cfg(test)]
 
 use frame_support::{parameter_types, traits::ConstU32};
-use pallet_places;
+use pallet_places::{self, traits::Verification, VerificationLevel};
 use sp_core::H256;
 
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, Header as _, IdentityLookup},
+	BuildStorage,
 };
 
-type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
 frame_support::construct_runtime!(
@@ -20,6 +21,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		Timestamp: pallet_timestamp,
+		Balances: pallet_balances,
 		Places: pallet_places,
 	}
 );
@@ -49,7 +51,7 @@ impl frame_system::Config for Test {
 	type PalletInfo = PalletInfo;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type SystemWeightInfo = ();
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = ();
@@ -67,12 +69,100 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+parameter_types! {
+	pub const InspectionValidity: u64 = 365 * 24 * 60 * 60 * 1000;
+	pub const PlacesUnsignedPriority: u64 = 1 << 20;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+/// Signs the results of the content checks with the keys set by `UintAuthorityId::set_all_keys`.
+pub struct TestAuthId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+	type GenericPublic = UintAuthorityId;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
+}
+
 impl pallet_places::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type MaxPayoutBeneficiaries = ConstU32<10>;
+	type MaxHouseRules = ConstU32<20>;
+	type MaxHouseRuleLength = ConstU32<32>;
+	type ListingBond = ();
+	type Verification = MockVerification;
+	type ActiveBookings = ();
+	type InspectionCurrency = Balances;
+	type InspectorOrigin = frame_system::EnsureRoot<u64>;
+	type InspectionValidity = InspectionValidity;
+	type AuthorityId = TestAuthId;
+	type UnsignedPriority = PlacesUnsignedPriority;
+	type MaxContentChecks = ConstU32<5>;
+}
+
+parameter_types! {
+	pub static VerifiedAccounts: Vec<(u64, VerificationLevel)> = vec![];
+}
+
+/// Verification judgements read from `VerifiedAccounts`.
+pub struct MockVerification;
+
+impl Verification<u64> for MockVerification {
+	fn verification_level(account: &u64) -> VerificationLevel {
+		VerifiedAccounts::get()
+			.into_iter()
+			.find(|(verified, _)| verified == account)
+			.map_or(VerificationLevel::Unverified, |(_, level)| level)
+	}
 }
 
+pub const BASE_TOKEN_AMOUNT: u64 = 100;
+
 pub fn build_with_default_config() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	GenesisConfig {
+		balances: BalancesConfig {
+			balances: (1..=3).map(|account| (account, BASE_TOKEN_AMOUNT)).collect(),
+		},
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	t.into()
 }
 
 pub fn setup_blocks(blocks: u64) {
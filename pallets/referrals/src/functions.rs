@@ -0,0 +1,46 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::{
+	interface::ReferralsInterface, BalanceOf, CompletedBookingsCount, Config, Error, Pallet,
+	Referrers,
+};
+use frame_support::{ensure, sp_runtime::DispatchError, traits::Get};
+use pallet_bookings::traits::ReferralProgram;
+
+impl<T: Config> ReferralsInterface<T> for Pallet<T> {
+	fn _set_referrer(account: &T::AccountId, referrer: &T::AccountId) -> Result<(), DispatchError> {
+		ensure!(!<Referrers<T>>::contains_key(account), Error::<T>::ReferrerAlreadySet);
+		// Accounts cannot name a referrer once they are already active on the platform
+		ensure!(Self::get_completed_bookings(account) == 0, Error::<T>::AccountAlreadyBooked);
+		ensure!(account != referrer, Error::<T>::SelfReferral);
+		ensure!(
+			Self::get_referrer(referrer).as_ref() != Some(account),
+			Error::<T>::CircularReferral
+		);
+
+		// Make persistance
+		<Referrers<T>>::insert(account, referrer);
+
+		// Logging to the console on debug level
+		log::debug!(target: "did", "Account ➡ {:?} was referred by {:?}.", account, referrer);
+
+		Ok(())
+	}
+}
+
+impl<T: Config> ReferralProgram<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn on_booking_completed(
+		account: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Option<(T::AccountId, BalanceOf<T>)> {
+		// Every completed booking is counted, even without referrer
+		let completed_bookings = Self::get_completed_bookings(account);
+		<CompletedBookingsCount<T>>::insert(account, completed_bookings.saturating_add(1));
+
+		let referrer = Self::get_referrer(account)?;
+		if completed_bookings >= T::RewardedBookings::get() {
+			return None
+		}
+
+		Some((referrer, T::ReferralShare::get().mul_floor(amount)))
+	}
+}
@@ -0,0 +1,24 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use crate::Config;
+use frame_support::sp_runtime::DispatchError;
+
+/// Interface for Referrals pallet
+pub trait ReferralsInterface<T: Config> {
+	/// Name the referrer of an account.
+	///
+	/// This function stores the referrer of an account. The referrer can only be named once,
+	/// before the account completes any booking, and it cannot be the account itself nor an
+	/// account it referred.
+	///
+	/// # Arguments
+	///
+	/// * `account` - The account identifier of the referred account.
+	/// * `referrer` - The account identifier of the referrer.
+	///
+	/// # Returns
+	///
+	/// Returns a `Result` indicating the success or failure of the operation. If the operation is
+	/// successful, the `Result` contains no data. Otherwise, it contains a `DispatchError`
+	/// explaining the reason for failure.
+	fn _set_referrer(account: &T::AccountId, referrer: &T::AccountId) -> Result<(), DispatchError>;
+}
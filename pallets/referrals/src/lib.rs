@@ -0,0 +1,109 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+pub mod functions;
+pub mod interface;
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use super::*;
+	use crate::interface::ReferralsInterface;
+	use frame_support::{
+		pallet_prelude::{ValueQuery, *},
+		sp_runtime::Perbill,
+		traits::Currency,
+	};
+	use frame_system::pallet_prelude::*;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info] // This allows us to use unsafe storages, at some point we might need bounded storages
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The Currency of the bookings, in which the rewards are paid.
+		type Currency: Currency<Self::AccountId>;
+
+		/// The share of the amount of a booking paid to the referrer by the platform.
+		#[pallet::constant]
+		type ReferralShare: Get<Perbill>;
+
+		/// The number of completed bookings of a referred account, as guest or as host, its
+		/// referrer is rewarded for.
+		#[pallet::constant]
+		type RewardedBookings: Get<u32>;
+	}
+
+	/// Stores the referrer named by every account.
+	#[pallet::storage]
+	#[pallet::getter(fn get_referrer)]
+	pub type Referrers<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::AccountId>;
+
+	/// Stores the number of completed bookings of every account, as guest or as host, whether it
+	/// was referred or not. The referrer is only rewarded for the first `RewardedBookings` ones.
+	#[pallet::storage]
+	#[pallet::getter(fn get_completed_bookings)]
+	pub type CompletedBookingsCount<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	// Pallets use events to inform users when important changes are made.
+	// https://docs.substrate.io/main-docs/build/events-errors/
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account has named its referrer
+		ReferrerSet { account: T::AccountId, referrer: T::AccountId },
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account has already named its referrer
+		ReferrerAlreadySet,
+		/// An account cannot be its own referrer
+		SelfReferral,
+		/// The referrer was referred by the account itself
+		CircularReferral,
+		/// The account has already completed some bookings
+		AccountAlreadyBooked,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Name the referrer of the sender.
+		///
+		/// This extrinsic allows an account to name, only once and before completing any booking,
+		/// the account that brought it to the platform. The referrer is then rewarded with a share
+		/// of the amount of the first `RewardedBookings` bookings the sender completes, either as
+		/// guest or as host.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The account naming its referrer.
+		/// * `referrer` - The account that referred the sender.
+		///
+		/// # Returns
+		///
+		/// Returns a `DispatchResult` indicating the success or failure of the operation. If the
+		/// operation is successful, the referrer is stored, and the `DispatchResult` contains no
+		/// error. If the operation fails, the `DispatchResult` contains an error describing the
+		/// reason for failure.
+		#[pallet::call_index(1)]
+		pub fn set_referrer(origin: OriginFor<T>, referrer: T::AccountId) -> DispatchResult {
+			// Check sender
+			let sender = ensure_signed(origin)?;
+			Self::_set_referrer(&sender, &referrer)?;
+			// Deposit our "ReferrerSet" event.
+			Self::deposit_event(Event::ReferrerSet { account: sender, referrer });
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+pub mod mock;
+use frame_support::{assert_noop, assert_ok};
+use pallet_bookings::traits::ReferralProgram;
+use pallet_referrals::Error;
+
+use crate::mock::*;
+
+const REFERRER: u64 = 1;
+const HOST: u64 = 2;
+const GUEST: u64 = 3;
+
+fn complete_booking(account: u64, amount: u64) -> Option<(u64, u64)> {
+	<Referrals as ReferralProgram<u64, u64>>::on_booking_completed(&account, amount)
+}
+
+// ========================================================
+// Referrer Registry Unit Tests
+// ========================================================
+#[test]
+fn test_set_referrer_should_work() {
+	build_with_funded_accounts().execute_with(|| {
+		assert_ok!(Referrals::set_referrer(RuntimeOrigin::signed(HOST), REFERRER));
+
+		assert_eq!(Referrals::get_referrer(HOST), Some(REFERRER));
+
+		// Check emitted events
+		System::assert_last_event(
+			pallet_referrals::Event::ReferrerSet { account: HOST, referrer: REFERRER }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_set_referrer_twice_should_fail() {
+	build_with_funded_accounts().execute_with(|| {
+		assert_ok!(Referrals::set_referrer(RuntimeOrigin::signed(HOST), REFERRER));
+
+		assert_noop!(
+			Referrals::set_referrer(RuntimeOrigin::signed(HOST), GUEST),
+			Error::<Test>::ReferrerAlreadySet
+		);
+	})
+}
+
+#[test]
+fn test_set_invalid_referrer_should_fail() {
+	build_with_funded_accounts().execute_with(|| {
+		assert_noop!(
+			Referrals::set_referrer(RuntimeOrigin::signed(HOST), HOST),
+			Error::<Test>::SelfReferral
+		);
+
+		assert_ok!(Referrals::set_referrer(RuntimeOrigin::signed(HOST), REFERRER));
+		assert_noop!(
+			Referrals::set_referrer(RuntimeOrigin::signed(REFERRER), HOST),
+			Error::<Test>::CircularReferral
+		);
+	})
+}
+
+#[test]
+fn test_set_referrer_after_completing_bookings_should_fail() {
+	build_with_funded_accounts().execute_with(|| {
+		assert_eq!(complete_booking(HOST, 0), None);
+
+		assert_noop!(
+			Referrals::set_referrer(RuntimeOrigin::signed(HOST), REFERRER),
+			Error::<Test>::AccountAlreadyBooked
+		);
+	})
+}
+
+// ========================================================
+// Referral Rewards Unit Tests
+// ========================================================
+#[test]
+fn test_referrer_rewarded_for_first_bookings() {
+	build_with_funded_accounts().execute_with(|| {
+		assert_ok!(Referrals::set_referrer(RuntimeOrigin::signed(HOST), REFERRER));
+
+		// The referrer gets 20% of the fee of the first two bookings
+		assert_eq!(complete_booking(HOST, 10), Some((REFERRER, 2)));
+		assert_eq!(complete_booking(HOST, 20), Some((REFERRER, 4)));
+		assert_eq!(Referrals::get_completed_bookings(HOST), 2);
+
+		assert_eq!(complete_booking(HOST, 10), None);
+		assert_eq!(Referrals::get_completed_bookings(HOST), 3);
+	})
+}
+
+#[test]
+fn test_account_without_referrer_is_not_rewarded() {
+	build_with_funded_accounts().execute_with(|| {
+		assert_eq!(complete_booking(GUEST, 10), None);
+		assert_eq!(Referrals::get_completed_bookings(GUEST), 1);
+	})
+}
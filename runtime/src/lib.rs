@@ -6,6 +6,8 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use codec::Encode;
+use frame_support::PalletId;
 use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -13,7 +15,8 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT,
+		Extrinsic as ExtrinsicT, IdentifyAccount, NumberFor, One, SaturatedConversion, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
@@ -38,6 +41,7 @@ pub use frame_support::{
 	StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::EnsureRoot;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
@@ -51,6 +55,21 @@ pub use pallet_places;
 /// Import the bookings pallet.
 pub use pallet_bookings;
 
+/// Import the bonds pallet.
+pub use pallet_bonds;
+
+/// Import the loyalty pallet.
+pub use pallet_loyalty;
+
+/// Import the referrals pallet.
+pub use pallet_referrals;
+
+/// Import the attestations pallet.
+pub use pallet_attestations;
+
+/// Import the calendar pallet.
+pub use pallet_calendar;
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -70,6 +89,9 @@ pub type Index = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// A timestamp: milliseconds since the unix epoch.
+pub type Moment = u64;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -225,7 +247,7 @@ impl pallet_grandpa::Config for Runtime {
 
 impl pallet_timestamp::Config for Runtime {
 	/// A timestamp: milliseconds since the unix epoch.
-	type Moment = u64;
+	type Moment = Moment;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
 	type WeightInfo = ();
@@ -270,13 +292,166 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeCall = RuntimeCall;
 }
 
+parameter_types! {
+	pub const InspectionValidity: u64 = 365 * 24 * 60 * 60 * 1000;
+	pub const PlacesUnsignedPriority: u64 = 1 << 20;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	fn create_transaction<A: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Index,
+	) -> Option<(RuntimeCall, <UncheckedExtrinsic as ExtrinsicT>::SignaturePayload)> {
+		// The transactions of the off-chain workers are mortal, and they pay no tip
+		let period =
+			BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+		let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+		let extra: SignedExtra = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+		);
+		let raw_payload = SignedPayload::new(call, extra).ok()?;
+		let signature = raw_payload.using_encoded(|payload| A::sign(payload, public))?;
+		let (call, extra, _) = raw_payload.deconstruct();
+		Some((call, (sp_runtime::MultiAddress::Id(account), signature, extra)))
+	}
+}
+
 impl pallet_places::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type MaxPayoutBeneficiaries = ConstU32<10>;
+	type MaxHouseRules = ConstU32<20>;
+	type MaxHouseRuleLength = ConstU32<64>;
+	type ListingBond = Bonds;
+	type Verification = Attestations;
+	type ActiveBookings = Bookings;
+	type InspectionCurrency = Balances;
+	type InspectorOrigin = EnsureRoot<AccountId>;
+	type InspectionValidity = InspectionValidity;
+	type AuthorityId = pallet_places::crypto::ContentAuthId;
+	type UnsignedPriority = PlacesUnsignedPriority;
+	type MaxContentChecks = ConstU32<5>;
+}
+
+parameter_types! {
+	pub const HostCancellationPenalty: Perbill = Perbill::from_percent(10);
+	pub const WaitlistClaimPeriod: u64 = 24 * 60 * 60 * 1000;
+	pub const ItineraryConfirmationPeriod: u64 = 2 * 24 * 60 * 60 * 1000;
+	pub const MessageInterval: u64 = 60 * 1000;
+	pub const DisputeWindow: u64 = 14 * 24 * 60 * 60 * 1000;
+	pub const BookingRetentionPeriod: u64 = 90 * 24 * 60 * 60 * 1000;
+	pub PlatformAccount: AccountId = PalletId(*b"drt/fees").into_account_truncating();
 }
 
 impl pallet_bookings::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type MaxEncryptedAddressLength = ConstU32<512>;
+	type HostCancellationPenalty = HostCancellationPenalty;
+	type MaxWaitlistLength = ConstU32<20>;
+	type WaitlistClaimPeriod = WaitlistClaimPeriod;
+	type MaxItineraryLegs = ConstU32<10>;
+	type ItineraryConfirmationPeriod = ItineraryConfirmationPeriod;
+	type CouponOrigin = EnsureRoot<AccountId>;
+	type HostReputation = Bonds;
+	type Loyalty = Loyalty;
+	type Referrals = Referrals;
+	type BlockedDates = Calendar;
+	type PlatformAccount = PlatformAccount;
+	type MaxMessageLength = ConstU32<2048>;
+	type MaxMessagesPerBooking = ConstU32<500>;
+	type MessageInterval = MessageInterval;
+	type DisputeWindow = DisputeWindow;
+	type BookingRetentionPeriod = BookingRetentionPeriod;
+	type MaxPruningChecks = ConstU32<50>;
+	type MaxScheduledChecks = ConstU32<50>;
+}
+
+parameter_types! {
+	pub const BondPerListing: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	pub const ReputationDiscount: Perbill = Perbill::from_percent(2);
+	pub const MaxReputationDiscount: Perbill = Perbill::from_percent(50);
+	pub const CancellationSlash: Perbill = Perbill::from_percent(10);
+}
+
+impl pallet_bonds::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BondPerListing = BondPerListing;
+	type ReputationDiscount = ReputationDiscount;
+	type MaxReputationDiscount = MaxReputationDiscount;
+	type CancellationSlash = CancellationSlash;
+	type SlashOrigin = EnsureRoot<AccountId>;
+}
+
+parameter_types! {
+	pub const GuestPointsRate: Perbill = Perbill::from_percent(2);
+	pub const MaxRedemptionShare: Perbill = Perbill::from_percent(20);
+	pub const PointsLifetime: BlockNumber = 365 * DAYS;
+	pub const MaxPointBatches: u32 = 50;
+}
+
+impl pallet_loyalty::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type GuestPointsRate = GuestPointsRate;
+	type MaxRedemptionShare = MaxRedemptionShare;
+	type PointsLifetime = PointsLifetime;
+	type MaxPointBatches = MaxPointBatches;
+}
+
+parameter_types! {
+	pub const ReferralShare: Perbill = Perbill::from_percent(1);
+	pub const RewardedBookings: u32 = 5;
+}
+
+impl pallet_referrals::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type ReferralShare = ReferralShare;
+	type RewardedBookings = RewardedBookings;
+}
+
+impl pallet_attestations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+}
+
+parameter_types! {
+	pub const CalendarSyncInterval: BlockNumber = 30 * MINUTES;
+}
+
+impl pallet_calendar::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityId = pallet_calendar::crypto::CalendarAuthId;
+	type SyncerOrigin = EnsureRoot<AccountId>;
+	type MaxFeedUrlLength = ConstU32<256>;
+	type MaxBlockedPeriods = ConstU32<100>;
+	type SyncInterval = CalendarSyncInterval;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -297,6 +472,11 @@ construct_runtime!(
 		// Include the custom logic from our custom pallets
 		Places: pallet_places,
 		Bookings: pallet_bookings,
+		Bonds: pallet_bonds,
+		Loyalty: pallet_loyalty,
+		Referrals: pallet_referrals,
+		Attestations: pallet_attestations,
+		Calendar: pallet_calendar,
 	}
 );
 
@@ -515,6 +695,52 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_places::runtime_api::PlacesApi<Block, Hash, pallet_bookings::CalendarDate> for Runtime {
+		fn places_in_bounding_box(
+			bounding_box: pallet_places::BoundingBox,
+			filter: pallet_places::PlaceFilter<pallet_bookings::CalendarDate>,
+		) -> Result<Vec<Hash>, sp_runtime::DispatchError> {
+			Places::search_places_in_bounding_box(&bounding_box, &filter, Bookings::is_place_available)
+				.map_err(Into::into)
+		}
+
+		fn places_within_radius(
+			center: pallet_places::Coordinates,
+			radius: u32,
+			filter: pallet_places::PlaceFilter<pallet_bookings::CalendarDate>,
+		) -> Result<Vec<Hash>, sp_runtime::DispatchError> {
+			Places::search_places_within_radius(&center, radius, &filter, Bookings::is_place_available)
+				.map_err(Into::into)
+		}
+
+		fn is_verified_host(place_id: Hash) -> bool {
+			Places::get_place_by_id(place_id)
+				.map_or(false, |place| Places::is_verified_host(&place.owner))
+		}
+	}
+
+	impl pallet_bookings::runtime_api::BookingsApi<Block, Hash, AccountId, Balance> for Runtime {
+		fn quote_booking(
+			place_id: Hash,
+			start_date: pallet_bookings::CalendarDate,
+			end_date: pallet_bookings::CalendarDate,
+			coupon: Option<Vec<u8>>,
+			redeem_points: Option<AccountId>,
+		) -> Result<Balance, sp_runtime::DispatchError> {
+			Bookings::quote_booking(
+				&place_id,
+				&start_date,
+				&end_date,
+				coupon.as_ref(),
+				redeem_points.as_ref(),
+			)
+		}
+
+		fn place_calendar(place_id: Hash) -> Option<pallet_bookings::PlaceCalendar<Hash>> {
+			Bookings::place_calendar(&place_id)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (